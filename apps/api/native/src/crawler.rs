@@ -1,9 +1,11 @@
+use flate2::read::MultiGzDecoder;
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::{
   collections::{HashMap, HashSet},
+  io::Read,
   sync::LazyLock,
 };
 use texting_robots::Robot;
@@ -19,6 +21,11 @@ static FILE_EXTENSIONS: &[&str] = &[
 static FILE_EXT_SET: LazyLock<HashSet<&'static str>> =
   LazyLock::new(|| FILE_EXTENSIONS.iter().copied().collect());
 
+/// Document formats Firecrawl can parse; gated by `allow_document_links`.
+static DOCUMENT_EXTENSIONS: &[&str] = &[
+  ".pdf", ".docx", ".doc", ".xlsx", ".pptx", ".odt", ".rtf", ".epub",
+];
+
 #[derive(Deserialize)]
 #[napi(object)]
 pub struct FilterLinksCall {
@@ -36,6 +43,30 @@ pub struct FilterLinksCall {
   pub robots_user_agent: Option<String>,
   pub allow_external_content_links: bool,
   pub allow_subdomains: bool,
+  /// Treat every `#fragment` on an internal link as a client-side route.
+  pub allow_hash_routes: bool,
+  /// Extra regex; fragments (without the `#`) matching it are treated as routes.
+  pub hash_route_pattern: Option<String>,
+  /// Read `Crawl-Delay` from robots.txt into the result.
+  pub respect_crawl_delay: bool,
+  /// Deny links whose absolute form is longer than this many bytes.
+  pub max_url_length: Option<u32>,
+  /// Query parameters (e.g. `utm_source`, `fbclid`) removed before filtering.
+  pub strip_query_params: Option<Vec<String>>,
+  /// When set, every query parameter not named here is removed before
+  /// filtering. Applied together with `strip_query_params`.
+  pub allow_query_parameters: Option<Vec<String>>,
+  /// Deduplicate on the stripped URL but return the original link.
+  pub deduplicate_stripped: bool,
+  /// Deny links whose URL fingerprint matches an already accepted link.
+  pub deduplicate_fingerprints: bool,
+  /// Domains denied as `SOCIAL_MEDIA` in addition to the built-in list.
+  pub additional_blocked_domains: Vec<String>,
+  /// Treat links differing only in their query string as duplicates, keeping the first.
+  pub strip_query_params_for_dedup: bool,
+  /// Let through links to parseable documents (`.pdf`, `.docx`, ...) instead
+  /// of denying them as `DOCUMENT_TYPE`.
+  pub allow_document_links: bool,
 }
 
 #[derive(Serialize)]
@@ -43,6 +74,14 @@ pub struct FilterLinksCall {
 pub struct FilterLinksResult {
   pub links: Vec<String>,
   pub denial_reasons: HashMap<String, String>,
+  /// Accepted links mapped to their normalised form.
+  pub normalised: HashMap<String, String>,
+  /// robots.txt `Crawl-Delay` for our user agent, if any.
+  pub crawl_delay_seconds: Option<f64>,
+  /// Number of links in `links`.
+  pub accepted_count: u32,
+  /// Denied link counts keyed by denial reason (e.g. `ROBOTS_TXT`).
+  pub denied_by_reason: HashMap<String, u32>,
 }
 
 #[derive(Deserialize)]
@@ -57,6 +96,13 @@ pub struct FilterUrlCall {
   pub robots_user_agent: Option<String>,
   pub allow_external_content_links: bool,
   pub allow_subdomains: bool,
+  /// Query parameters (e.g. `utm_source`, `fbclid`) removed before filtering.
+  pub strip_query_params: Option<Vec<String>>,
+  /// When set, every query parameter not named here is removed before
+  /// filtering. Applied together with `strip_query_params`.
+  pub allow_query_parameters: Option<Vec<String>>,
+  /// Domains denied as `SOCIAL_MEDIA` in addition to the built-in list.
+  pub additional_blocked_domains: Vec<String>,
 }
 
 #[derive(Serialize)]
@@ -71,6 +117,19 @@ pub struct FilterUrlResult {
 #[napi(object)]
 pub struct SitemapUrl {
   pub loc: Vec<String>,
+  pub last_mod: Option<String>,
+  pub change_freq: Option<String>,
+  pub priority: Option<f64>,
+  pub news: Option<SitemapNewsEntry>,
+}
+
+#[derive(Serialize, Debug)]
+#[napi(object)]
+pub struct SitemapNewsEntry {
+  pub title: Option<String>,
+  pub publication_date: Option<String>,
+  pub keywords: Option<Vec<String>>,
+  pub language: Option<String>,
 }
 
 #[derive(Serialize, Debug)]
@@ -104,6 +163,16 @@ pub struct SitemapInstruction {
   pub action: String,
   pub urls: Vec<String>,
   pub count: u32,
+  pub metadata: Vec<SitemapUrlMetadata>,
+}
+
+#[derive(Serialize, Debug)]
+#[napi(object)]
+pub struct SitemapUrlMetadata {
+  pub url: String,
+  pub last_mod: Option<String>,
+  pub change_freq: Option<String>,
+  pub priority: Option<f64>,
 }
 
 #[derive(Serialize, Debug)]
@@ -124,6 +193,17 @@ const SOCIAL_MEDIA: &str = "SOCIAL_MEDIA";
 const EXTERNAL_LINK: &str = "EXTERNAL_LINK";
 const SECTION_LINK: &str = "SECTION_LINK";
 const NON_WEB_PROTOCOL: &str = "NON_WEB_PROTOCOL";
+const URL_TOO_LONG: &str = "URL_TOO_LONG";
+const DUPLICATE_URL: &str = "DUPLICATE_URL";
+const DUPLICATE_CONTENT: &str = "DUPLICATE_CONTENT";
+const DOCUMENT_TYPE: &str = "DOCUMENT_TYPE";
+
+#[inline]
+fn is_document(path: &str) -> bool {
+  path
+    .rfind('.')
+    .is_some_and(|dot_pos| DOCUMENT_EXTENSIONS.contains(&&path[dot_pos..]))
+}
 
 #[inline]
 fn is_file(path: &str) -> bool {
@@ -160,17 +240,46 @@ fn is_internal_link(url: &Url, base_url: &Url) -> bool {
 }
 
 #[inline]
-fn no_sections(url_str: &str) -> bool {
-  if !url_str.contains('#') {
+fn no_sections(url_str: &str, allow_hash_routes: bool, hash_route_pattern: Option<&Regex>) -> bool {
+  let Some((_, hash_part)) = url_str.split_once('#') else {
+    return true;
+  };
+
+  if allow_hash_routes {
     return true;
   }
 
-  // Check if the hash fragment looks like a route (contains forward slashes and has substantial content)
-  if let Some(hash_part) = url_str.split('#').nth(1) {
-    hash_part.len() > 1 && hash_part.contains('/')
-  } else {
-    false
+  if hash_route_pattern.is_some_and(|r| r.is_match(hash_part)) {
+    return true;
+  }
+
+  is_hash_route(hash_part)
+}
+
+/// Whether a fragment looks like a SPA route rather than an in-page anchor.
+///
+/// Path-like routes (see [`is_path_hash_route`]) count, and so do fragments
+/// made only of ASCII letters, digits and hyphens (`#pricing`, `#page-2`),
+/// which SPAs commonly use as route names. Anything else, such as
+/// `#section_title` or `#top.of.page`, is treated as a section link.
+#[inline]
+fn is_hash_route(hash_part: &str) -> bool {
+  is_path_hash_route(hash_part)
+    || (!hash_part.is_empty()
+      && hash_part
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-'))
+}
+
+/// Whether a fragment is a hashbang (`#!/page`, `#!page`) or contains a
+/// forward slash (`#/path/to/route`).
+#[inline]
+fn is_path_hash_route(hash_part: &str) -> bool {
+  if let Some(route) = hash_part.strip_prefix('!') {
+    return !route.trim_start_matches('/').is_empty();
   }
+
+  hash_part.len() > 1 && hash_part.contains('/')
 }
 
 #[inline]
@@ -185,8 +294,9 @@ fn is_non_web_protocol(url_str: &str) -> bool {
 }
 
 #[inline]
-fn is_social_media_or_email(url_str: &str) -> bool {
+fn is_social_media_or_email(url_str: &str, additional_blocked_domains: &[String]) -> bool {
   const SOCIAL_MEDIA_OR_EMAIL: &[&str] = &[
+    "mailto:",
     "facebook.com",
     "twitter.com",
     "linkedin.com",
@@ -201,6 +311,9 @@ fn is_social_media_or_email(url_str: &str) -> bool {
   SOCIAL_MEDIA_OR_EMAIL
     .iter()
     .any(|domain| url_str.contains(domain))
+    || additional_blocked_domains
+      .iter()
+      .any(|domain| !domain.is_empty() && url_str.contains(domain.as_str()))
 }
 
 #[inline]
@@ -229,6 +342,307 @@ fn is_external_main_page(url_str: &str) -> bool {
   }
 }
 
+/// Normalises a URL so that trivially different spellings of the same page
+/// compare equal: lowercase host, no default port, no trailing slash on the
+/// path (except for the root) and no empty fragment.
+fn normalise_url(url: &Url) -> String {
+  let mut url = url.clone();
+
+  if let Some(host) = url.host_str() {
+    let host = host.to_ascii_lowercase();
+    let _ = url.set_host(Some(&host));
+  }
+
+  // `Url` already drops the port when it is the scheme's default.
+
+  let path = url.path();
+  if path.len() > 1 && path.ends_with('/') {
+    let trimmed = path.trim_end_matches('/');
+    let trimmed = if trimmed.is_empty() { "/" } else { trimmed }.to_string();
+    url.set_path(&trimmed);
+  }
+
+  if url.fragment() == Some("") {
+    url.set_fragment(None);
+  }
+
+  url.to_string()
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64-bit FNV-1a hash of a canonical form of `url`: lowercase host, no
+/// default port, query parameters sorted, no trailing slash and no fragment.
+/// Route-like fragments (`#/page`, `#!/page`) are kept, since they address
+/// different pages of a SPA.
+pub(crate) fn compute_url_fingerprint(url: &Url) -> u64 {
+  let mut url = url.clone();
+
+  if let Some(host) = url.host_str() {
+    let host = host.to_ascii_lowercase();
+    let _ = url.set_host(Some(&host));
+  }
+
+  if url.query().is_some() {
+    let mut pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+    pairs.sort();
+    if pairs.is_empty() {
+      url.set_query(None);
+    } else {
+      url.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+  }
+
+  // Only path-like routes survive: single-word fragments are usually heading
+  // anchors, and keeping them would stop `/page#intro` matching `/page`.
+  if !url.fragment().is_some_and(is_path_hash_route) {
+    url.set_fragment(None);
+  }
+
+  let path = url.path();
+  if path.len() > 1 && path.ends_with('/') {
+    let trimmed = path.trim_end_matches('/');
+    let trimmed = if trimmed.is_empty() { "/" } else { trimmed }.to_string();
+    url.set_path(&trimmed);
+  }
+
+  url.as_str().bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+    (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+  })
+}
+
+/// Compute the deduplication fingerprint of a URL.
+#[napi]
+pub fn url_fingerprint(url: String) -> Result<BigInt> {
+  let url = Url::parse(&url)
+    .map_err(|e| Error::new(Status::InvalidArg, format!("URL parse error: {e}")))?;
+  Ok(BigInt::from(compute_url_fingerprint(&url)))
+}
+
+#[derive(Serialize)]
+#[napi(object)]
+pub struct RobotsTxtValidation {
+  pub valid: bool,
+  pub warnings: Vec<String>,
+  pub user_agents: Vec<String>,
+}
+
+const ROBOTS_TXT_DIRECTIVES: &[&str] = &[
+  "user-agent",
+  "allow",
+  "disallow",
+  "crawl-delay",
+  "sitemap",
+  "host",
+  "clean-param",
+  "request-rate",
+];
+
+fn _validate_robots_txt(content: &str) -> RobotsTxtValidation {
+  let mut warnings = Vec::new();
+  let mut user_agents: Vec<String> = Vec::new();
+
+  if let Err(e) = Robot::new("*", content.as_bytes()) {
+    warnings.push(format!("robots.txt could not be parsed: {e}"));
+  }
+
+  for (i, line) in content.lines().enumerate() {
+    let line_no = i + 1;
+    let line = line.split('#').next().unwrap_or_default().trim();
+    if line.is_empty() {
+      continue;
+    }
+
+    let Some((directive, value)) = line.split_once(':') else {
+      warnings.push(format!(
+        "Line {line_no}: missing ':' separator in \"{line}\""
+      ));
+      continue;
+    };
+    let directive = directive.trim().to_ascii_lowercase();
+    let value = value.trim();
+
+    match directive.as_str() {
+      "user-agent" => {
+        if value.is_empty() {
+          warnings.push(format!("Line {line_no}: empty User-agent"));
+        } else if !user_agents.iter().any(|ua| ua.eq_ignore_ascii_case(value)) {
+          user_agents.push(value.to_string());
+        }
+      }
+      "allow" | "disallow" | "crawl-delay" if user_agents.is_empty() => {
+        warnings.push(format!(
+          "Line {line_no}: {directive} appears before any User-agent and is ignored"
+        ));
+      }
+      "allow" | "disallow" => {
+        if !value.is_empty() && !value.starts_with('/') && !value.starts_with('*') {
+          warnings.push(format!(
+            "Line {line_no}: {directive} path \"{value}\" should start with '/' or '*'"
+          ));
+        }
+      }
+      "crawl-delay" => {
+        if !value.parse::<f64>().is_ok_and(|x| x >= 0.0) {
+          warnings.push(format!("Line {line_no}: invalid Crawl-delay \"{value}\""));
+        }
+      }
+      "sitemap" => {
+        if Url::parse(value).is_err() {
+          warnings.push(format!(
+            "Line {line_no}: Sitemap \"{value}\" is not an absolute URL"
+          ));
+        }
+      }
+      d if ROBOTS_TXT_DIRECTIVES.contains(&d) => {}
+      _ => warnings.push(format!("Line {line_no}: unknown directive \"{directive}\"")),
+    }
+  }
+
+  if user_agents.is_empty() && !content.trim().is_empty() {
+    warnings.push("No User-agent groups found; no rules will apply".to_string());
+  }
+
+  RobotsTxtValidation {
+    valid: warnings.is_empty(),
+    warnings,
+    user_agents,
+  }
+}
+
+/// Check robots.txt content for problems that would otherwise make it
+/// silently allow everything. `valid` is true only when there are no warnings.
+#[napi]
+pub fn validate_robots_txt(content: String) -> Result<RobotsTxtValidation> {
+  Ok(_validate_robots_txt(&content))
+}
+
+/// Compiles an `excludes`/`includes` entry. Patterns without regex syntax
+/// are treated as wildcards (`*.pdf`, `/blog/*`, `/docs$`), where `*` matches
+/// anything and a trailing `$` anchors the end.
+fn compile_pattern(pattern: &str) -> Option<Regex> {
+  if is_wildcard_pattern(pattern) {
+    Regex::new(&wildcard_to_regex(pattern)).ok()
+  } else {
+    Regex::new(pattern).ok()
+  }
+}
+
+#[inline]
+fn is_wildcard_pattern(pattern: &str) -> bool {
+  const REGEX_SIGNALS: &[char] = &['(', ')', '[', ']', '{', '}', '^', '|', '\\', '?', '+'];
+
+  // `.*` is the usual regex spelling of a wildcard, so leave it alone.
+  (pattern.contains('*') || pattern.contains('$'))
+    && !pattern.contains(REGEX_SIGNALS)
+    && !pattern.contains(".*")
+}
+
+fn wildcard_to_regex(pattern: &str) -> String {
+  let (body, anchored) = match pattern.strip_suffix('$') {
+    Some(body) => (body, true),
+    None => (pattern, false),
+  };
+
+  let mut out = body
+    .split('*')
+    .map(regex::escape)
+    .collect::<Vec<_>>()
+    .join(".*");
+  if anchored {
+    out.push('$');
+  }
+  out
+}
+
+/// Removes the named query parameters from `url`. Returns whether anything
+/// was removed.
+fn strip_query_params(url: &mut Url, params: &[String]) -> bool {
+  if params.is_empty() {
+    return false;
+  }
+
+  retain_query_pairs(url, |key| !params.iter().any(|p| p == key))
+}
+
+/// Removes every query parameter not named in `allowed` from `url`. Returns
+/// whether anything was removed.
+fn allow_query_params(url: &mut Url, allowed: &[String]) -> bool {
+  retain_query_pairs(url, |key| allowed.iter().any(|p| p == key))
+}
+
+fn retain_query_pairs(url: &mut Url, keep: impl Fn(&str) -> bool) -> bool {
+  if url.query().is_none() {
+    return false;
+  }
+
+  let pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+  let kept: Vec<&(String, String)> = pairs.iter().filter(|(key, _)| keep(key)).collect();
+
+  if kept.len() == pairs.len() {
+    return false;
+  }
+
+  if kept.is_empty() {
+    url.set_query(None);
+  } else {
+    url.query_pairs_mut().clear().extend_pairs(kept);
+  }
+  true
+}
+
+/// Dedup keys of the links `filter_links` has accepted so far. Keys are only
+/// recorded on acceptance, so a link denied for another reason doesn't
+/// shadow a later variant of it.
+#[derive(Default)]
+struct SeenLinks {
+  stripped: HashSet<String>,
+  fingerprints: HashSet<u64>,
+  without_query: HashSet<u64>,
+}
+
+/// The keys a single link is deduplicated on, per the enabled options.
+struct LinkKeys {
+  stripped: Option<String>,
+  fingerprint: Option<u64>,
+  without_query: Option<u64>,
+}
+
+impl SeenLinks {
+  fn duplicate_reason(&self, keys: &LinkKeys) -> Option<&'static str> {
+    if keys
+      .stripped
+      .as_ref()
+      .is_some_and(|k| self.stripped.contains(k))
+      || keys
+        .fingerprint
+        .is_some_and(|k| self.fingerprints.contains(&k))
+    {
+      return Some(DUPLICATE_URL);
+    }
+    if keys
+      .without_query
+      .is_some_and(|k| self.without_query.contains(&k))
+    {
+      return Some(DUPLICATE_CONTENT);
+    }
+    None
+  }
+
+  fn record(&mut self, keys: LinkKeys) {
+    if let Some(k) = keys.stripped {
+      self.stripped.insert(k);
+    }
+    if let Some(k) = keys.fingerprint {
+      self.fingerprints.insert(k);
+    }
+    if let Some(k) = keys.without_query {
+      self.without_query.insert(k);
+    }
+  }
+}
+
 fn build_robot(
   ignore_robots_txt: bool,
   robots_txt: &str,
@@ -251,6 +665,10 @@ fn _filter_links(data: FilterLinksCall) -> std::result::Result<FilterLinksResult
     return Ok(FilterLinksResult {
       links: Vec::new(),
       denial_reasons: HashMap::new(),
+      normalised: HashMap::new(),
+      crawl_delay_seconds: None,
+      accepted_count: 0,
+      denied_by_reason: HashMap::new(),
     });
   }
 
@@ -262,12 +680,12 @@ fn _filter_links(data: FilterLinksCall) -> std::result::Result<FilterLinksResult
   let excludes_regex: Vec<Regex> = data
     .excludes
     .iter()
-    .filter_map(|e| Regex::new(e).ok())
+    .filter_map(|e| compile_pattern(e))
     .collect();
   let includes_regex: Vec<Regex> = data
     .includes
     .iter()
-    .filter_map(|i| Regex::new(i).ok())
+    .filter_map(|i| compile_pattern(i))
     .collect();
 
   let robot = build_robot(
@@ -276,15 +694,33 @@ fn _filter_links(data: FilterLinksCall) -> std::result::Result<FilterLinksResult
     data.robots_user_agent.as_deref(),
   );
 
+  let crawl_delay_seconds = if data.respect_crawl_delay {
+    robot.as_ref().and_then(|r| r.delay).map(f64::from)
+  } else {
+    None
+  };
+
+  let hash_route_pattern = match data.hash_route_pattern.as_deref() {
+    Some(pattern) => {
+      Some(Regex::new(pattern).map_err(|e| format!("Hash route pattern parse error: {e}"))?)
+    }
+    None => None,
+  };
+
+  let strip_params = data.strip_query_params.unwrap_or_default();
+  let allowed_params = data.allow_query_parameters;
+
   let mut result_links = Vec::new();
   let mut denial_reasons = HashMap::new();
+  let mut normalised = HashMap::new();
+  let mut seen = SeenLinks::default();
 
   for link in data.links {
     if result_links.len() >= limit {
       break;
     }
 
-    let url = match base_url.join(&link) {
+    let mut url = match base_url.join(&link) {
       Ok(url) => url,
       Err(_) => {
         denial_reasons.insert(link, URL_PARSE_ERROR.to_string());
@@ -292,9 +728,46 @@ fn _filter_links(data: FilterLinksCall) -> std::result::Result<FilterLinksResult
       }
     };
 
+    let mut stripped = strip_query_params(&mut url, &strip_params);
+    if let Some(ref allowed) = allowed_params {
+      stripped |= allow_query_params(&mut url, allowed);
+    }
+    let keys = LinkKeys {
+      stripped: data.deduplicate_stripped.then(|| url.to_string()),
+      fingerprint: data
+        .deduplicate_fingerprints
+        .then(|| compute_url_fingerprint(&url)),
+      without_query: data.strip_query_params_for_dedup.then(|| {
+        let mut without_query = url.clone();
+        without_query.set_query(None);
+        compute_url_fingerprint(&without_query)
+      }),
+    };
+
+    let link = if data.deduplicate_stripped {
+      link
+    } else if stripped {
+      url.to_string()
+    } else {
+      link
+    };
+
+    if let Some(reason) = seen.duplicate_reason(&keys) {
+      denial_reasons.insert(link, reason.to_string());
+      continue;
+    }
+
     let path = url.path();
     let url_str = url.as_str();
 
+    if data
+      .max_url_length
+      .is_some_and(|max| url_str.len() > max as usize)
+    {
+      denial_reasons.insert(link, URL_TOO_LONG.to_string());
+      continue;
+    }
+
     if is_non_web_protocol(url_str) {
       denial_reasons.insert(link, NON_WEB_PROTOCOL.to_string());
       continue;
@@ -305,14 +778,19 @@ fn _filter_links(data: FilterLinksCall) -> std::result::Result<FilterLinksResult
       continue;
     }
 
-    if is_file(path) {
+    if is_document(path) {
+      if !data.allow_document_links {
+        denial_reasons.insert(link, DOCUMENT_TYPE.to_string());
+        continue;
+      }
+    } else if is_file(path) {
       denial_reasons.insert(link, FILE_TYPE.to_string());
       continue;
     }
 
     if is_internal_link(&url, &base_url) {
       // INTERNAL LINKS
-      if !no_sections(url_str) {
+      if !no_sections(url_str, data.allow_hash_routes, hash_route_pattern.as_ref()) {
         denial_reasons.insert(link, SECTION_LINK.to_string());
         continue;
       }
@@ -345,10 +823,12 @@ fn _filter_links(data: FilterLinksCall) -> std::result::Result<FilterLinksResult
         }
       }
 
+      normalised.insert(link.clone(), normalise_url(&url));
+      seen.record(keys);
       result_links.push(link);
     } else {
       // EXTERNAL LINKS
-      if is_social_media_or_email(url_str) {
+      if is_social_media_or_email(url_str, &data.additional_blocked_domains) {
         denial_reasons.insert(link, SOCIAL_MEDIA.to_string());
         continue;
       }
@@ -362,12 +842,14 @@ fn _filter_links(data: FilterLinksCall) -> std::result::Result<FilterLinksResult
         && data.allow_external_content_links
         && !is_external_main_page(url_str)
       {
+        normalised.insert(link.clone(), normalise_url(&url));
+        seen.record(keys);
         result_links.push(link);
         continue;
       }
 
       if data.allow_subdomains
-        && !is_social_media_or_email(url_str)
+        && !is_social_media_or_email(url_str, &data.additional_blocked_domains)
         && is_subdomain(&url, &base_url)
       {
         // When allowing subdomains, still honor include patterns
@@ -380,6 +862,8 @@ fn _filter_links(data: FilterLinksCall) -> std::result::Result<FilterLinksResult
           denial_reasons.insert(link, INCLUDE_PATTERN.to_string());
           continue;
         }
+        normalised.insert(link.clone(), normalise_url(&url));
+        seen.record(keys);
         result_links.push(link);
         continue;
       }
@@ -388,9 +872,18 @@ fn _filter_links(data: FilterLinksCall) -> std::result::Result<FilterLinksResult
     }
   }
 
+  let mut denied_by_reason = HashMap::new();
+  for reason in denial_reasons.values() {
+    *denied_by_reason.entry(reason.clone()).or_insert(0) += 1;
+  }
+
   Ok(FilterLinksResult {
+    accepted_count: result_links.len() as u32,
     links: result_links,
     denial_reasons,
+    normalised,
+    crawl_delay_seconds,
+    denied_by_reason,
   })
 }
 
@@ -435,7 +928,7 @@ fn _filter_url(data: FilterUrlCall) -> std::result::Result<FilterUrlResult, Stri
     }
   }
 
-  let url = match Url::parse(&full_url) {
+  let mut url = match Url::parse(&full_url) {
     Ok(url) => url,
     Err(_) => {
       return Ok(FilterUrlResult {
@@ -446,6 +939,18 @@ fn _filter_url(data: FilterUrlCall) -> std::result::Result<FilterUrlResult, Stri
     }
   };
 
+  if let Some(ref params) = data.strip_query_params {
+    if strip_query_params(&mut url, params) {
+      full_url = url.to_string();
+    }
+  }
+
+  if let Some(ref allowed) = data.allow_query_parameters {
+    if allow_query_params(&mut url, allowed) {
+      full_url = url.to_string();
+    }
+  }
+
   let base_url = match Url::parse(&data.base_url) {
     Ok(url) => url,
     Err(_) => {
@@ -471,7 +976,7 @@ fn _filter_url(data: FilterUrlCall) -> std::result::Result<FilterUrlResult, Stri
   let excludes_regex: Vec<Regex> = data
     .excludes
     .iter()
-    .filter_map(|e| Regex::new(e).ok())
+    .filter_map(|e| compile_pattern(e))
     .collect();
 
   let robot = build_robot(
@@ -482,7 +987,7 @@ fn _filter_url(data: FilterUrlCall) -> std::result::Result<FilterUrlResult, Stri
 
   if is_internal_link(&url, &base_url) {
     // INTERNAL LINKS
-    if !no_sections(url_str) {
+    if !no_sections(url_str, false, None) {
       return Ok(FilterUrlResult {
         allowed: false,
         url: None,
@@ -515,7 +1020,7 @@ fn _filter_url(data: FilterUrlCall) -> std::result::Result<FilterUrlResult, Stri
     })
   } else {
     // EXTERNAL LINKS
-    if is_social_media_or_email(url_str) {
+    if is_social_media_or_email(url_str, &data.additional_blocked_domains) {
       return Ok(FilterUrlResult {
         allowed: false,
         url: None,
@@ -553,7 +1058,9 @@ fn _filter_url(data: FilterUrlCall) -> std::result::Result<FilterUrlResult, Stri
       });
     }
 
-    if data.allow_subdomains && !is_social_media_or_email(url_str) && is_subdomain(&url, &base_url)
+    if data.allow_subdomains
+      && !is_social_media_or_email(url_str, &data.additional_blocked_domains)
+      && is_subdomain(&url, &base_url)
     {
       return Ok(FilterUrlResult {
         allowed: true,
@@ -585,6 +1092,46 @@ pub async fn filter_url(data: FilterUrlCall) -> Result<FilterUrlResult> {
   res.map_err(|e| Error::new(Status::GenericFailure, format!("Filter URL error: {e}")))
 }
 
+fn sitemap_child_text<'a>(node: roxmltree::Node<'a, '_>, name: &str) -> Option<&'a str> {
+  node
+    .children()
+    .find(|n| n.is_element() && n.tag_name().name() == name)
+    .and_then(|n| n.text())
+}
+
+const SITEMAP_NEWS_NAMESPACE: &str = "http://www.google.com/schemas/sitemap-news/0.9";
+
+fn sitemap_news_entry(url_node: roxmltree::Node) -> Option<SitemapNewsEntry> {
+  let news = url_node.children().find(|n| {
+    n.is_element()
+      && n.tag_name().name() == "news"
+      && n.tag_name().namespace() == Some(SITEMAP_NEWS_NAMESPACE)
+  })?;
+  let text = |node: roxmltree::Node, name: &str| {
+    sitemap_child_text(node, name)
+      .map(str::trim)
+      .filter(|x| !x.is_empty())
+      .map(str::to_string)
+  };
+
+  Some(SitemapNewsEntry {
+    title: text(news, "title"),
+    publication_date: text(news, "publication_date"),
+    keywords: text(news, "keywords").map(|keywords| {
+      keywords
+        .split(',')
+        .map(str::trim)
+        .filter(|k| !k.is_empty())
+        .map(str::to_string)
+        .collect()
+    }),
+    language: news
+      .children()
+      .find(|n| n.is_element() && n.tag_name().name() == "publication")
+      .and_then(|publication| text(publication, "language")),
+  })
+}
+
 fn _parse_sitemap_xml(xml_content: &str) -> std::result::Result<ParsedSitemap, String> {
   let doc = roxmltree::Document::parse_with_options(
     xml_content,
@@ -622,13 +1169,15 @@ fn _parse_sitemap_xml(xml_content: &str) -> std::result::Result<ParsedSitemap, S
         .children()
         .filter(|n| n.is_element() && n.tag_name().name() == "url")
         .filter_map(|url_node| {
-          url_node
-            .children()
-            .find(|n| n.is_element() && n.tag_name().name() == "loc")
-            .and_then(|loc_node| loc_node.text())
-            .map(|loc_text| SitemapUrl {
-              loc: vec![loc_text.to_string()],
-            })
+          sitemap_child_text(url_node, "loc").map(|loc_text| SitemapUrl {
+            loc: vec![loc_text.to_string()],
+            last_mod: sitemap_child_text(url_node, "lastmod").map(|x| x.trim().to_string()),
+            change_freq: sitemap_child_text(url_node, "changefreq")
+              .map(|x| x.trim().to_ascii_lowercase()),
+            priority: sitemap_child_text(url_node, "priority")
+              .and_then(|x| x.trim().parse::<f64>().ok()),
+            news: sitemap_news_entry(url_node),
+          })
         })
         .collect();
 
@@ -685,25 +1234,35 @@ fn _process_sitemap(xml_content: &str) -> std::result::Result<SitemapProcessingR
         action: "recurse".to_string(),
         urls: sitemap_urls,
         count,
+        metadata: Vec::new(),
       });
       total_count += count;
     }
   } else if let Some(urlset) = parsed.urlset {
     let mut xml_sitemaps = Vec::new();
+    let mut xml_sitemaps_metadata = Vec::new();
     let mut valid_urls = Vec::new();
+    let mut valid_urls_metadata = Vec::new();
 
     for url_entry in urlset.url {
       if !url_entry.loc.is_empty() {
-        let url = url_entry.loc[0].trim();
+        let url = url_entry.loc[0].trim().to_string();
         let url_lower = url.to_lowercase();
-        if url_lower.ends_with(".xml") || url_lower.ends_with(".xml.gz") {
-          xml_sitemaps.push(url.to_string());
-        } else if let Ok(parsed_url) = Url::parse(url) {
-          let path_lower = parsed_url.path().to_lowercase();
-          if !is_file(&path_lower) {
-            valid_urls.push(url.to_string());
-          }
-        }
+        let (urls, metadata) = if url_lower.ends_with(".xml") || url_lower.ends_with(".xml.gz") {
+          (&mut xml_sitemaps, &mut xml_sitemaps_metadata)
+        } else if Url::parse(&url).is_ok_and(|u| !is_file(&u.path().to_lowercase())) {
+          (&mut valid_urls, &mut valid_urls_metadata)
+        } else {
+          continue;
+        };
+
+        metadata.push(SitemapUrlMetadata {
+          url: url.clone(),
+          last_mod: url_entry.last_mod,
+          change_freq: url_entry.change_freq,
+          priority: url_entry.priority,
+        });
+        urls.push(url);
       }
     }
 
@@ -713,6 +1272,7 @@ fn _process_sitemap(xml_content: &str) -> std::result::Result<SitemapProcessingR
         action: "recurse".to_string(),
         urls: xml_sitemaps,
         count,
+        metadata: xml_sitemaps_metadata,
       });
       total_count += count;
     }
@@ -723,6 +1283,7 @@ fn _process_sitemap(xml_content: &str) -> std::result::Result<SitemapProcessingR
         action: "process".to_string(),
         urls: valid_urls,
         count,
+        metadata: valid_urls_metadata,
       });
       total_count += count;
     }
@@ -749,10 +1310,164 @@ pub async fn process_sitemap(xml_content: String) -> Result<SitemapProcessingRes
   res.map_err(|e| Error::new(Status::GenericFailure, format!("Parse sitemap error: {e}")))
 }
 
+/// Process several sitemaps concurrently, returning one result per input in
+/// the same order.
+#[napi]
+pub async fn process_sitemap_batch(
+  xml_contents: Vec<String>,
+) -> Result<Vec<SitemapProcessingResult>> {
+  let handles: Vec<_> = xml_contents
+    .into_iter()
+    .map(|xml_content| task::spawn_blocking(move || _process_sitemap(&xml_content)))
+    .collect();
+
+  let mut results = Vec::with_capacity(handles.len());
+  for (i, handle) in handles.into_iter().enumerate() {
+    let res = handle.await.map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("process_sitemap_batch join error: {e}"),
+      )
+    })?;
+    results.push(res.map_err(|e| {
+      Error::new(
+        Status::GenericFailure,
+        format!("Parse sitemap error at index {i}: {e}"),
+      )
+    })?);
+  }
+
+  Ok(results)
+}
+
+/// Merge sitemap results into one, with a single instruction per action.
+/// URLs are deduplicated across results, keeping the first occurrence and its
+/// metadata; `total_count` is the number of merged URLs.
+#[napi]
+pub fn merge_sitemap_results(results: Vec<SitemapProcessingResult>) -> SitemapProcessingResult {
+  let mut merged: Vec<SitemapInstruction> = Vec::new();
+  let mut seen: HashMap<String, HashSet<String>> = HashMap::new();
+
+  for instruction in results.into_iter().flat_map(|r| r.instructions) {
+    let seen = seen.entry(instruction.action.clone()).or_default();
+    let target = match merged.iter().position(|x| x.action == instruction.action) {
+      Some(i) => &mut merged[i],
+      None => {
+        merged.push(SitemapInstruction {
+          action: instruction.action.clone(),
+          urls: Vec::new(),
+          count: 0,
+          metadata: Vec::new(),
+        });
+        merged.last_mut().unwrap()
+      }
+    };
+
+    let mut metadata: HashMap<String, SitemapUrlMetadata> = instruction
+      .metadata
+      .into_iter()
+      .map(|m| (m.url.clone(), m))
+      .collect();
+    for url in instruction.urls {
+      if seen.insert(url.clone()) {
+        target.metadata.extend(metadata.remove(&url));
+        target.urls.push(url);
+      }
+    }
+    target.count = target.urls.len() as u32;
+  }
+
+  merged.retain(|x| !x.urls.is_empty());
+  let total_count = merged.iter().map(|x| x.count).sum();
+  SitemapProcessingResult {
+    instructions: merged,
+    total_count,
+  }
+}
+
+/// Upper bound on a decompressed sitemap; the protocol caps them at 50MB.
+const MAX_DECOMPRESSED_SITEMAP_BYTES: u64 = 100 * 1024 * 1024;
+
+#[inline]
+fn is_gzip(data: &[u8]) -> bool {
+  data.starts_with(&[0x1f, 0x8b])
+}
+
+/// Processes a sitemap that may be gzip-compressed. Data without the gzip
+/// magic bytes is treated as plain XML.
+fn process_sitemap_gzip(gz_bytes: &[u8]) -> std::result::Result<SitemapProcessingResult, String> {
+  if !is_gzip(gz_bytes) {
+    let xml_content =
+      std::str::from_utf8(gz_bytes).map_err(|e| format!("Sitemap is not valid UTF-8: {e}"))?;
+    return _process_sitemap(xml_content);
+  }
+
+  let mut xml_content = String::new();
+  MultiGzDecoder::new(gz_bytes)
+    .take(MAX_DECOMPRESSED_SITEMAP_BYTES + 1)
+    .read_to_string(&mut xml_content)
+    .map_err(|e| format!("Gzip decompression error: {e}"))?;
+
+  if xml_content.len() as u64 > MAX_DECOMPRESSED_SITEMAP_BYTES {
+    return Err("Decompressed sitemap exceeds size limit".to_string());
+  }
+
+  _process_sitemap(&xml_content)
+}
+
+/// Process a gzip-compressed (`.xml.gz`) or plain XML sitemap buffer and
+/// extract crawling instructions.
+#[napi]
+pub async fn process_sitemap_gz(data: Buffer) -> Result<SitemapProcessingResult> {
+  let bytes = data.to_vec();
+  let res = task::spawn_blocking(move || process_sitemap_gzip(&bytes))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("process_sitemap_gz join error: {e}"),
+      )
+    })?;
+
+  res.map_err(|e| Error::new(Status::GenericFailure, format!("Parse sitemap error: {e}")))
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  /// Same-origin `filter_links` call with robots.txt ignored and every
+  /// optional filter off; tests override only the fields they exercise.
+  fn base() -> FilterLinksCall {
+    FilterLinksCall {
+      links: vec![],
+      limit: Some(10),
+      includes: vec![],
+      excludes: vec![],
+      ignore_robots_txt: true,
+      robots_txt: String::new(),
+      max_depth: 10,
+      base_url: "https://example.com".to_string(),
+      initial_url: "https://example.com".to_string(),
+      regex_on_full_url: false,
+      allow_backward_crawling: true,
+      allow_external_content_links: false,
+      allow_subdomains: false,
+      allow_hash_routes: false,
+      hash_route_pattern: None,
+      respect_crawl_delay: false,
+      max_url_length: None,
+      strip_query_params: None,
+      allow_query_parameters: None,
+      deduplicate_stripped: false,
+      deduplicate_fingerprints: false,
+      additional_blocked_domains: vec![],
+      strip_query_params_for_dedup: false,
+      allow_document_links: false,
+      robots_user_agent: None,
+    }
+  }
+
   #[test]
   fn test_parse_sitemap_xml_urlset() {
     let xml_content = r#"<?xml version="1.0" encoding="UTF-8"?>
@@ -865,13 +1580,83 @@ mod tests {
   }
 
   #[test]
-  fn test_process_sitemap_sitemapindex() {
+  fn test_parse_sitemap_news_extension() {
     let xml_content = r#"<?xml version="1.0" encoding="UTF-8"?>
-<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
-  <sitemap>
-    <loc>https://example.com/sitemap1.xml</loc>
-  </sitemap>
-  <sitemap>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9"
+        xmlns:news="http://www.google.com/schemas/sitemap-news/0.9">
+  <url>
+    <loc>https://example.com/story</loc>
+    <news:news>
+      <news:publication>
+        <news:name>Example Times</news:name>
+        <news:language>en</news:language>
+      </news:publication>
+      <news:publication_date>2024-05-01T08:00:00+00:00</news:publication_date>
+      <news:title>Big Story</news:title>
+      <news:keywords>politics, economy ,</news:keywords>
+    </news:news>
+  </url>
+  <url>
+    <loc>https://example.com/about</loc>
+  </url>
+</urlset>"#;
+
+    let urls = _parse_sitemap_xml(xml_content).unwrap().urlset.unwrap().url;
+    let news = urls[0].news.as_ref().unwrap();
+    assert_eq!(news.title.as_deref(), Some("Big Story"));
+    assert_eq!(
+      news.publication_date.as_deref(),
+      Some("2024-05-01T08:00:00+00:00")
+    );
+    assert_eq!(
+      news.keywords,
+      Some(vec!["politics".to_string(), "economy".to_string()])
+    );
+    assert_eq!(news.language.as_deref(), Some("en"));
+    assert!(urls[1].news.is_none());
+  }
+
+  #[test]
+  fn test_process_sitemap_urlset_metadata() {
+    let xml_content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url>
+    <loc>https://example.com/page1</loc>
+    <lastmod>2024-05-01</lastmod>
+    <changefreq>Weekly</changefreq>
+    <priority>0.8</priority>
+  </url>
+  <url>
+    <loc>https://example.com/page2</loc>
+    <priority>high</priority>
+  </url>
+</urlset>"#;
+
+    let parsed = _parse_sitemap_xml(xml_content).unwrap();
+    let urls = parsed.urlset.unwrap().url;
+    assert_eq!(urls[0].last_mod.as_deref(), Some("2024-05-01"));
+    assert_eq!(urls[0].change_freq.as_deref(), Some("weekly"));
+    assert_eq!(urls[0].priority, Some(0.8));
+    assert_eq!(urls[1].last_mod, None);
+    assert_eq!(urls[1].priority, None);
+
+    let result = _process_sitemap(xml_content).unwrap();
+    let metadata = &result.instructions[0].metadata;
+    assert_eq!(metadata.len(), 2);
+    assert_eq!(metadata[0].url, "https://example.com/page1");
+    assert_eq!(metadata[0].last_mod.as_deref(), Some("2024-05-01"));
+    assert_eq!(metadata[1].url, "https://example.com/page2");
+    assert_eq!(metadata[1].change_freq, None);
+  }
+
+  #[test]
+  fn test_process_sitemap_sitemapindex() {
+    let xml_content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <sitemap>
+    <loc>https://example.com/sitemap1.xml</loc>
+  </sitemap>
+  <sitemap>
     <loc>https://example.com/sitemap2.xml</loc>
   </sitemap>
 </sitemapindex>"#;
@@ -897,19 +1682,9 @@ mod tests {
         "https://example.com/allowed".to_string(),
         "https://example.com/disallowed".to_string(),
       ],
-      limit: Some(10),
-      includes: vec![],
-      excludes: vec![],
       ignore_robots_txt: false,
       robots_txt: "User-agent: *\nDisallow: /disallowed".to_string(),
-      max_depth: 10,
-      base_url: "https://example.com".to_string(),
-      initial_url: "https://example.com".to_string(),
-      regex_on_full_url: false,
-      allow_backward_crawling: true,
-      allow_external_content_links: false,
-      allow_subdomains: false,
-      robots_user_agent: None,
+      ..base()
     };
 
     let result = _filter_links(data).unwrap();
@@ -931,20 +1706,10 @@ mod tests {
   fn test_filter_links_malformed_robots_txt() {
     let data = FilterLinksCall {
       links: vec!["https://example.com/test".to_string()],
-      limit: Some(10),
-      includes: vec![],
-      excludes: vec![],
       ignore_robots_txt: false,
       robots_txt: "Invalid robots.txt content with \x00 null bytes and malformed syntax"
         .to_string(),
-      max_depth: 10,
-      base_url: "https://example.com".to_string(),
-      initial_url: "https://example.com".to_string(),
-      regex_on_full_url: false,
-      allow_backward_crawling: true,
-      allow_external_content_links: false,
-      allow_subdomains: false,
-      robots_user_agent: None,
+      ..base()
     };
 
     let result = _filter_links(data);
@@ -962,19 +1727,9 @@ mod tests {
 
     let data = FilterLinksCall {
       links: vec!["https://example.com/allowed".to_string()],
-      limit: Some(10),
-      includes: vec![],
-      excludes: vec![],
       ignore_robots_txt: false,
       robots_txt: non_utf8_string,
-      max_depth: 10,
-      base_url: "https://example.com".to_string(),
-      initial_url: "https://example.com".to_string(),
-      regex_on_full_url: false,
-      allow_backward_crawling: true,
-      allow_external_content_links: false,
-      allow_subdomains: false,
-      robots_user_agent: None,
+      ..base()
     };
 
     let result = _filter_links(data);
@@ -990,19 +1745,9 @@ mod tests {
 
     let data = FilterLinksCall {
       links: vec!["https://example.com/test".to_string()],
-      limit: Some(10),
-      includes: vec![],
-      excludes: vec![],
       ignore_robots_txt: false,
       robots_txt: problematic_content.to_string(),
-      max_depth: 10,
-      base_url: "https://example.com".to_string(),
-      initial_url: "https://example.com".to_string(),
-      regex_on_full_url: false,
-      allow_backward_crawling: true,
-      allow_external_content_links: false,
-      allow_subdomains: false,
-      robots_user_agent: None,
+      ..base()
     };
 
     let result = _filter_links(data);
@@ -1021,19 +1766,9 @@ mod tests {
         "https://other.example.com/pricing".to_string(),
         "https://example.com/pricing".to_string(),
       ],
-      limit: Some(10),
       includes: vec!["^/pricing$".to_string()],
-      excludes: vec![],
-      ignore_robots_txt: true,
-      robots_txt: "".to_string(),
-      max_depth: 10,
-      base_url: "https://example.com".to_string(),
-      initial_url: "https://example.com".to_string(),
-      regex_on_full_url: false,
-      allow_backward_crawling: true,
-      allow_external_content_links: false,
       allow_subdomains: true,
-      robots_user_agent: None,
+      ..base()
     };
 
     let result = _filter_links(data).unwrap();
@@ -1070,19 +1805,10 @@ mod tests {
 
     let base_call = |ua: Option<String>| FilterLinksCall {
       links: vec!["https://example.com/page".to_string()],
-      limit: Some(10),
-      includes: vec![],
-      excludes: vec![],
       ignore_robots_txt: false,
       robots_txt: robots_txt.to_string(),
-      max_depth: 10,
-      base_url: "https://example.com".to_string(),
-      initial_url: "https://example.com".to_string(),
-      regex_on_full_url: false,
-      allow_backward_crawling: true,
-      allow_external_content_links: false,
-      allow_subdomains: false,
       robots_user_agent: ua,
+      ..base()
     };
 
     let default_result = _filter_links(base_call(None)).unwrap();
@@ -1107,4 +1833,718 @@ mod tests {
     assert!(!is_file("page"));
     assert!(!is_file("directory/"));
   }
+
+  #[test]
+  fn test_is_document() {
+    assert!(is_document("/files/report.pdf"));
+    assert!(is_document("/files/deck.pptx"));
+    assert!(is_document("/book.epub"));
+    assert!(!is_document("/image.png"));
+    assert!(!is_document("/docs"));
+  }
+
+  #[test]
+  fn test_no_sections_without_fragment() {
+    assert!(no_sections("https://example.com/page", false, None));
+  }
+
+  #[test]
+  fn test_no_sections_blocks_plain_anchor() {
+    assert!(!no_sections(
+      "https://example.com/page#section_title",
+      false,
+      None
+    ));
+    assert!(!no_sections(
+      "https://example.com/page#top.of.page",
+      false,
+      None
+    ));
+    assert!(!no_sections("https://example.com/page#a%20b", false, None));
+    assert!(!no_sections("https://example.com/page#", false, None));
+    assert!(!no_sections("https://example.com/page#/", false, None));
+  }
+
+  #[test]
+  fn test_no_sections_allows_alphanumeric_hyphen_routes() {
+    assert!(no_sections(
+      "https://example.com/page#section-title",
+      false,
+      None
+    ));
+    assert!(no_sections("https://example.com/#pricing", false, None));
+    assert!(no_sections("https://example.com/#page2", false, None));
+    assert!(no_sections(
+      "https://example.com/#Contact-Us-2",
+      false,
+      None
+    ));
+  }
+
+  #[test]
+  fn test_no_sections_allows_slash_routes() {
+    assert!(no_sections(
+      "https://example.com/#/path/to/route",
+      false,
+      None
+    ));
+    assert!(no_sections("https://example.com/#docs/intro", false, None));
+  }
+
+  #[test]
+  fn test_no_sections_allows_hashbang_routes() {
+    assert!(no_sections("https://example.com/#!/page", false, None));
+    assert!(no_sections("https://example.com/#!page", false, None));
+    assert!(!no_sections("https://example.com/#!", false, None));
+    assert!(!no_sections("https://example.com/#!/", false, None));
+  }
+
+  #[test]
+  fn test_no_sections_allow_hash_routes() {
+    assert!(no_sections(
+      "https://example.com/page#section-title",
+      true,
+      None
+    ));
+    assert!(no_sections("https://example.com/page#", true, None));
+  }
+
+  #[test]
+  fn test_no_sections_hash_route_pattern() {
+    let pattern = Regex::new(r"^page-\d+$").unwrap();
+    assert!(no_sections(
+      "https://example.com/#page-2",
+      false,
+      Some(&pattern)
+    ));
+    assert!(!no_sections(
+      "https://example.com/#page_two",
+      false,
+      Some(&pattern)
+    ));
+  }
+
+  #[test]
+  fn test_filter_links_hash_routes() {
+    let call = |allow_hash_routes: bool| FilterLinksCall {
+      links: vec![
+        "https://example.com/#!/pricing".to_string(),
+        "https://example.com/#pricing".to_string(),
+        "https://example.com/#pricing_table".to_string(),
+      ],
+      allow_hash_routes,
+      ..base()
+    };
+
+    let result = _filter_links(call(false)).unwrap();
+    assert_eq!(
+      result.links,
+      vec![
+        "https://example.com/#!/pricing",
+        "https://example.com/#pricing"
+      ]
+    );
+    assert_eq!(
+      result
+        .denial_reasons
+        .get("https://example.com/#pricing_table")
+        .unwrap(),
+      "SECTION_LINK"
+    );
+
+    let result = _filter_links(call(true)).unwrap();
+    assert_eq!(result.links.len(), 3);
+  }
+
+  #[test]
+  fn test_normalise_url_lowercases_host() {
+    let url = Url::parse("https://Example.COM/Path").unwrap();
+    assert_eq!(normalise_url(&url), "https://example.com/Path");
+  }
+
+  #[test]
+  fn test_normalise_url_removes_default_port() {
+    let url = Url::parse("https://example.com:443/page").unwrap();
+    assert_eq!(normalise_url(&url), "https://example.com/page");
+    let url = Url::parse("http://example.com:8080/page").unwrap();
+    assert_eq!(normalise_url(&url), "http://example.com:8080/page");
+  }
+
+  #[test]
+  fn test_normalise_url_strips_trailing_slash() {
+    let url = Url::parse("https://example.com/blog/").unwrap();
+    assert_eq!(normalise_url(&url), "https://example.com/blog");
+    let url = Url::parse("https://example.com/blog//?page=2").unwrap();
+    assert_eq!(normalise_url(&url), "https://example.com/blog?page=2");
+    let url = Url::parse("https://example.com/").unwrap();
+    assert_eq!(normalise_url(&url), "https://example.com/");
+  }
+
+  #[test]
+  fn test_normalise_url_removes_empty_fragment() {
+    let url = Url::parse("https://example.com/page#").unwrap();
+    assert_eq!(normalise_url(&url), "https://example.com/page");
+    let url = Url::parse("https://example.com/#/route").unwrap();
+    assert_eq!(normalise_url(&url), "https://example.com/#/route");
+  }
+
+  #[test]
+  fn test_filter_links_returns_normalised_links() {
+    let data = FilterLinksCall {
+      links: vec![
+        "https://EXAMPLE.com:443/docs/".to_string(),
+        "/about".to_string(),
+      ],
+      ..base()
+    };
+
+    let result = _filter_links(data).unwrap();
+    assert_eq!(result.links.len(), 2);
+    assert_eq!(
+      result
+        .normalised
+        .get("https://EXAMPLE.com:443/docs/")
+        .unwrap(),
+      "https://example.com/docs"
+    );
+    assert_eq!(
+      result.normalised.get("/about").unwrap(),
+      "https://example.com/about"
+    );
+  }
+
+  #[test]
+  fn test_filter_links_crawl_delay() {
+    let call = |respect_crawl_delay: bool| FilterLinksCall {
+      links: vec!["https://example.com/page".to_string()],
+      ignore_robots_txt: false,
+      robots_txt: "User-agent: *\nCrawl-delay: 2.5\nDisallow: /private".to_string(),
+      respect_crawl_delay,
+      ..base()
+    };
+
+    let result = _filter_links(call(true)).unwrap();
+    assert_eq!(result.crawl_delay_seconds, Some(2.5));
+    assert_eq!(result.links, vec!["https://example.com/page"]);
+
+    let result = _filter_links(call(false)).unwrap();
+    assert_eq!(result.crawl_delay_seconds, None);
+  }
+
+  #[test]
+  fn test_wildcard_patterns() {
+    let pdf = compile_pattern("*.pdf").unwrap();
+    assert!(pdf.is_match("/files/report.pdf"));
+    assert!(!pdf.is_match("/files/reportxpdf"));
+
+    let blog = compile_pattern("/blog/*").unwrap();
+    assert!(blog.is_match("/blog/post-1"));
+    assert!(!blog.is_match("/news/post-1"));
+
+    let anchored = compile_pattern("/docs$").unwrap();
+    assert!(anchored.is_match("/docs"));
+    assert!(!anchored.is_match("/docs/intro"));
+
+    let literal_dollar = compile_pattern("/price$/*").unwrap();
+    assert!(literal_dollar.is_match("/price$/usd"));
+  }
+
+  #[test]
+  fn test_regex_patterns_are_untouched() {
+    assert!(!is_wildcard_pattern("/blog/.*"));
+    assert!(!is_wildcard_pattern("^/blog/(a|b)$"));
+    assert!(!is_wildcard_pattern("/posts/\\d+"));
+    assert!(!is_wildcard_pattern("/blog/"));
+
+    let regex = compile_pattern("^/blog/[0-9]+$").unwrap();
+    assert!(regex.is_match("/blog/42"));
+    assert!(!regex.is_match("/blog/abc"));
+  }
+
+  #[test]
+  fn test_process_sitemap_gzip() {
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::Write;
+
+    let xml_content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url>
+    <loc>https://example.com/page1</loc>
+  </url>
+</urlset>"#;
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(xml_content.as_bytes()).unwrap();
+    let gz_bytes = encoder.finish().unwrap();
+    assert!(is_gzip(&gz_bytes));
+
+    let result = process_sitemap_gzip(&gz_bytes).unwrap();
+    assert_eq!(result.total_count, 1);
+    assert_eq!(result.instructions[0].urls[0], "https://example.com/page1");
+
+    let plain = process_sitemap_gzip(xml_content.as_bytes()).unwrap();
+    assert_eq!(plain.total_count, 1);
+  }
+
+  #[test]
+  fn test_process_sitemap_gzip_corrupt() {
+    assert!(process_sitemap_gzip(&[0x1f, 0x8b, 0x00, 0x01, 0x02]).is_err());
+  }
+
+  #[test]
+  fn test_filter_links_max_url_length() {
+    let long_link = format!("https://example.com/{}", "a".repeat(100));
+    let data = FilterLinksCall {
+      links: vec!["https://example.com/short".to_string(), long_link.clone()],
+      max_url_length: Some(64),
+      ..base()
+    };
+
+    let result = _filter_links(data).unwrap();
+    assert_eq!(result.links, vec!["https://example.com/short"]);
+    assert_eq!(
+      result.denial_reasons.get(&long_link).unwrap(),
+      "URL_TOO_LONG"
+    );
+  }
+
+  #[test]
+  fn test_strip_query_params() {
+    let params = vec!["utm_source".to_string(), "fbclid".to_string()];
+
+    let mut url = Url::parse("https://example.com/page?utm_source=google&id=7").unwrap();
+    assert!(strip_query_params(&mut url, &params));
+    assert_eq!(url.as_str(), "https://example.com/page?id=7");
+
+    let mut url = Url::parse("https://example.com/page?utm_source=google&fbclid=x").unwrap();
+    assert!(strip_query_params(&mut url, &params));
+    assert_eq!(url.as_str(), "https://example.com/page");
+
+    let mut url = Url::parse("https://example.com/page?id=7").unwrap();
+    assert!(!strip_query_params(&mut url, &params));
+    assert_eq!(url.as_str(), "https://example.com/page?id=7");
+  }
+
+  #[test]
+  fn test_allow_query_params() {
+    let allowed = vec!["id".to_string()];
+
+    let mut url = Url::parse("https://example.com/page?utm_source=google&id=7&fbclid=x").unwrap();
+    assert!(allow_query_params(&mut url, &allowed));
+    assert_eq!(url.as_str(), "https://example.com/page?id=7");
+
+    let mut url = Url::parse("https://example.com/page?utm_source=google").unwrap();
+    assert!(allow_query_params(&mut url, &allowed));
+    assert_eq!(url.as_str(), "https://example.com/page");
+
+    let mut url = Url::parse("https://example.com/page?id=7").unwrap();
+    assert!(!allow_query_params(&mut url, &allowed));
+
+    let mut url = Url::parse("https://example.com/page?id=7").unwrap();
+    assert!(allow_query_params(&mut url, &[]));
+    assert_eq!(url.as_str(), "https://example.com/page");
+  }
+
+  #[test]
+  fn test_filter_links_allow_query_parameters() {
+    let data = FilterLinksCall {
+      links: vec![
+        "https://example.com/page?id=1&utm_source=google".to_string(),
+        "https://example.com/page?id=1&sessionid=abc".to_string(),
+        "https://example.com/page?id=2&ref=x".to_string(),
+      ],
+      allow_query_parameters: Some(vec!["id".to_string(), "ref".to_string()]),
+      strip_query_params: Some(vec!["ref".to_string()]),
+      deduplicate_stripped: true,
+      ..base()
+    };
+
+    let result = _filter_links(data).unwrap();
+    assert_eq!(
+      result.links,
+      vec![
+        "https://example.com/page?id=1&utm_source=google",
+        "https://example.com/page?id=2&ref=x",
+      ]
+    );
+    assert_eq!(
+      result
+        .denial_reasons
+        .get("https://example.com/page?id=1&sessionid=abc")
+        .unwrap(),
+      "DUPLICATE_URL"
+    );
+  }
+
+  #[test]
+  fn test_filter_url_allow_query_parameters() {
+    let result = _filter_url(FilterUrlCall {
+      href: "/page?id=7&utm_source=google".to_string(),
+      url: "https://example.com/".to_string(),
+      base_url: "https://example.com".to_string(),
+      excludes: vec![],
+      ignore_robots_txt: true,
+      robots_txt: String::new(),
+      robots_user_agent: None,
+      allow_external_content_links: false,
+      allow_subdomains: false,
+      strip_query_params: None,
+      allow_query_parameters: Some(vec!["id".to_string()]),
+      additional_blocked_domains: vec![],
+    })
+    .unwrap();
+    assert!(result.allowed);
+    assert_eq!(result.url.as_deref(), Some("https://example.com/page?id=7"));
+  }
+
+  #[test]
+  fn test_filter_links_strip_query_params() {
+    let call = |deduplicate_stripped: bool| FilterLinksCall {
+      links: vec![
+        "https://example.com/page?utm_source=google".to_string(),
+        "https://example.com/page?utm_source=newsletter".to_string(),
+        "https://example.com/other".to_string(),
+      ],
+      strip_query_params: Some(vec!["utm_source".to_string()]),
+      deduplicate_stripped,
+      ..base()
+    };
+
+    let result = _filter_links(call(false)).unwrap();
+    assert_eq!(
+      result.links,
+      vec![
+        "https://example.com/page",
+        "https://example.com/page",
+        "https://example.com/other",
+      ]
+    );
+
+    let result = _filter_links(call(true)).unwrap();
+    assert_eq!(
+      result.links,
+      vec![
+        "https://example.com/page?utm_source=google",
+        "https://example.com/other",
+      ]
+    );
+    assert_eq!(
+      result
+        .denial_reasons
+        .get("https://example.com/page?utm_source=newsletter")
+        .unwrap(),
+      "DUPLICATE_URL"
+    );
+  }
+
+  #[test]
+  fn test_compute_url_fingerprint() {
+    let fp = |u: &str| compute_url_fingerprint(&Url::parse(u).unwrap());
+
+    assert_eq!(
+      fp("https://Example.com:443/page/?b=2&a=1#top"),
+      fp("https://example.com/page?a=1&b=2")
+    );
+    assert_ne!(
+      fp("https://example.com/page?a=1"),
+      fp("https://example.com/page?a=2")
+    );
+    assert_ne!(fp("https://example.com/#/a"), fp("https://example.com/#/b"));
+    assert_eq!(fp("https://example.com/"), fp("https://example.com"));
+  }
+
+  #[test]
+  fn test_fnv1a_fingerprint_value() {
+    // FNV-1a of the canonical form "https://example.com/"
+    let fp = |u: &str| compute_url_fingerprint(&Url::parse(u).unwrap());
+    assert_eq!(fp("https://example.com"), 0x0c8b_41cf_dcb3_c914);
+    assert_eq!(fp("https://EXAMPLE.com:443/#top"), 0x0c8b_41cf_dcb3_c914);
+  }
+
+  #[test]
+  fn test_filter_links_skips_duplicate_fingerprints() {
+    let data = FilterLinksCall {
+      links: vec![
+        "https://example.com/search?b=2&a=1".to_string(),
+        "https://example.com/search?a=1&b=2".to_string(),
+        "https://example.com/search/?a=1&b=2#results".to_string(),
+      ],
+      allow_hash_routes: true,
+      deduplicate_fingerprints: true,
+      ..base()
+    };
+
+    let result = _filter_links(data).unwrap();
+    assert_eq!(result.links, vec!["https://example.com/search?b=2&a=1"]);
+    assert_eq!(result.denial_reasons.len(), 2);
+    assert!(result
+      .denial_reasons
+      .values()
+      .all(|reason| reason == "DUPLICATE_URL"));
+  }
+
+  #[test]
+  fn test_filter_links_denied_link_does_not_claim_fingerprint() {
+    let data = FilterLinksCall {
+      links: vec![
+        "https://example.com/docs#getting_started".to_string(),
+        "https://example.com/docs".to_string(),
+      ],
+      deduplicate_fingerprints: true,
+      ..base()
+    };
+
+    let result = _filter_links(data).unwrap();
+    assert_eq!(result.links, vec!["https://example.com/docs"]);
+    assert_eq!(
+      result
+        .denial_reasons
+        .get("https://example.com/docs#getting_started")
+        .unwrap(),
+      "SECTION_LINK"
+    );
+  }
+
+  #[test]
+  fn test_filter_links_denied_link_does_not_claim_stripped_url() {
+    let data = FilterLinksCall {
+      links: vec![
+        "https://example.com/private?utm_source=google".to_string(),
+        "https://example.com/private?utm_source=newsletter".to_string(),
+      ],
+      excludes: vec!["^/private".to_string()],
+      strip_query_params: Some(vec!["utm_source".to_string()]),
+      deduplicate_stripped: true,
+      ..base()
+    };
+
+    let result = _filter_links(data).unwrap();
+    assert!(result.links.is_empty());
+    assert!(result
+      .denial_reasons
+      .values()
+      .all(|reason| reason == "EXCLUDE_PATTERN"));
+  }
+
+  #[test]
+  fn test_filter_links_social_media_domains() {
+    let data = FilterLinksCall {
+      links: vec![
+        "https://github.com/firecrawl/firecrawl".to_string(),
+        "https://discord.com/invite/firecrawl".to_string(),
+        "https://www.facebook.com/firecrawl".to_string(),
+        "https://forum.blocked.dev/thread/1".to_string(),
+      ],
+      additional_blocked_domains: vec!["blocked.dev".to_string()],
+      ..base()
+    };
+
+    let result = _filter_links(data).unwrap();
+    let reason = |link: &str| result.denial_reasons.get(link).map(String::as_str);
+
+    assert_eq!(
+      reason("https://github.com/firecrawl/firecrawl"),
+      Some("SOCIAL_MEDIA")
+    );
+    assert_eq!(
+      reason("https://discord.com/invite/firecrawl"),
+      Some("SOCIAL_MEDIA")
+    );
+    assert_eq!(
+      reason("https://www.facebook.com/firecrawl"),
+      Some("SOCIAL_MEDIA")
+    );
+    assert_eq!(
+      reason("https://forum.blocked.dev/thread/1"),
+      Some("SOCIAL_MEDIA")
+    );
+  }
+
+  #[test]
+  fn test_filter_links_dedupes_query_variants() {
+    let data = FilterLinksCall {
+      links: (1..=5)
+        .map(|i| format!("https://example.com/article?sessionid={i}"))
+        .collect(),
+      strip_query_params_for_dedup: true,
+      ..base()
+    };
+
+    let result = _filter_links(data).unwrap();
+    assert_eq!(
+      result.links,
+      vec!["https://example.com/article?sessionid=1"]
+    );
+    assert_eq!(result.denial_reasons.len(), 4);
+    assert!(result
+      .denial_reasons
+      .values()
+      .all(|reason| reason == "DUPLICATE_CONTENT"));
+  }
+
+  #[test]
+  fn test_filter_links_denied_link_does_not_claim_query_variants() {
+    let data = FilterLinksCall {
+      links: (1..=3)
+        .map(|i| format!("https://example.com/article?sessionid={i}"))
+        .collect(),
+      includes: vec!["sessionid=2".to_string()],
+      regex_on_full_url: true,
+      strip_query_params_for_dedup: true,
+      ..base()
+    };
+
+    let result = _filter_links(data).unwrap();
+    assert_eq!(
+      result.links,
+      vec!["https://example.com/article?sessionid=2"]
+    );
+    let reason = |link: &str| result.denial_reasons.get(link).map(String::as_str);
+    assert_eq!(
+      reason("https://example.com/article?sessionid=1"),
+      Some("INCLUDE_PATTERN")
+    );
+    assert_eq!(
+      reason("https://example.com/article?sessionid=3"),
+      Some("DUPLICATE_CONTENT")
+    );
+  }
+
+  #[test]
+  fn test_validate_robots_txt_valid() {
+    let result = _validate_robots_txt(
+      "# comment\nUser-agent: FirecrawlAgent\nUser-agent: *\nDisallow: /admin\nAllow: /admin/public # ok\nCrawl-delay: 2\n\nSitemap: https://example.com/sitemap.xml\n",
+    );
+    assert!(result.valid, "{:?}", result.warnings);
+    assert!(result.warnings.is_empty());
+    assert_eq!(result.user_agents, vec!["FirecrawlAgent", "*"]);
+  }
+
+  #[test]
+  fn test_validate_robots_txt_reports_common_issues() {
+    let result = _validate_robots_txt(
+      "Disallow: /early\nUser-agent: *\nDisalow: /typo\nDisallow private\nAllow: relative\nCrawl-delay: soon\nSitemap: /sitemap.xml\n",
+    );
+    assert!(!result.valid);
+    assert_eq!(result.user_agents, vec!["*"]);
+    assert_eq!(
+      result.warnings,
+      vec![
+        "Line 1: disallow appears before any User-agent and is ignored",
+        "Line 3: unknown directive \"disalow\"",
+        "Line 4: missing ':' separator in \"Disallow private\"",
+        "Line 5: allow path \"relative\" should start with '/' or '*'",
+        "Line 6: invalid Crawl-delay \"soon\"",
+        "Line 7: Sitemap \"/sitemap.xml\" is not an absolute URL",
+      ]
+    );
+
+    let empty = _validate_robots_txt("# nothing here\nDisallow: /\n");
+    assert!(empty.user_agents.is_empty());
+    assert!(empty
+      .warnings
+      .contains(&"No User-agent groups found; no rules will apply".to_string()));
+  }
+
+  #[test]
+  fn test_filter_links_document_links() {
+    let call = |allow_document_links: bool| FilterLinksCall {
+      links: vec![
+        "https://example.com/report.pdf".to_string(),
+        "https://example.com/slides.pptx".to_string(),
+        "https://example.com/logo.png".to_string(),
+        "https://example.com/about".to_string(),
+      ],
+      allow_document_links,
+      ..base()
+    };
+
+    let denied = _filter_links(call(false)).unwrap();
+    assert_eq!(denied.links, vec!["https://example.com/about"]);
+    assert_eq!(denied.accepted_count, 1);
+    assert_eq!(
+      denied.denied_by_reason,
+      HashMap::from([(DOCUMENT_TYPE.to_string(), 2), (FILE_TYPE.to_string(), 1)])
+    );
+    assert_eq!(
+      denied.denial_reasons["https://example.com/report.pdf"],
+      DOCUMENT_TYPE
+    );
+    assert_eq!(
+      denied.denial_reasons["https://example.com/slides.pptx"],
+      DOCUMENT_TYPE
+    );
+    assert_eq!(
+      denied.denial_reasons["https://example.com/logo.png"],
+      FILE_TYPE
+    );
+
+    let allowed = _filter_links(call(true)).unwrap();
+    assert_eq!(allowed.accepted_count, 3);
+    assert_eq!(
+      allowed.denied_by_reason,
+      HashMap::from([(FILE_TYPE.to_string(), 1)])
+    );
+    assert_eq!(
+      allowed.links,
+      vec![
+        "https://example.com/report.pdf",
+        "https://example.com/slides.pptx",
+        "https://example.com/about",
+      ]
+    );
+    assert_eq!(
+      allowed.denial_reasons["https://example.com/logo.png"],
+      FILE_TYPE
+    );
+  }
+
+  #[test]
+  fn test_merge_sitemap_results() {
+    let first = r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url><loc>https://example.com/a</loc><lastmod>2024-01-01</lastmod></url>
+  <url><loc>https://example.com/b</loc></url>
+  <url><loc>https://example.com/more.xml</loc></url>
+</urlset>"#;
+    let second = r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url><loc>https://example.com/b</loc><lastmod>2024-02-02</lastmod></url>
+  <url><loc>https://example.com/c</loc></url>
+</urlset>"#;
+
+    let results = vec![
+      _process_sitemap(first).unwrap(),
+      _process_sitemap(second).unwrap(),
+    ];
+    assert_eq!(results[0].total_count, 3);
+    assert_eq!(results[1].total_count, 2);
+
+    let merged = merge_sitemap_results(results);
+    assert_eq!(merged.total_count, 4);
+    assert_eq!(merged.instructions.len(), 2);
+
+    let process = merged
+      .instructions
+      .iter()
+      .find(|i| i.action == "process")
+      .unwrap();
+    assert_eq!(
+      process.urls,
+      vec![
+        "https://example.com/a",
+        "https://example.com/b",
+        "https://example.com/c",
+      ]
+    );
+    assert_eq!(process.count, 3);
+    assert_eq!(process.metadata.len(), 3);
+    assert_eq!(process.metadata[0].last_mod.as_deref(), Some("2024-01-01"));
+    assert_eq!(process.metadata[1].last_mod, None);
+
+    let recurse = merged
+      .instructions
+      .iter()
+      .find(|i| i.action == "recurse")
+      .unwrap();
+    assert_eq!(recurse.urls, vec!["https://example.com/more.xml"]);
+  }
 }
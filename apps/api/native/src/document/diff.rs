@@ -0,0 +1,176 @@
+use crate::document::model::*;
+use crate::document::renderers::plain_text::PlainTextRenderer;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentDiff {
+  /// Indices into `new.blocks`.
+  pub added_blocks: Vec<usize>,
+  /// Indices into `old.blocks`.
+  pub removed_blocks: Vec<usize>,
+  /// `(old index, new index)` pairs of blocks that were modified in place.
+  pub changed_blocks: Vec<(usize, usize)>,
+  pub metadata_changed: bool,
+}
+
+/// Diffs the top-level blocks of two documents.
+///
+/// Blocks are aligned on their plain-text representation using a longest
+/// common subsequence. Aligned blocks whose structure differs (e.g. a
+/// paragraph that became a heading) are reported as changed, as are unaligned
+/// blocks that sit at the same position between two aligned ones; anything
+/// left over is added or removed.
+pub fn diff(old: &Document, new: &Document) -> DocumentDiff {
+  let renderer = PlainTextRenderer::new();
+  let old_text: Vec<String> = old
+    .blocks
+    .iter()
+    .map(|b| renderer.render_block(b))
+    .collect();
+  let new_text: Vec<String> = new
+    .blocks
+    .iter()
+    .map(|b| renderer.render_block(b))
+    .collect();
+
+  let mut result = DocumentDiff {
+    metadata_changed: old.metadata != new.metadata,
+    ..Default::default()
+  };
+
+  let mut unmatched_old = Vec::new();
+  let mut unmatched_new = Vec::new();
+  let (mut i, mut j) = (0, 0);
+  for (oi, ni) in lcs_pairs(&old_text, &new_text) {
+    unmatched_old.extend(i..oi);
+    unmatched_new.extend(j..ni);
+    flush_unmatched(&mut unmatched_old, &mut unmatched_new, &mut result);
+    if old.blocks[oi] != new.blocks[ni] {
+      result.changed_blocks.push((oi, ni));
+    }
+    (i, j) = (oi + 1, ni + 1);
+  }
+  unmatched_old.extend(i..old_text.len());
+  unmatched_new.extend(j..new_text.len());
+  flush_unmatched(&mut unmatched_old, &mut unmatched_new, &mut result);
+
+  result
+}
+
+/// Pairs up blocks that fell between the same two aligned blocks as changed,
+/// and records the remainder as removed or added.
+fn flush_unmatched(old: &mut Vec<usize>, new: &mut Vec<usize>, result: &mut DocumentDiff) {
+  let paired = old.len().min(new.len());
+  result
+    .changed_blocks
+    .extend(old.iter().copied().zip(new.iter().copied()));
+  result.removed_blocks.extend(&old[paired..]);
+  result.added_blocks.extend(&new[paired..]);
+  old.clear();
+  new.clear();
+}
+
+/// Returns the `(old, new)` index pairs of a longest common subsequence.
+fn lcs_pairs(old: &[String], new: &[String]) -> Vec<(usize, usize)> {
+  let (n, m) = (old.len(), new.len());
+  // lengths[i][j] is the LCS length of old[i..] and new[j..]
+  let mut lengths = vec![0u32; (n + 1) * (m + 1)];
+  let at = |i: usize, j: usize| i * (m + 1) + j;
+  for i in (0..n).rev() {
+    for j in (0..m).rev() {
+      lengths[at(i, j)] = if old[i] == new[j] {
+        lengths[at(i + 1, j + 1)] + 1
+      } else {
+        lengths[at(i + 1, j)].max(lengths[at(i, j + 1)])
+      };
+    }
+  }
+
+  let mut pairs = Vec::new();
+  let (mut i, mut j) = (0, 0);
+  while i < n && j < m {
+    if old[i] == new[j] {
+      pairs.push((i, j));
+      i += 1;
+      j += 1;
+    } else if lengths[at(i + 1, j)] >= lengths[at(i, j + 1)] {
+      i += 1;
+    } else {
+      j += 1;
+    }
+  }
+  pairs
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn para(kind: ParagraphKind, s: &str) -> Block {
+    Block::Paragraph(Paragraph {
+      kind,
+      inlines: vec![Inline::Text(s.to_string())],
+      attrs: Default::default(),
+    })
+  }
+
+  fn doc(blocks: Vec<Block>) -> Document {
+    Document {
+      blocks,
+      metadata: DocumentMetadata::default(),
+      notes: Vec::new(),
+      comments: Vec::new(),
+    }
+  }
+
+  #[test]
+  fn identical_documents_have_no_diff() {
+    let a = doc(vec![
+      para(ParagraphKind::Normal, "One"),
+      para(ParagraphKind::Normal, "Two"),
+    ]);
+    assert_eq!(diff(&a, &a.clone()), DocumentDiff::default());
+  }
+
+  #[test]
+  fn detects_added_removed_and_changed_blocks() {
+    let old = doc(vec![
+      para(ParagraphKind::Normal, "Intro"),
+      para(ParagraphKind::Normal, "Old middle"),
+      para(ParagraphKind::Normal, "Removed"),
+      para(ParagraphKind::Normal, "Title"),
+      para(ParagraphKind::Normal, "Outro"),
+    ]);
+    let mut new = doc(vec![
+      para(ParagraphKind::Normal, "Intro"),
+      para(ParagraphKind::Normal, "New middle"),
+      para(ParagraphKind::Heading(1), "Title"),
+      para(ParagraphKind::Normal, "Outro"),
+      para(ParagraphKind::Normal, "Appendix"),
+    ]);
+    new.metadata.title = Some("Changed".to_string());
+
+    assert_eq!(
+      diff(&old, &new),
+      DocumentDiff {
+        added_blocks: vec![4],
+        removed_blocks: vec![2],
+        changed_blocks: vec![(1, 1), (3, 2)],
+        metadata_changed: true,
+      }
+    );
+  }
+
+  #[test]
+  fn diffs_against_empty_document() {
+    let a = doc(vec![
+      para(ParagraphKind::Normal, "One"),
+      para(ParagraphKind::Normal, "Two"),
+    ]);
+    let empty = doc(Vec::new());
+
+    assert_eq!(diff(&empty, &a).added_blocks, vec![0, 1]);
+    assert_eq!(diff(&a, &empty).removed_blocks, vec![0, 1]);
+  }
+}
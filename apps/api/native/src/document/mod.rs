@@ -1,12 +1,20 @@
+pub mod diff;
 pub mod model;
 pub mod providers;
 pub mod renderers;
+pub mod toc;
 
+pub use providers::docx::TrackedChangeMode;
 pub use providers::factory::DocumentType;
 
+use crate::document::diff::diff;
 use crate::document::model::Document;
-use crate::document::providers::factory::ProviderFactory;
-use crate::document::renderers::html::HtmlRenderer;
+use crate::document::providers::docx::read_toc_entries;
+use crate::document::providers::factory::{ProviderFactory, ProviderOptions};
+use crate::document::renderers::html::{HtmlRenderOptions, HtmlRenderer};
+use crate::document::renderers::markdown::MarkdownRenderer;
+use crate::document::renderers::plain_text::PlainTextRenderer;
+use crate::document::toc::{extract_toc, inline_text, toc_to_json};
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 
@@ -32,13 +40,29 @@ impl DocumentConverter {
     }
   }
 
+  /// `tracked_changes` only applies to DOCX input and defaults to `Accept`.
+  /// The document type is detected from the buffer when `doc_type` is omitted.
   #[napi]
   pub fn convert_buffer_to_html(
     &self,
     data: &[u8],
-    doc_type: DocumentType,
+    doc_type: Option<DocumentType>,
+    tracked_changes: Option<TrackedChangeMode>,
   ) -> napi::Result<String> {
-    let provider = self.factory.get_provider(doc_type);
+    let doc_type = doc_type
+      .or_else(|| ProviderFactory::detect_type_from_bytes(data))
+      .ok_or_else(|| {
+        Error::new(
+          Status::InvalidArg,
+          "Could not detect document type".to_string(),
+        )
+      })?;
+
+    let options = tracked_changes.map(|mode| ProviderOptions {
+      tracked_changes: mode,
+      ..Default::default()
+    });
+    let provider = self.factory.get_provider(doc_type, options.as_ref());
 
     let document: Document = provider
       .parse_buffer(data)
@@ -47,4 +71,259 @@ impl DocumentConverter {
     let html = self.html_renderer.render(&document);
     Ok(html)
   }
+
+  /// Like `convert_buffer_to_html`, with control over what ends up in the
+  /// output. Image options only apply to DOCX, ODT and EPUB input, and
+  /// `only_sheets` to XLSX input.
+  #[napi]
+  pub fn convert_buffer_to_html_with_options(
+    &self,
+    data: &[u8],
+    doc_type: DocumentType,
+    options: ConversionOptions,
+  ) -> napi::Result<String> {
+    let provider_options = ProviderOptions {
+      embed_images: options.embed_images,
+      max_image_size_bytes: options.max_image_size_bytes,
+      only_sheets: options.only_sheets,
+      ..Default::default()
+    };
+    let provider = self.factory.get_provider(doc_type, Some(&provider_options));
+
+    let document: Document = provider
+      .parse_buffer(data)
+      .map_err(|e| Error::new(Status::GenericFailure, format!("Provider error: {e}")))?;
+
+    let renderer = HtmlRenderer::with_options(HtmlRenderOptions {
+      include_comments: options.include_comments,
+      include_footnotes: options.include_footnotes,
+      heading_id_prefix: options.heading_id_prefix,
+      ..Default::default()
+    });
+    Ok(renderer.render(&document))
+  }
+
+  /// Returns the parsed document tree serialized as JSON.
+  #[napi]
+  pub fn convert_buffer_to_json(
+    &self,
+    data: &[u8],
+    doc_type: DocumentType,
+  ) -> napi::Result<String> {
+    let provider = self.factory.get_provider(doc_type, None);
+
+    let document: Document = provider
+      .parse_buffer(data)
+      .map_err(|e| Error::new(Status::GenericFailure, format!("Provider error: {e}")))?;
+
+    serde_json::to_string(&document)
+      .map_err(|e| Error::new(Status::GenericFailure, format!("Serialization error: {e}")))
+  }
+
+  /// Returns the table of contents as a JSON array of
+  /// `{ level, title, bookmarkId }` objects. For DOCX, entries from a
+  /// `TOC N`-styled table of contents take precedence over headings.
+  #[napi]
+  pub fn extract_toc_from_buffer(
+    &self,
+    data: &[u8],
+    doc_type: DocumentType,
+  ) -> napi::Result<String> {
+    if doc_type == DocumentType::Docx {
+      let entries = read_toc_entries(data)
+        .map_err(|e| Error::new(Status::GenericFailure, format!("Provider error: {e}")))?;
+      if !entries.is_empty() {
+        return Ok(toc_to_json(&entries));
+      }
+    }
+
+    let provider = self.factory.get_provider(doc_type, None);
+
+    let document: Document = provider
+      .parse_buffer(data)
+      .map_err(|e| Error::new(Status::GenericFailure, format!("Provider error: {e}")))?;
+
+    Ok(toc_to_json(&extract_toc(&document)))
+  }
+
+  /// Diffs two versions of a document, returning a JSON object with
+  /// `addedBlocks`, `removedBlocks`, `changedBlocks` and `metadataChanged`.
+  #[napi]
+  pub fn diff_buffers(
+    &self,
+    old_data: &[u8],
+    new_data: &[u8],
+    doc_type: DocumentType,
+  ) -> napi::Result<String> {
+    let provider = self.factory.get_provider(doc_type, None);
+
+    let old: Document = provider
+      .parse_buffer(old_data)
+      .map_err(|e| Error::new(Status::GenericFailure, format!("Provider error: {e}")))?;
+    let new: Document = provider
+      .parse_buffer(new_data)
+      .map_err(|e| Error::new(Status::GenericFailure, format!("Provider error: {e}")))?;
+
+    serde_json::to_string(&diff(&old, &new))
+      .map_err(|e| Error::new(Status::GenericFailure, format!("Serialization error: {e}")))
+  }
+
+  /// Word count, estimated reading time at `wpm` words per minute (defaults
+  /// to 200), and the links and images found anywhere in the document.
+  #[napi]
+  pub fn document_stats(
+    &self,
+    data: &[u8],
+    doc_type: DocumentType,
+    wpm: Option<u32>,
+  ) -> napi::Result<DocumentStats> {
+    let provider = self.factory.get_provider(doc_type, None);
+
+    let document: Document = provider
+      .parse_buffer(data)
+      .map_err(|e| Error::new(Status::GenericFailure, format!("Provider error: {e}")))?;
+
+    Ok(DocumentStats {
+      word_count: document.word_count().min(u32::MAX as usize) as u32,
+      reading_time_secs: document.reading_time_secs(wpm.unwrap_or(200)),
+      links: document
+        .collect_links()
+        .into_iter()
+        .map(|(href, children)| DocumentLink {
+          href,
+          text: inline_text(&children),
+        })
+        .collect(),
+      images: document
+        .collect_images()
+        .into_iter()
+        .map(|image| DocumentImage {
+          src: image.src,
+          alt: image.alt,
+        })
+        .collect(),
+    })
+  }
+}
+
+#[napi(object)]
+pub struct ConversionOptions {
+  /// Inline embedded images as base64 `data:` URIs.
+  pub embed_images: bool,
+  pub include_comments: bool,
+  /// Covers both footnotes and endnotes.
+  pub include_footnotes: bool,
+  /// When set, headings get an `id="{prefix}-{slug}"` anchor.
+  pub heading_id_prefix: Option<String>,
+  /// Embedded images larger than this are dropped.
+  pub max_image_size_bytes: Option<u32>,
+  /// Restrict spreadsheet output to these sheet names.
+  pub only_sheets: Option<Vec<String>>,
+}
+
+/// Detects the document type of a buffer from its contents.
+#[napi]
+pub fn detect_document_type(data: &[u8]) -> Option<DocumentType> {
+  ProviderFactory::detect_type_from_bytes(data)
+}
+
+#[napi(object)]
+pub struct DocumentStats {
+  pub word_count: u32,
+  pub reading_time_secs: f64,
+  /// In document order, covering the body, notes and comments.
+  pub links: Vec<DocumentLink>,
+  /// In document order, covering the body, notes and comments.
+  pub images: Vec<DocumentImage>,
+}
+
+#[napi(object)]
+pub struct DocumentLink {
+  pub href: String,
+  /// Plain text of the link's children.
+  pub text: String,
+}
+
+#[napi(object)]
+pub struct DocumentImage {
+  pub src: String,
+  pub alt: Option<String>,
+}
+
+#[napi]
+pub struct MarkdownConverter {
+  factory: ProviderFactory,
+  markdown_renderer: MarkdownRenderer,
+}
+
+impl Default for MarkdownConverter {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[napi]
+impl MarkdownConverter {
+  #[napi(constructor)]
+  pub fn new() -> Self {
+    Self {
+      factory: ProviderFactory::new(),
+      markdown_renderer: MarkdownRenderer::new(),
+    }
+  }
+
+  #[napi]
+  pub fn convert_buffer_to_markdown(
+    &self,
+    data: &[u8],
+    doc_type: DocumentType,
+  ) -> napi::Result<String> {
+    let provider = self.factory.get_provider(doc_type, None);
+
+    let document: Document = provider
+      .parse_buffer(data)
+      .map_err(|e| Error::new(Status::GenericFailure, format!("Provider error: {e}")))?;
+
+    let markdown = self.markdown_renderer.render(&document);
+    Ok(markdown)
+  }
+}
+
+#[napi]
+pub struct PlainTextConverter {
+  factory: ProviderFactory,
+  plain_text_renderer: PlainTextRenderer,
+}
+
+impl Default for PlainTextConverter {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[napi]
+impl PlainTextConverter {
+  #[napi(constructor)]
+  pub fn new() -> Self {
+    Self {
+      factory: ProviderFactory::new(),
+      plain_text_renderer: PlainTextRenderer::new(),
+    }
+  }
+
+  #[napi]
+  pub fn convert_buffer_to_text(
+    &self,
+    data: &[u8],
+    doc_type: DocumentType,
+  ) -> napi::Result<String> {
+    let provider = self.factory.get_provider(doc_type, None);
+
+    let document: Document = provider
+      .parse_buffer(data)
+      .map_err(|e| Error::new(Status::GenericFailure, format!("Provider error: {e}")))?;
+
+    let text = self.plain_text_renderer.render(&document);
+    Ok(text)
+  }
 }
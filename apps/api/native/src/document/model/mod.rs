@@ -1,7 +1,9 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use std::num::NonZeroU32;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Document {
   pub blocks: Vec<Block>,
   pub metadata: DocumentMetadata,
@@ -9,23 +11,25 @@ pub struct Document {
   pub comments: Vec<Comment>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct DocumentMetadata {
   pub title: Option<String>,
   pub author: Option<String>,
   pub created: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct NoteId(pub String);
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CommentId(pub String);
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct BookmarkId(pub String);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum Block {
   Paragraph(Paragraph),
   Table(Table),
@@ -33,20 +37,46 @@ pub enum Block {
   Image(Image),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Paragraph {
   pub kind: ParagraphKind,
   pub inlines: Vec<Inline>,
+  #[serde(default, skip_serializing_if = "ParagraphAttrs::is_default")]
+  pub attrs: ParagraphAttrs,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParagraphAttrs {
+  pub alignment: Option<TextAlignment>,
+}
+
+impl ParagraphAttrs {
+  pub fn is_default(&self) -> bool {
+    *self == Self::default()
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TextAlignment {
+  Left,
+  Right,
+  Center,
+  Justify,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum ParagraphKind {
   Normal,
   Heading(u8), // 1..=6 will render as <h1>.. <h6>
   Blockquote,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum Inline {
   Text(String),
   LineBreak,
@@ -65,71 +95,396 @@ pub enum Inline {
   Bookmark(BookmarkId),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Image {
   pub src: String,
   pub alt: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Table {
   pub rows: Vec<TableRow>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TableRow {
   pub cells: Vec<TableCell>,
   pub kind: TableRowKind,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum TableRowKind {
   Header,
   Body,
   Footer,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TableCell {
   pub blocks: Vec<Block>,
   pub colspan: NonZeroU32,
   pub rowspan: NonZeroU32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct List {
   pub items: Vec<ListItem>,
   pub list_type: ListType,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ListItem {
   pub blocks: Vec<Block>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum ListType {
   Ordered,
   Unordered,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Note {
   pub id: NoteId,
   pub kind: NoteKind,
   pub blocks: Vec<Block>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum NoteKind {
   Footnote,
   Endnote,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Comment {
   pub id: CommentId,
   pub author_name: Option<String>,
   pub author_initials: Option<String>,
+  /// ISO 8601 timestamp of the comment, when the source records one.
+  pub date: Option<String>,
   pub blocks: Vec<Block>,
 }
+
+impl Document {
+  /// Counts whitespace-separated words in the text and code of the document
+  /// body and its notes.
+  pub fn word_count(&self) -> usize {
+    let body: usize = self.blocks.iter().map(block_word_count).sum();
+    let notes: usize = self
+      .notes
+      .iter()
+      .flat_map(|n| &n.blocks)
+      .map(block_word_count)
+      .sum();
+    body + notes
+  }
+
+  /// Estimated time to read the document, in seconds, at `wpm` words per
+  /// minute (200 is a common default).
+  pub fn reading_time_secs(&self, wpm: u32) -> f64 {
+    if wpm == 0 {
+      return 0.0;
+    }
+    self.word_count() as f64 * 60.0 / wpm as f64
+  }
+
+  /// Returns the `(href, children)` of every link in the document body,
+  /// notes and comments, in document order.
+  pub fn collect_links(&self) -> Vec<(String, Vec<Inline>)> {
+    let mut links = Vec::new();
+    self.walk(|node| {
+      if let Node::Inline(Inline::Link { href, children }) = node {
+        links.push((href.clone(), children.clone()));
+      }
+    });
+    links
+  }
+
+  /// Returns every image block in the document body, notes and comments, in
+  /// document order.
+  pub fn collect_images(&self) -> Vec<Image> {
+    let mut images = Vec::new();
+    self.walk(|node| {
+      if let Node::Block(Block::Image(image)) = node {
+        images.push(image.clone());
+      }
+    });
+    images
+  }
+
+  /// Visits every block and inline in document order, using an explicit stack
+  /// so deeply nested documents can't overflow the call stack.
+  fn walk<'a>(&'a self, mut visit: impl FnMut(Node<'a>)) {
+    let roots = self
+      .blocks
+      .iter()
+      .chain(self.notes.iter().flat_map(|n| &n.blocks))
+      .chain(self.comments.iter().flat_map(|c| &c.blocks));
+    let mut stack: Vec<Node<'a>> = roots.map(Node::Block).collect();
+    stack.reverse();
+
+    while let Some(node) = stack.pop() {
+      let start = stack.len();
+      match node {
+        Node::Block(Block::Paragraph(p)) => stack.extend(p.inlines.iter().map(Node::Inline)),
+        Node::Block(Block::Table(t)) => stack.extend(
+          t.rows
+            .iter()
+            .flat_map(|r| &r.cells)
+            .flat_map(|c| &c.blocks)
+            .map(Node::Block),
+        ),
+        Node::Block(Block::List(l)) => {
+          stack.extend(l.items.iter().flat_map(|i| &i.blocks).map(Node::Block))
+        }
+        Node::Inline(
+          Inline::Link { children, .. }
+          | Inline::Strong(children)
+          | Inline::Em(children)
+          | Inline::Del(children)
+          | Inline::Sup(children)
+          | Inline::Sub(children),
+        ) => stack.extend(children.iter().map(Node::Inline)),
+        Node::Block(Block::Image(_)) | Node::Inline(_) => {}
+      }
+      // Children were pushed in order, so reverse them to pop the first one next
+      stack[start..].reverse();
+      visit(node);
+    }
+  }
+}
+
+#[derive(Clone, Copy)]
+enum Node<'a> {
+  Block(&'a Block),
+  Inline(&'a Inline),
+}
+
+fn block_word_count(block: &Block) -> usize {
+  match block {
+    Block::Paragraph(p) => p.inlines.iter().map(inline_word_count).sum(),
+    Block::Table(t) => t
+      .rows
+      .iter()
+      .flat_map(|r| &r.cells)
+      .flat_map(|c| &c.blocks)
+      .map(block_word_count)
+      .sum(),
+    Block::List(l) => l
+      .items
+      .iter()
+      .flat_map(|i| &i.blocks)
+      .map(block_word_count)
+      .sum(),
+    Block::Image(_) => 0,
+  }
+}
+
+fn inline_word_count(inline: &Inline) -> usize {
+  match inline {
+    Inline::Text(t) | Inline::Code(t) => t.split_whitespace().count(),
+    Inline::Link { children, .. }
+    | Inline::Strong(children)
+    | Inline::Em(children)
+    | Inline::Del(children)
+    | Inline::Sup(children)
+    | Inline::Sub(children) => children.iter().map(inline_word_count).sum(),
+    Inline::LineBreak
+    | Inline::FootnoteRef(_)
+    | Inline::EndnoteRef(_)
+    | Inline::CommentRef(_)
+    | Inline::Bookmark(_) => 0,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn paragraph(inlines: Vec<Inline>) -> Block {
+    Block::Paragraph(Paragraph {
+      kind: ParagraphKind::Normal,
+      inlines,
+      attrs: Default::default(),
+    })
+  }
+
+  fn text(t: &str) -> Inline {
+    Inline::Text(t.to_string())
+  }
+
+  fn document(blocks: Vec<Block>) -> Document {
+    Document {
+      blocks,
+      metadata: DocumentMetadata::default(),
+      notes: Vec::new(),
+      comments: Vec::new(),
+    }
+  }
+
+  #[test]
+  fn test_word_count() {
+    let one = NonZeroU32::new(1).unwrap();
+    let mut doc = document(vec![
+      paragraph(vec![
+        text("The quick "),
+        Inline::Strong(vec![text("brown fox")]),
+        Inline::LineBreak,
+        Inline::Link {
+          href: "https://example.com".to_string(),
+          children: vec![text("jumps  over")],
+        },
+        Inline::FootnoteRef(NoteId("1".to_string())),
+      ]),
+      Block::List(List {
+        items: vec![ListItem {
+          blocks: vec![paragraph(vec![Inline::Code("let x = 1;".to_string())])],
+        }],
+        list_type: ListType::Unordered,
+      }),
+      Block::Table(Table {
+        rows: vec![TableRow {
+          cells: vec![TableCell {
+            blocks: vec![paragraph(vec![text("the lazy dog")])],
+            colspan: one,
+            rowspan: one,
+          }],
+          kind: TableRowKind::Body,
+        }],
+      }),
+      Block::Image(Image {
+        src: "https://example.com/a.png".to_string(),
+        alt: Some("not counted".to_string()),
+      }),
+    ]);
+    doc.notes.push(Note {
+      id: NoteId("1".to_string()),
+      kind: NoteKind::Footnote,
+      blocks: vec![paragraph(vec![text("A footnote.")])],
+    });
+    doc.comments.push(Comment {
+      id: CommentId("c1".to_string()),
+      author_name: None,
+      author_initials: None,
+      date: None,
+      blocks: vec![paragraph(vec![text("Comments are not counted")])],
+    });
+
+    assert_eq!(doc.word_count(), 6 + 4 + 3 + 2);
+  }
+
+  #[test]
+  fn test_collect_links_and_images() {
+    let link = |href: &str, children: Vec<Inline>| Inline::Link {
+      href: href.to_string(),
+      children,
+    };
+    let image = |src: &str| Image {
+      src: src.to_string(),
+      alt: None,
+    };
+
+    let mut doc = document(vec![
+      paragraph(vec![
+        link("https://a.example", vec![text("A")]),
+        Inline::Em(vec![link("https://b.example", vec![text("B")])]),
+      ]),
+      Block::Image(image("https://example.com/1.png")),
+      Block::List(List {
+        items: vec![ListItem {
+          blocks: vec![
+            paragraph(vec![link("https://c.example", vec![text("C")])]),
+            Block::Image(image("https://example.com/2.png")),
+          ],
+        }],
+        list_type: ListType::Ordered,
+      }),
+    ]);
+    doc.notes.push(Note {
+      id: NoteId("1".to_string()),
+      kind: NoteKind::Endnote,
+      blocks: vec![paragraph(vec![link("https://d.example", vec![])])],
+    });
+    doc.comments.push(Comment {
+      id: CommentId("c1".to_string()),
+      author_name: None,
+      author_initials: None,
+      date: None,
+      blocks: vec![paragraph(vec![link("https://e.example", vec![])])],
+    });
+
+    let links = doc.collect_links();
+    assert_eq!(
+      links
+        .iter()
+        .map(|(href, _)| href.as_str())
+        .collect::<Vec<_>>(),
+      vec![
+        "https://a.example",
+        "https://b.example",
+        "https://c.example",
+        "https://d.example",
+        "https://e.example",
+      ]
+    );
+    assert_eq!(links[0].1, vec![text("A")]);
+
+    assert_eq!(
+      doc.collect_images(),
+      vec![
+        image("https://example.com/1.png"),
+        image("https://example.com/2.png")
+      ]
+    );
+  }
+
+  #[test]
+  fn test_json_round_trip() {
+    let doc = document(vec![paragraph(vec![
+      text("Hello "),
+      Inline::Link {
+        href: "https://example.com".to_string(),
+        children: vec![text("world")],
+      },
+    ])]);
+
+    let json = serde_json::to_value(&doc).unwrap();
+    assert_eq!(
+      json["blocks"][0],
+      serde_json::json!({
+        "paragraph": {
+          "kind": "normal",
+          "inlines": [
+            { "text": "Hello " },
+            { "link": { "href": "https://example.com", "children": [{ "text": "world" }] } }
+          ]
+        }
+      })
+    );
+
+    let back: Document = serde_json::from_value(json).unwrap();
+    assert_eq!(back, doc);
+  }
+
+  #[test]
+  fn test_reading_time_secs() {
+    let words = vec!["word"; 300].join(" ");
+    let doc = document(vec![paragraph(vec![text(&words)])]);
+
+    assert_eq!(doc.reading_time_secs(200), 90.0);
+    assert_eq!(doc.reading_time_secs(0), 0.0);
+    assert_eq!(document(Vec::new()).reading_time_secs(200), 0.0);
+  }
+}
@@ -1,6 +1,7 @@
 use crate::document::model::*;
 use crate::document::providers::DocumentProvider;
 use cfb::CompoundFile;
+use chrono::{DateTime, Utc};
 use std::error::Error;
 use std::io::Cursor;
 use std::io::Read;
@@ -24,6 +25,8 @@ impl DocumentProvider for DocProvider {
     if let Ok(summary_info) = extract_summary_info(&mut cfb) {
       metadata.title = summary_info.title;
       metadata.author = summary_info.author;
+      // Fall back to the last save time for documents without a creation time
+      metadata.created = summary_info.created.or(summary_info.last_saved);
     }
 
     // Extract text content from the document
@@ -49,8 +52,27 @@ impl DocumentProvider for DocProvider {
 struct SummaryInfo {
   title: Option<String>,
   author: Option<String>,
+  created: Option<DateTime<Utc>>,
+  last_saved: Option<DateTime<Utc>>,
 }
 
+// MS-OLEPS property identifiers from the SummaryInformation property set
+const PID_CODEPAGE: u32 = 1;
+const PID_TITLE: u32 = 2;
+const PID_AUTHOR: u32 = 4;
+const PID_CREATE_DTM: u32 = 12;
+const PID_LASTSAVE_DTM: u32 = 13;
+
+// MS-OLEPS property types
+const VT_I2: u32 = 0x0002;
+const VT_LPSTR: u32 = 0x001E;
+const VT_FILETIME: u32 = 0x0040;
+
+const CP_UTF8: u16 = 65001;
+
+// Seconds between 1601-01-01 (FILETIME epoch) and 1970-01-01 (Unix epoch)
+const FILETIME_UNIX_EPOCH_OFFSET_SECS: i64 = 11_644_473_600;
+
 fn extract_summary_info<R: Read + std::io::Seek>(
   cfb: &mut CompoundFile<R>,
 ) -> Result<SummaryInfo, Box<dyn Error + Send + Sync>> {
@@ -61,58 +83,129 @@ fn extract_summary_info<R: Read + std::io::Seek>(
     let mut buf = Vec::new();
     stream.read_to_end(&mut buf)?;
 
-    // Parse the OLE property set stream to extract title and author
-    if let Some((title, author)) = parse_summary_info_stream(&buf) {
-      info.title = title;
-      info.author = author;
+    if let Some(parsed) = parse_summary_info_stream(&buf) {
+      info = parsed;
     }
   }
 
   Ok(info)
 }
 
-fn parse_summary_info_stream(data: &[u8]) -> Option<(Option<String>, Option<String>)> {
-  // MS-OLEPS: Property Set Stream format
-  // This is a simplified parser that extracts strings from the property stream
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+  let bytes = data.get(offset..offset.checked_add(2)?)?;
+  Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+  let bytes = data.get(offset..offset.checked_add(4)?)?;
+  Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+  let lo = read_u32(data, offset)? as u64;
+  let hi = read_u32(data, offset.checked_add(4)?)? as u64;
+  Some((hi << 32) | lo)
+}
 
+fn parse_summary_info_stream(data: &[u8]) -> Option<SummaryInfo> {
+  // MS-OLEPS: Property Set Stream format
+  //   header:  byte order (2), version (2), system id (4), CLSID (16), set count (4)
+  //   then one (FMTID (16), offset (4)) pair per property set
   if data.len() < 48 {
     return None;
   }
 
   // Byte order mark at offset 0 should be 0xFFFE (little-endian)
-  if data.len() >= 2 && (data[0] != 0xFE || data[1] != 0xFF) {
+  if data[0] != 0xFE || data[1] != 0xFF {
     return None;
   }
 
-  let mut title: Option<String> = None;
-  let mut author: Option<String> = None;
+  if read_u32(data, 24)? == 0 {
+    return None;
+  }
 
-  // Extract readable strings from the property stream
-  let strings = extract_ascii_strings(data, 3);
+  // The first property set is the SummaryInformation one
+  let section = read_u32(data, 44)? as usize;
+  let property_count = read_u32(data, section.checked_add(4)?)? as usize;
 
-  // Filter out common non-title/author strings
-  let filtered: Vec<&str> = strings
+  let mut properties = Vec::new();
+  for i in 0..property_count {
+    let entry = section.checked_add(8 + i.checked_mul(8)?)?;
+    let (Some(id), Some(offset)) = (read_u32(data, entry), read_u32(data, entry + 4)) else {
+      break;
+    };
+    if let Some(value_offset) = section.checked_add(offset as usize) {
+      properties.push((id, value_offset));
+    }
+  }
+
+  // Strings are stored in the property set's code page, so resolve it first
+  let codepage = properties
     .iter()
-    .map(|s| s.as_str())
-    .filter(|s| {
-      !s.contains("Microsoft")
-        && !s.contains("Normal")
-        && !s.contains("template")
-        && !s.starts_with("http")
-        && s.len() >= 2
-        && s.len() <= 200
-    })
-    .collect();
+    .find(|(id, _)| *id == PID_CODEPAGE)
+    .and_then(|(_, offset)| match read_u32(data, *offset)? {
+      VT_I2 => read_u16(data, offset + 4),
+      _ => None,
+    });
+
+  let mut info = SummaryInfo::default();
+  for (id, offset) in properties {
+    match id {
+      PID_TITLE => info.title = read_lpstr_property(data, offset, codepage),
+      PID_AUTHOR => info.author = read_lpstr_property(data, offset, codepage),
+      PID_CREATE_DTM => info.created = read_filetime_property(data, offset),
+      PID_LASTSAVE_DTM => info.last_saved = read_filetime_property(data, offset),
+      _ => {}
+    }
+  }
+
+  Some(info)
+}
+
+fn read_lpstr_property(data: &[u8], offset: usize, codepage: Option<u16>) -> Option<String> {
+  if read_u32(data, offset)? != VT_LPSTR {
+    return None;
+  }
+
+  let len = read_u32(data, offset + 4)? as usize;
+  let start = offset + 8;
+  let bytes = data.get(start..start.checked_add(len)?)?;
+  // The stored length includes the null terminator
+  let bytes = match bytes.iter().position(|&b| b == 0) {
+    Some(end) => &bytes[..end],
+    None => bytes,
+  };
+
+  let value = if codepage == Some(CP_UTF8) {
+    String::from_utf8_lossy(bytes).into_owned()
+  } else {
+    bytes.iter().map(|&b| decode_cp1252(b)).collect()
+  };
+
+  let value = value.trim();
+  if value.is_empty() {
+    None
+  } else {
+    Some(value.to_string())
+  }
+}
 
-  // Title and author are typically the first meaningful strings
-  if let Some(t) = filtered.first() {
-    title = Some(t.to_string());
+fn read_filetime_property(data: &[u8], offset: usize) -> Option<DateTime<Utc>> {
+  if read_u32(data, offset)? != VT_FILETIME {
+    return None;
   }
-  if let Some(a) = filtered.get(1) {
-    author = Some(a.to_string());
+  filetime_to_datetime(read_u64(data, offset + 4)?)
+}
+
+fn filetime_to_datetime(filetime: u64) -> Option<DateTime<Utc>> {
+  // Unset timestamps are written as zero
+  if filetime == 0 {
+    return None;
   }
 
-  Some((title, author))
+  let secs = (filetime / 10_000_000) as i64 - FILETIME_UNIX_EPOCH_OFFSET_SECS;
+  let nanos = ((filetime % 10_000_000) * 100) as u32;
+  DateTime::from_timestamp(secs, nanos)
 }
 
 fn extract_text_content<R: Read + std::io::Seek>(
@@ -292,29 +385,6 @@ fn is_text_char(ch: char) -> bool {
   (ch >= ' ' && ch != '\x7F') || ch == '\t'
 }
 
-fn extract_ascii_strings(data: &[u8], min_length: usize) -> Vec<String> {
-  let mut strings = Vec::new();
-  let mut current = String::new();
-
-  for &byte in data {
-    let ch = decode_cp1252(byte);
-    if ch.is_ascii_graphic() || ch == ' ' {
-      current.push(ch);
-    } else {
-      if current.len() >= min_length {
-        strings.push(current.clone());
-      }
-      current.clear();
-    }
-  }
-
-  if current.len() >= min_length {
-    strings.push(current);
-  }
-
-  strings
-}
-
 fn extract_text_fallback<R: Read + std::io::Seek>(
   cfb: &mut CompoundFile<R>,
 ) -> Result<String, Box<dyn Error + Send + Sync>> {
@@ -413,8 +483,103 @@ fn text_to_blocks(text: &str) -> Vec<Block> {
     blocks.push(Block::Paragraph(Paragraph {
       kind: ParagraphKind::Normal,
       inlines: vec![Inline::Text(cleaned)],
+      attrs: Default::default(),
     }));
   }
 
   blocks
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn build_summary_info(properties: &[(u32, Vec<u8>)]) -> Vec<u8> {
+    let mut data = vec![0xFE, 0xFF, 0x00, 0x00];
+    data.extend_from_slice(&[0u8; 4]);
+    data.extend_from_slice(&[0u8; 16]);
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(&[0u8; 16]);
+    data.extend_from_slice(&48u32.to_le_bytes());
+
+    let mut values = Vec::new();
+    let mut entries = Vec::new();
+    let values_start = 8 + properties.len() * 8;
+    for (id, value) in properties {
+      entries.extend_from_slice(&id.to_le_bytes());
+      entries.extend_from_slice(&((values_start + values.len()) as u32).to_le_bytes());
+      values.extend_from_slice(value);
+    }
+
+    data.extend_from_slice(&((values_start + values.len()) as u32).to_le_bytes());
+    data.extend_from_slice(&(properties.len() as u32).to_le_bytes());
+    data.extend_from_slice(&entries);
+    data.extend_from_slice(&values);
+    data
+  }
+
+  fn lpstr(value: &[u8]) -> Vec<u8> {
+    let mut out = VT_LPSTR.to_le_bytes().to_vec();
+    out.extend_from_slice(&((value.len() + 1) as u32).to_le_bytes());
+    out.extend_from_slice(value);
+    out.push(0);
+    while !out.len().is_multiple_of(4) {
+      out.push(0);
+    }
+    out
+  }
+
+  fn filetime(value: u64) -> Vec<u8> {
+    let mut out = VT_FILETIME.to_le_bytes().to_vec();
+    out.extend_from_slice(&value.to_le_bytes());
+    out
+  }
+
+  #[test]
+  fn test_parse_summary_info_stream() {
+    // 2020-01-02T03:04:05Z
+    let created = (1_577_934_245 + FILETIME_UNIX_EPOCH_OFFSET_SECS as u64) * 10_000_000;
+    let data = build_summary_info(&[
+      (PID_TITLE, lpstr(b"Quarterly Report")),
+      (PID_AUTHOR, lpstr(b"Jos\xe9 Garc\xeda")),
+      (PID_CREATE_DTM, filetime(created)),
+    ]);
+
+    let info = parse_summary_info_stream(&data).unwrap();
+    assert_eq!(info.title.as_deref(), Some("Quarterly Report"));
+    assert_eq!(info.author.as_deref(), Some("José García"));
+    assert_eq!(
+      info.created.map(|d| d.to_rfc3339()).as_deref(),
+      Some("2020-01-02T03:04:05+00:00")
+    );
+    assert!(info.last_saved.is_none());
+  }
+
+  #[test]
+  fn test_parse_summary_info_stream_utf8_codepage() {
+    let mut codepage = VT_I2.to_le_bytes().to_vec();
+    codepage.extend_from_slice(&CP_UTF8.to_le_bytes());
+    codepage.extend_from_slice(&[0, 0]);
+    let data = build_summary_info(&[
+      (PID_CODEPAGE, codepage),
+      (PID_AUTHOR, lpstr("Zoë".as_bytes())),
+      (PID_LASTSAVE_DTM, filetime(0)),
+    ]);
+
+    let info = parse_summary_info_stream(&data).unwrap();
+    assert_eq!(info.author.as_deref(), Some("Zoë"));
+    assert!(info.title.is_none());
+    assert!(info.last_saved.is_none());
+  }
+
+  #[test]
+  fn test_parse_summary_info_stream_rejects_truncated_data() {
+    let mut data = build_summary_info(&[(PID_TITLE, lpstr(b"Title"))]);
+    assert!(parse_summary_info_stream(&data[..20]).is_none());
+
+    // Offsets pointing past the end of the stream are ignored
+    data.truncate(data.len() - 8);
+    let info = parse_summary_info_stream(&data).unwrap();
+    assert!(info.title.is_none());
+  }
+}
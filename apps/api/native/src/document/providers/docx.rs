@@ -1,6 +1,9 @@
 use crate::document::model::*;
 use crate::document::providers::DocumentProvider;
-use chrono::{DateTime, Utc};
+use crate::document::toc::TocEntry;
+use base64::prelude::*;
+use chrono::{DateTime, SecondsFormat, Utc};
+use napi_derive::napi;
 use roxmltree::{Document as XmlDoc, Node};
 use std::collections::HashMap;
 use std::error::Error;
@@ -8,11 +11,49 @@ use std::io::{Read, Seek};
 use std::num::NonZeroU32;
 use zip::read::ZipArchive;
 
-pub struct DocxProvider;
+/// How `<w:ins>` / `<w:del>` revision marks are reflected in the output.
+#[napi]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrackedChangeMode {
+  /// Keep inserted text and drop deleted text (the document as it would read
+  /// after accepting every change).
+  Accept,
+  /// Keep deleted text and drop inserted text.
+  Reject,
+  /// Keep both, wrapping insertions in `Strong` and deletions in `Del`.
+  ShowBoth,
+}
+
+#[derive(Debug, Clone)]
+pub struct DocxParseOptions {
+  pub tracked_changes: TrackedChangeMode,
+  /// Inline images stored in `word/media/` as base64 `data:` URIs.
+  pub embed_images: bool,
+  /// Embedded images larger than this are dropped instead of inlined.
+  pub max_image_size_bytes: Option<u32>,
+}
+
+impl Default for DocxParseOptions {
+  fn default() -> Self {
+    Self {
+      tracked_changes: TrackedChangeMode::Accept,
+      embed_images: false,
+      max_image_size_bytes: None,
+    }
+  }
+}
+
+pub struct DocxProvider {
+  options: DocxParseOptions,
+}
 
 impl DocxProvider {
   pub fn new() -> Self {
-    Self
+    Self::with_options(DocxParseOptions::default())
+  }
+
+  pub fn with_options(options: DocxParseOptions) -> Self {
+    Self { options }
   }
 }
 
@@ -32,16 +73,15 @@ impl DocumentProvider for DocxProvider {
     let metadata = read_core_properties(&mut zip).unwrap_or_default();
 
     let size_buckets = compute_style_size_buckets_for_doc(&xml, &styles);
+    let ctx = ParseContext {
+      styles: &styles,
+      size_buckets: &size_buckets,
+      numbering: &numbering,
+      options: &self.options,
+    };
     let mut blocks = Vec::new();
     if let Some(body) = xml.descendants().find(|n| is_tag(n, "body")) {
-      blocks = parse_block_children(
-        &body,
-        &relationships,
-        &styles,
-        &size_buckets,
-        &numbering,
-        &mut zip,
-      );
+      blocks = parse_block_children(&body, &relationships, &ctx, &mut zip);
     }
 
     let mut notes = Vec::new();
@@ -50,27 +90,21 @@ impl DocumentProvider for DocxProvider {
       "word/footnotes.xml",
       "word/_rels/footnotes.xml.rels",
       NoteKind::Footnote,
-      &styles,
-      &size_buckets,
-      &numbering,
+      &ctx,
     ));
     notes.extend(read_notes(
       &mut zip,
       "word/endnotes.xml",
       "word/_rels/endnotes.xml.rels",
       NoteKind::Endnote,
-      &styles,
-      &size_buckets,
-      &numbering,
+      &ctx,
     ));
 
     let comments = read_comments(
       &mut zip,
       "word/comments.xml",
       "word/_rels/comments.xml.rels",
-      &styles,
-      &size_buckets,
-      &numbering,
+      &ctx,
     );
 
     Ok(Document {
@@ -86,6 +120,75 @@ impl DocumentProvider for DocxProvider {
   }
 }
 
+/// Reads the entries of a Word-generated table of contents, i.e. paragraphs
+/// styled `TOC 1`..`TOC 9`. Returns an empty list when the document has none.
+pub fn read_toc_entries(data: &[u8]) -> Result<Vec<TocEntry>, Box<dyn Error + Send + Sync>> {
+  let cursor = std::io::Cursor::new(data);
+  let mut zip = ZipArchive::new(cursor)?;
+  let styles = read_styles(&mut zip);
+
+  let document_xml =
+    read_zip_text(&mut zip, "word/document.xml").ok_or("Missing word/document.xml in document")?;
+  let xml = XmlDoc::parse(strip_bom(&document_xml))?;
+
+  let mut entries = Vec::new();
+  for p in xml.descendants().filter(|n| is_tag(n, "p")) {
+    let Some(style_id) = child(&p, "pPr")
+      .and_then(|n| child(&n, "pStyle"))
+      .and_then(|n| get_attr_local(&n, "val"))
+    else {
+      continue;
+    };
+    let level = styles
+      .name_by_style_id
+      .get(style_id)
+      .and_then(|name| parse_toc_level(name))
+      .or_else(|| parse_toc_level(style_id));
+    let Some(level) = level else {
+      continue;
+    };
+
+    // the entry title ends at the tab leading to the page number
+    let mut title = String::new();
+    for n in p.descendants() {
+      if is_tag(&n, "tab") && !n.ancestors().any(|a| is_tag(&a, "pPr")) {
+        break;
+      }
+      if is_tag(&n, "t") {
+        title.push_str(n.text().unwrap_or(""));
+      }
+    }
+    let title = title.split_whitespace().collect::<Vec<_>>().join(" ");
+    if title.is_empty() {
+      continue;
+    }
+
+    let bookmark_id = p
+      .descendants()
+      .find(|n| is_tag(n, "hyperlink"))
+      .and_then(|n| get_attr_local(&n, "anchor"))
+      .map(|a| BookmarkId(a.to_string()));
+
+    entries.push(TocEntry {
+      level,
+      title,
+      bookmark_id,
+    });
+  }
+
+  Ok(entries)
+}
+
+fn parse_toc_level(s: &str) -> Option<u8> {
+  let lower = s.to_ascii_lowercase();
+  let rest = lower.strip_prefix("toc")?;
+  let digits = rest.trim_start_matches([' ', '_', '-']);
+  match digits.parse::<u8>() {
+    Ok(n @ 1..=9) => Some(n),
+    _ => None,
+  }
+}
+
 fn read_zip_text<R: Read + std::io::Seek>(zip: &mut ZipArchive<R>, path: &str) -> Option<String> {
   let mut file = zip.by_name(path).ok()?;
   let mut s = String::new();
@@ -282,36 +385,90 @@ fn children<'a, 'b>(
     .filter(move |n| n.is_element() && n.tag_name().name() == local)
 }
 
+/// Document-wide lookups shared by every part (body, notes, comments).
+struct ParseContext<'a> {
+  styles: &'a StylesInfo,
+  size_buckets: &'a HashMap<String, Vec<u32>>,
+  numbering: &'a NumberingInfo,
+  options: &'a DocxParseOptions,
+}
+
 fn parse_paragraph_with_listinfo(
   node: &Node,
   rels: &Relationships,
-  styles: &StylesInfo,
-  size_buckets: &HashMap<String, Vec<u32>>,
-  numbering: &NumberingInfo,
+  ctx: &ParseContext,
 ) -> Option<(Paragraph, Option<ListInfo>)> {
-  let kind = paragraph_kind(node, styles, size_buckets);
+  let kind = paragraph_kind(node, ctx.styles, ctx.size_buckets);
   let base_style = paragraph_run_style(node);
+  let inlines = parse_paragraph_content(node, rels, &base_style, ctx.options);
+
+  let list_info = paragraph_list_info(node, ctx.numbering);
+  Some((
+    Paragraph {
+      kind,
+      inlines,
+      attrs: Default::default(),
+    },
+    list_info,
+  ))
+}
+
+fn parse_paragraph_content(
+  parent: &Node,
+  rels: &Relationships,
+  base_style: &RunStyle,
+  options: &DocxParseOptions,
+) -> Vec<Inline> {
   let mut inlines = Vec::new();
 
-  for child in node.children().filter(|n| n.is_element()) {
-    if is_tag(&child, "r") {
-      let run_inlines = parse_run(&child, rels, &base_style);
+  for c in parent.children().filter(|n| n.is_element()) {
+    if is_tag(&c, "r") {
+      let run_inlines = parse_run(&c, rels, base_style);
       inlines.extend(run_inlines);
-    } else if is_tag(&child, "hyperlink") {
-      if let Some(link) = parse_hyperlink(&child, rels, &base_style) {
+    } else if is_tag(&c, "hyperlink") {
+      if let Some(link) = parse_hyperlink(&c, rels, base_style) {
         inlines.push(link);
       }
-    } else if is_tag(&child, "bookmarkStart") {
-      if let Some(name) = get_attr_local(&child, "name") {
+    } else if is_tag(&c, "ins") || is_tag(&c, "moveTo") {
+      inlines.extend(parse_revision(&c, rels, base_style, options, true));
+    } else if is_tag(&c, "del") || is_tag(&c, "moveFrom") {
+      inlines.extend(parse_revision(&c, rels, base_style, options, false));
+    } else if is_tag(&c, "sdt") {
+      if let Some(value) = sdt_form_value(&c) {
+        inlines.push(value);
+      } else if let Some(content) = child(&c, "sdtContent") {
+        inlines.extend(parse_paragraph_content(&content, rels, base_style, options));
+      }
+    } else if is_tag(&c, "bookmarkStart") {
+      if let Some(name) = get_attr_local(&c, "name") {
         inlines.push(Inline::Bookmark(BookmarkId(name.to_string())));
       }
-    } else if is_tag(&child, "br") {
+    } else if is_tag(&c, "br") {
       inlines.push(Inline::LineBreak);
     }
   }
 
-  let list_info = paragraph_list_info(node, numbering);
-  Some((Paragraph { kind, inlines }, list_info))
+  inlines
+}
+
+/// Checkboxes and drop-downs keep their state in `w:sdtPr`; every other
+/// content control is rendered from its `w:sdtContent`.
+fn sdt_form_value(sdt: &Node) -> Option<Inline> {
+  let props = child(sdt, "sdtPr")?;
+  if let Some(checkbox) = child(&props, "checkbox") {
+    let checked = child(&checkbox, "checked")
+      .and_then(|c| get_attr_local(&c, "val"))
+      .is_some_and(|v| matches!(v, "1" | "true" | "on"));
+    let glyph = if checked { "\u{2611}" } else { "\u{2610}" }; // ☑ / ☐
+    return Some(Inline::Text(glyph.to_string()));
+  }
+
+  let list = child(&props, "dropDownList")?;
+  let selected = get_attr_local(&list, "lastValue")?;
+  let item =
+    children(&list, "listItem").find(|item| get_attr_local(item, "value") == Some(selected))?;
+  let text = get_attr_local(&item, "displayText").unwrap_or(selected);
+  Some(Inline::Text(text.to_string()))
 }
 
 fn paragraph_kind(
@@ -592,7 +749,7 @@ fn parse_run(run: &Node, _rels: &Relationships, base_style: &RunStyle) -> Vec<In
   let mut out = Vec::new();
 
   for c in run.children().filter(|n| n.is_element()) {
-    if is_tag(&c, "t") {
+    if is_tag(&c, "t") || is_tag(&c, "delText") {
       if let Some(text) = c.text() {
         out.push(Inline::Text(text.to_string()));
       }
@@ -653,23 +810,97 @@ fn parse_hyperlink(node: &Node, rels: &Relationships, base_style: &RunStyle) ->
   Some(Inline::Link { href, children })
 }
 
+fn parse_revision(
+  node: &Node,
+  rels: &Relationships,
+  base_style: &RunStyle,
+  options: &DocxParseOptions,
+  inserted: bool,
+) -> Vec<Inline> {
+  let keep = match options.tracked_changes {
+    TrackedChangeMode::Accept => inserted,
+    TrackedChangeMode::Reject => !inserted,
+    TrackedChangeMode::ShowBoth => true,
+  };
+  if !keep {
+    return Vec::new();
+  }
+
+  let mut out = Vec::new();
+  for child in node.children().filter(|n| n.is_element()) {
+    if is_tag(&child, "r") {
+      out.extend(parse_run(&child, rels, base_style));
+    } else if is_tag(&child, "hyperlink") {
+      if let Some(link) = parse_hyperlink(&child, rels, base_style) {
+        out.push(link);
+      }
+    }
+  }
+
+  if options.tracked_changes == TrackedChangeMode::ShowBoth && !out.is_empty() {
+    return if inserted {
+      vec![Inline::Strong(out)]
+    } else {
+      vec![Inline::Del(out)]
+    };
+  }
+  out
+}
+
 fn parse_table<R: Read + Seek>(
   node: &Node,
   rels: &Relationships,
-  styles: &StylesInfo,
-  size_buckets: &HashMap<String, Vec<u32>>,
-  numbering: &NumberingInfo,
+  ctx: &ParseContext,
   zip: &mut ZipArchive<R>,
 ) -> Option<Table> {
-  let mut rows = Vec::new();
+  let mut rows: Vec<TableRow> = Vec::new();
+  // grid column -> (row index, cell index) of the cell a vertical merge started at
+  let mut open_merges: HashMap<u32, (usize, usize)> = HashMap::new();
+
   for tr in children(node, "tr") {
     let kind = table_row_kind(&tr);
     let mut cells = Vec::new();
+    let mut grid_col = child(&tr, "trPr")
+      .and_then(|trpr| child(&trpr, "gridBefore"))
+      .and_then(|n| get_attr_local(&n, "val"))
+      .and_then(|v| v.parse::<u32>().ok())
+      .unwrap_or(0);
+
     for tc in children(&tr, "tc") {
-      let cell_blocks = parse_block_children(&tc, rels, styles, size_buckets, numbering, zip);
+      let tcpr = child(&tc, "tcPr");
+      let colspan = tcpr
+        .and_then(|p| child(&p, "gridSpan"))
+        .and_then(|n| get_attr_local(&n, "val"))
+        .and_then(|v| v.parse::<u32>().ok())
+        .and_then(NonZeroU32::new)
+        .unwrap_or_else(|| NonZeroU32::new(1).unwrap());
+      let v_merge = tcpr
+        .and_then(|p| child(&p, "vMerge"))
+        .map(|n| get_attr_local(&n, "val").unwrap_or("continue"));
+
+      let col = grid_col;
+      grid_col += colspan.get();
+
+      match v_merge {
+        Some("continue") => {
+          if let Some(&(r, c)) = open_merges.get(&col) {
+            let origin = &mut rows[r].cells[c];
+            origin.rowspan = origin.rowspan.saturating_add(1);
+            continue;
+          }
+        }
+        Some(_) => {
+          open_merges.insert(col, (rows.len(), cells.len()));
+        }
+        None => {
+          open_merges.remove(&col);
+        }
+      }
+
+      let cell_blocks = parse_block_children(&tc, rels, ctx, zip);
       let cell = TableCell {
         blocks: cell_blocks,
-        colspan: NonZeroU32::new(1).unwrap(),
+        colspan,
         rowspan: NonZeroU32::new(1).unwrap(),
       };
       cells.push(cell);
@@ -776,9 +1007,7 @@ fn paragraph_list_info(p: &Node, numbering: &NumberingInfo) -> Option<ListInfo>
 fn parse_block_children<R: Read + Seek>(
   parent: &Node,
   rels: &Relationships,
-  styles: &StylesInfo,
-  size_buckets: &HashMap<String, Vec<u32>>,
-  numbering: &NumberingInfo,
+  ctx: &ParseContext,
   zip: &mut ZipArchive<R>,
 ) -> Vec<Block> {
   let nodes: Vec<Node> = parent.children().filter(|n| n.is_element()).collect();
@@ -788,32 +1017,41 @@ fn parse_block_children<R: Read + Seek>(
   while i < nodes.len() {
     let node = &nodes[i];
     if is_tag(node, "p") {
-      if paragraph_list_info(node, numbering).is_some() {
-        let (list, new_i) = parse_list(&nodes, i, rels, styles, size_buckets, numbering, zip);
+      if paragraph_list_info(node, ctx.numbering).is_some() {
+        let (list, new_i) = parse_list(&nodes, i, rels, ctx, zip);
         if !list.items.is_empty() {
           out.push(Block::List(list));
         }
         i = new_i;
         continue;
       }
-      if let Some(image) = parse_image_paragraph(node, rels, zip) {
+      if let Some(image) = parse_image_paragraph(node, rels, ctx.options, zip) {
         out.push(Block::Image(image));
         i += 1;
         continue;
       }
-      if let Some((para, _)) =
-        parse_paragraph_with_listinfo(node, rels, styles, size_buckets, numbering)
-      {
+      if let Some((para, _)) = parse_paragraph_with_listinfo(node, rels, ctx) {
         if paragraph_has_visible_content(&para) {
           out.push(Block::Paragraph(para));
         }
       }
       i += 1;
     } else if is_tag(node, "tbl") {
-      if let Some(table) = parse_table(node, rels, styles, size_buckets, numbering, zip) {
+      if let Some(table) = parse_table(node, rels, ctx, zip) {
         out.push(Block::Table(table));
       }
       i += 1;
+    } else if is_tag(node, "sdt") {
+      if let Some(value) = sdt_form_value(node) {
+        out.push(Block::Paragraph(Paragraph {
+          kind: ParagraphKind::Normal,
+          inlines: vec![value],
+          attrs: Default::default(),
+        }));
+      } else if let Some(content) = child(node, "sdtContent") {
+        out.extend(parse_block_children(&content, rels, ctx, zip));
+      }
+      i += 1;
     } else {
       i += 1;
     }
@@ -825,13 +1063,11 @@ fn parse_list<R: Read + Seek>(
   nodes: &[Node],
   mut i: usize,
   rels: &Relationships,
-  styles: &StylesInfo,
-  size_buckets: &HashMap<String, Vec<u32>>,
-  numbering: &NumberingInfo,
+  ctx: &ParseContext,
   zip: &mut ZipArchive<R>,
 ) -> (List, usize) {
   let first_info =
-    paragraph_list_info(&nodes[i], numbering).expect("parse_list called at non-list paragraph");
+    paragraph_list_info(&nodes[i], ctx.numbering).expect("parse_list called at non-list paragraph");
   let base_ilvl = first_info.ilvl;
   let base_num_id = first_info.num_id.clone();
   let base_type = first_info.list_type;
@@ -846,7 +1082,7 @@ fn parse_list<R: Read + Seek>(
     if !is_tag(node, "p") {
       break;
     }
-    let info = match paragraph_list_info(node, numbering) {
+    let info = match paragraph_list_info(node, ctx.numbering) {
       Some(x) => x,
       None => break,
     };
@@ -859,11 +1095,9 @@ fn parse_list<R: Read + Seek>(
 
     if info.ilvl == base_ilvl {
       let mut blocks: Vec<Block> = Vec::new();
-      if let Some(image) = parse_image_paragraph(node, rels, zip) {
+      if let Some(image) = parse_image_paragraph(node, rels, ctx.options, zip) {
         blocks.push(Block::Image(image));
-      } else if let Some((para, _)) =
-        parse_paragraph_with_listinfo(node, rels, styles, size_buckets, numbering)
-      {
+      } else if let Some((para, _)) = parse_paragraph_with_listinfo(node, rels, ctx) {
         if paragraph_has_visible_content(&para) {
           blocks.push(Block::Paragraph(para));
         }
@@ -879,9 +1113,9 @@ fn parse_list<R: Read + Seek>(
         if !is_tag(node2, "p") {
           break;
         }
-        match paragraph_list_info(node2, numbering) {
+        match paragraph_list_info(node2, ctx.numbering) {
           Some(sub) if sub.ilvl > base_ilvl => {
-            let (sublist, new_i) = parse_list(nodes, i, rels, styles, size_buckets, numbering, zip);
+            let (sublist, new_i) = parse_list(nodes, i, rels, ctx, zip);
             if let Some(last) = list.items.last_mut() {
               last.blocks.push(Block::List(sublist));
             }
@@ -925,6 +1159,7 @@ fn inline_is_visible(i: &Inline) -> bool {
 fn parse_image_paragraph<R: Read + Seek>(
   p: &Node,
   rels: &Relationships,
+  options: &DocxParseOptions,
   zip: &mut ZipArchive<R>,
 ) -> Option<Image> {
   let has_text = p
@@ -936,13 +1171,13 @@ fn parse_image_paragraph<R: Read + Seek>(
   }
 
   if let Some(drawing) = p.descendants().find(|n| is_tag(n, "drawing")) {
-    if let Some(img) = image_from_drawing(&drawing, rels, zip) {
+    if let Some(img) = image_from_drawing(&drawing, rels, options, zip) {
       return Some(img);
     }
   }
 
   if let Some(pict) = p.descendants().find(|n| is_tag(n, "pict")) {
-    if let Some(img) = image_from_vml(&pict, rels, zip) {
+    if let Some(img) = image_from_vml(&pict, rels, options, zip) {
       return Some(img);
     }
   }
@@ -952,6 +1187,7 @@ fn parse_image_paragraph<R: Read + Seek>(
 fn image_from_drawing<R: Read + Seek>(
   drawing: &Node,
   rels: &Relationships,
+  options: &DocxParseOptions,
   zip: &mut ZipArchive<R>,
 ) -> Option<Image> {
   let blip = drawing.descendants().find(|n| is_tag(n, "blip"))?;
@@ -961,35 +1197,75 @@ fn image_from_drawing<R: Read + Seek>(
     .find(|n| is_tag(n, "docPr"))
     .and_then(|n| get_attr_local(&n, "descr").or_else(|| get_attr_local(&n, "title")))
     .map(|s| s.to_string());
-  image_from_relationship_id(rel_id, rels, zip, alt)
+  image_from_relationship_id(rel_id, rels, options, zip, alt)
 }
 
 fn image_from_vml<R: Read + Seek>(
   pict: &Node,
   rels: &Relationships,
+  options: &DocxParseOptions,
   zip: &mut ZipArchive<R>,
 ) -> Option<Image> {
   let imagedata = pict.descendants().find(|n| is_tag(n, "imagedata"))?;
   let rel_id = get_attr_local(&imagedata, "id")?;
   let alt = get_attr_local(&imagedata, "title").map(|s| s.to_string());
-  image_from_relationship_id(rel_id, rels, zip, alt)
+  image_from_relationship_id(rel_id, rels, options, zip, alt)
 }
 
 fn image_from_relationship_id<R: Read + Seek>(
   rid: &str,
   rels: &Relationships,
-  _zip: &mut ZipArchive<R>,
+  options: &DocxParseOptions,
+  zip: &mut ZipArchive<R>,
   alt: Option<String>,
 ) -> Option<Image> {
   let target = rels.get(rid)?;
-  // only include external images (http/https URLs)
   if target.starts_with("http://") || target.starts_with("https://") {
     return Some(Image {
       src: target.to_string(),
       alt,
     });
   }
-  None
+  // embedded images are dropped unless the caller asked for data URIs
+  if !options.embed_images {
+    return None;
+  }
+
+  let path = match target.strip_prefix('/') {
+    Some(absolute) => absolute.to_string(),
+    None => format!("word/{target}"),
+  };
+  let mime = image_mime_type(&path)?;
+  let mut file = zip.by_name(&path).ok()?;
+  if let Some(max) = options.max_image_size_bytes {
+    if file.size() > max as u64 {
+      return None;
+    }
+  }
+  let mut bytes = Vec::new();
+  file.read_to_end(&mut bytes).ok()?;
+
+  Some(Image {
+    src: format!("data:{mime};base64,{}", BASE64_STANDARD.encode(&bytes)),
+    alt,
+  })
+}
+
+pub(crate) fn image_mime_type(path: &str) -> Option<&'static str> {
+  let ext = path.rsplit_once('.')?.1.to_ascii_lowercase();
+  let mime = match ext.as_str() {
+    "png" => "image/png",
+    "jpg" | "jpeg" => "image/jpeg",
+    "gif" => "image/gif",
+    "bmp" => "image/bmp",
+    "tif" | "tiff" => "image/tiff",
+    "webp" => "image/webp",
+    "svg" => "image/svg+xml",
+    "emf" => "image/emf",
+    "wmf" => "image/wmf",
+    _ => return None,
+  };
+  Some(mime)
 }
 
 fn read_notes<R: Read + Seek>(
@@ -997,9 +1273,7 @@ fn read_notes<R: Read + Seek>(
   xml_path: &str,
   rels_path: &str,
   kind: NoteKind,
-  styles: &StylesInfo,
-  size_buckets: &HashMap<String, Vec<u32>>,
-  numbering: &NumberingInfo,
+  ctx: &ParseContext,
 ) -> Vec<Note> {
   let text = match read_zip_text(zip, xml_path) {
     Some(t) => t,
@@ -1026,7 +1300,7 @@ fn read_notes<R: Read + Seek>(
         continue;
       }
     }
-    let blocks = parse_block_children(&n, &rels, styles, size_buckets, numbering, zip);
+    let blocks = parse_block_children(&n, &rels, ctx, zip);
     notes.push(Note {
       id: NoteId(id.to_string()),
       kind,
@@ -1040,9 +1314,7 @@ fn read_comments<R: Read + Seek>(
   zip: &mut ZipArchive<R>,
   xml_path: &str,
   rels_path: &str,
-  styles: &StylesInfo,
-  size_buckets: &HashMap<String, Vec<u32>>,
-  numbering: &NumberingInfo,
+  ctx: &ParseContext,
 ) -> Vec<Comment> {
   let text = match read_zip_text(zip, xml_path) {
     Some(t) => t,
@@ -1062,13 +1334,301 @@ fn read_comments<R: Read + Seek>(
 
     let author = get_attr_local(&c, "author").map(|s| s.to_string());
     let initials = get_attr_local(&c, "initials").map(|s| s.to_string());
-    let blocks = parse_block_children(&c, &rels, styles, size_buckets, numbering, zip);
+    let date: Option<DateTime<Utc>> = get_attr_local(&c, "date")
+      .and_then(|d| DateTime::parse_from_rfc3339(d.trim()).ok())
+      .map(|d| d.with_timezone(&Utc));
+    let blocks = parse_block_children(&c, &rels, ctx, zip);
     out.push(Comment {
       id: CommentId(id.to_string()),
       author_name: author,
       author_initials: initials,
+      date: date.map(|d| d.to_rfc3339_opts(SecondsFormat::Secs, true)),
       blocks,
     });
   }
   out
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::io::Write;
+  use zip::write::{SimpleFileOptions, ZipWriter};
+
+  fn build_docx(body: &str) -> Vec<u8> {
+    build_docx_with_parts(body, &[])
+  }
+
+  fn build_docx_with_parts(body: &str, parts: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut zip = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for (name, bytes) in parts {
+      zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+      zip.write_all(bytes).unwrap();
+    }
+    zip
+      .start_file("word/document.xml", SimpleFileOptions::default())
+      .unwrap();
+    write!(
+      zip,
+      r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"
+  xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+<w:body>{body}</w:body>
+</w:document>"#
+    )
+    .unwrap();
+    zip.finish().unwrap().into_inner()
+  }
+
+  fn cell_xml(text: &str, tcpr: &str) -> String {
+    format!(r#"<w:tc><w:tcPr>{tcpr}</w:tcPr><w:p><w:r><w:t>{text}</w:t></w:r></w:p></w:tc>"#)
+  }
+
+  fn cell_text(cell: &TableCell) -> String {
+    match cell.blocks.first() {
+      Some(Block::Paragraph(p)) => match p.inlines.first() {
+        Some(Inline::Text(t)) => t.clone(),
+        _ => String::new(),
+      },
+      _ => String::new(),
+    }
+  }
+
+  #[test]
+  fn parse_table_reads_grid_span_and_vertical_merges() {
+    let restart = r#"<w:vMerge w:val="restart"/>"#;
+    let cont = r#"<w:vMerge/>"#;
+    let body = format!(
+      "<w:tbl><w:tr>{}{}</w:tr><w:tr>{}{}{}</w:tr><w:tr>{}{}{}</w:tr></w:tbl>",
+      cell_xml("A", r#"<w:gridSpan w:val="2"/>"#),
+      cell_xml("B", restart),
+      cell_xml("C", restart),
+      cell_xml("D", ""),
+      cell_xml("", cont),
+      cell_xml("", cont),
+      cell_xml("G", ""),
+      cell_xml("H", ""),
+    );
+
+    let doc = DocxProvider::new()
+      .parse_buffer(&build_docx(&body))
+      .unwrap();
+    let Some(Block::Table(table)) = doc.blocks.first() else {
+      panic!("expected a table, got {:?}", doc.blocks);
+    };
+
+    let spans: Vec<Vec<(String, u32, u32)>> = table
+      .rows
+      .iter()
+      .map(|r| {
+        r.cells
+          .iter()
+          .map(|c| (cell_text(c), c.colspan.get(), c.rowspan.get()))
+          .collect()
+      })
+      .collect();
+
+    assert_eq!(
+      spans,
+      vec![
+        vec![("A".to_string(), 2, 1), ("B".to_string(), 1, 2)],
+        vec![("C".to_string(), 1, 2), ("D".to_string(), 1, 1)],
+        vec![("G".to_string(), 1, 1), ("H".to_string(), 1, 1)],
+      ]
+    );
+
+    let html = crate::document::renderers::html::HtmlRenderer::new().render(&doc);
+    assert!(html.contains(r#"<td colspan="2">A</td>"#));
+    assert!(html.contains(r#"<td rowspan="2">B</td>"#));
+    assert!(html.contains(r#"<td rowspan="2">C</td>"#));
+  }
+
+  fn describe(inlines: &[Inline]) -> String {
+    inlines
+      .iter()
+      .map(|i| match i {
+        Inline::Text(t) => t.clone(),
+        Inline::Strong(c) => format!("+[{}]", describe(c)),
+        Inline::Del(c) => format!("-[{}]", describe(c)),
+        other => format!("{other:?}"),
+      })
+      .collect()
+  }
+
+  #[test]
+  fn tracked_changes_follow_the_requested_mode() {
+    let body = r#"<w:p>
+      <w:r><w:t xml:space="preserve">Kept </w:t></w:r>
+      <w:ins w:id="1" w:author="a"><w:r><w:t>added</w:t></w:r></w:ins>
+      <w:del w:id="2" w:author="a"><w:r><w:delText>removed</w:delText></w:r></w:del>
+    </w:p>"#;
+    let data = build_docx(body);
+
+    let parse = |mode| {
+      let doc = DocxProvider::with_options(DocxParseOptions {
+        tracked_changes: mode,
+        ..Default::default()
+      })
+      .parse_buffer(&data)
+      .unwrap();
+      match doc.blocks.into_iter().next() {
+        Some(Block::Paragraph(p)) => p.inlines,
+        other => panic!("expected a paragraph, got {other:?}"),
+      }
+    };
+
+    assert_eq!(describe(&parse(TrackedChangeMode::Accept)), "Kept added");
+    assert_eq!(describe(&parse(TrackedChangeMode::Reject)), "Kept removed");
+    assert_eq!(
+      describe(&parse(TrackedChangeMode::ShowBoth)),
+      "Kept +[added]-[removed]"
+    );
+  }
+
+  #[test]
+  fn content_controls_render_their_value() {
+    let body = r#"<w:p xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml">
+      <w:sdt>
+        <w:sdtPr><w14:checkbox><w14:checked w14:val="1"/></w14:checkbox></w:sdtPr>
+        <w:sdtContent><w:r><w:t>X</w:t></w:r></w:sdtContent>
+      </w:sdt>
+      <w:sdt>
+        <w:sdtPr><w14:checkbox><w14:checked w14:val="0"/></w14:checkbox></w:sdtPr>
+        <w:sdtContent><w:r><w:t>X</w:t></w:r></w:sdtContent>
+      </w:sdt>
+      <w:r><w:t xml:space="preserve"> Colour: </w:t></w:r>
+      <w:sdt>
+        <w:sdtPr>
+          <w:dropDownList w:lastValue="g">
+            <w:listItem w:displayText="Red" w:value="r"/>
+            <w:listItem w:displayText="Green" w:value="g"/>
+          </w:dropDownList>
+        </w:sdtPr>
+        <w:sdtContent><w:r><w:t>Choose an item.</w:t></w:r></w:sdtContent>
+      </w:sdt>
+    </w:p>
+    <w:sdt>
+      <w:sdtPr><w:tag w:val="plainText"/><w:text/></w:sdtPr>
+      <w:sdtContent><w:p><w:r><w:t>Filled in</w:t></w:r></w:p></w:sdtContent>
+    </w:sdt>"#;
+    let doc = DocxProvider::new().parse_buffer(&build_docx(body)).unwrap();
+
+    let texts: Vec<String> = doc
+      .blocks
+      .iter()
+      .map(|b| match b {
+        Block::Paragraph(p) => describe(&p.inlines),
+        other => panic!("expected a paragraph, got {other:?}"),
+      })
+      .collect();
+    assert_eq!(texts, vec!["\u{2611}\u{2610} Colour: Green", "Filled in"]);
+  }
+
+  #[test]
+  fn embedded_images_become_data_uris_when_requested() {
+    let body = r#"<w:p><w:r><w:drawing>
+      <wp:inline xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing">
+        <wp:docPr id="1" name="Picture 1" descr="A logo"/>
+        <a:graphic xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+          <a:graphicData><a:blip r:embed="rIdImg"/></a:graphicData>
+        </a:graphic>
+      </wp:inline>
+    </w:drawing></w:r></w:p>"#;
+    let rels = br#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Id="rIdImg" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image" Target="media/image1.png"/>
+</Relationships>"#;
+    let png = b"\x89PNG\r\n\x1a\n";
+    let data = build_docx_with_parts(
+      body,
+      &[
+        ("word/_rels/document.xml.rels", rels),
+        ("word/media/image1.png", png),
+      ],
+    );
+
+    let doc = DocxProvider::new().parse_buffer(&data).unwrap();
+    assert!(doc.blocks.is_empty());
+
+    let doc = DocxProvider::with_options(DocxParseOptions {
+      embed_images: true,
+      ..Default::default()
+    })
+    .parse_buffer(&data)
+    .unwrap();
+    let Some(Block::Image(image)) = doc.blocks.first() else {
+      panic!("expected an image, got {:?}", doc.blocks);
+    };
+    assert!(image.src.starts_with("data:image/png;base64,"));
+    assert_eq!(
+      BASE64_STANDARD
+        .decode(image.src.trim_start_matches("data:image/png;base64,"))
+        .unwrap(),
+      png
+    );
+    assert_eq!(image.alt.as_deref(), Some("A logo"));
+
+    let doc = DocxProvider::with_options(DocxParseOptions {
+      embed_images: true,
+      max_image_size_bytes: Some(png.len() as u32 - 1),
+      ..Default::default()
+    })
+    .parse_buffer(&data)
+    .unwrap();
+    assert!(doc.blocks.is_empty());
+  }
+
+  #[test]
+  fn comments_carry_their_date() {
+    let comments = br#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:comments xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:comment w:id="0" w:author="Ada" w:date="2024-03-01T09:30:00+02:00">
+    <w:p><w:r><w:t>Dated</w:t></w:r></w:p>
+  </w:comment>
+  <w:comment w:id="1" w:author="Bob" w:date="yesterday">
+    <w:p><w:r><w:t>Undated</w:t></w:r></w:p>
+  </w:comment>
+</w:comments>"#;
+    let data = build_docx_with_parts(
+      "<w:p><w:r><w:t>Body</w:t></w:r></w:p>",
+      &[("word/comments.xml", comments)],
+    );
+
+    let doc = DocxProvider::new().parse_buffer(&data).unwrap();
+    let dates: Vec<Option<&str>> = doc.comments.iter().map(|c| c.date.as_deref()).collect();
+    assert_eq!(dates, vec![Some("2024-03-01T07:30:00Z"), None]);
+  }
+
+  #[test]
+  fn read_toc_entries_uses_toc_styles() {
+    let entry = |style: &str, anchor: &str, title: &str, page: &str| {
+      format!(
+        r#"<w:p><w:pPr><w:pStyle w:val="{style}"/><w:tabs><w:tab w:val="right" w:pos="9350"/></w:tabs></w:pPr>
+        <w:hyperlink w:anchor="{anchor}"><w:r><w:t>{title}</w:t></w:r><w:r><w:tab/></w:r>
+        <w:r><w:instrText> PAGEREF {anchor} \h </w:instrText></w:r><w:r><w:t>{page}</w:t></w:r></w:hyperlink></w:p>"#
+      )
+    };
+    let body = format!(
+      "{}{}<w:p><w:r><w:t>Body text</w:t></w:r></w:p>",
+      entry("TOC1", "_Toc1", "Introduction", "1"),
+      entry("TOC2", "_Toc2", "Background", "3"),
+    );
+
+    let entries = read_toc_entries(&build_docx(&body)).unwrap();
+    assert_eq!(
+      entries,
+      vec![
+        TocEntry {
+          level: 1,
+          title: "Introduction".to_string(),
+          bookmark_id: Some(BookmarkId("_Toc1".to_string())),
+        },
+        TocEntry {
+          level: 2,
+          title: "Background".to_string(),
+          bookmark_id: Some(BookmarkId("_Toc2".to_string())),
+        },
+      ]
+    );
+  }
+}
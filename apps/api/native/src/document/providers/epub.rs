@@ -0,0 +1,857 @@
+use crate::document::model::*;
+use crate::document::providers::docx::image_mime_type;
+use crate::document::providers::DocumentProvider;
+use base64::prelude::*;
+use chrono::{DateTime, NaiveDate, Utc};
+use kuchikiki::{parse_html, traits::TendrilSink, NodeRef};
+use roxmltree::{Document as XmlDoc, Node};
+use std::collections::HashMap;
+use std::error::Error;
+use std::io::{Read, Seek};
+use std::num::NonZeroU32;
+use zip::read::ZipArchive;
+
+#[derive(Debug, Clone, Default)]
+pub struct EpubParseOptions {
+  /// Inline images stored in the archive as base64 `data:` URIs.
+  pub embed_images: bool,
+  /// Embedded images larger than this are dropped instead of inlined.
+  pub max_image_size_bytes: Option<u32>,
+}
+
+pub struct EpubProvider {
+  options: EpubParseOptions,
+}
+
+impl EpubProvider {
+  pub fn new() -> Self {
+    Self::with_options(EpubParseOptions::default())
+  }
+
+  pub fn with_options(options: EpubParseOptions) -> Self {
+    Self { options }
+  }
+}
+
+impl DocumentProvider for EpubProvider {
+  fn parse_buffer(&self, data: &[u8]) -> Result<Document, Box<dyn Error + Send + Sync>> {
+    let cursor = std::io::Cursor::new(data);
+    let mut zip = ZipArchive::new(cursor)?;
+
+    let container = read_zip_text(&mut zip, "META-INF/container.xml")
+      .ok_or("Missing META-INF/container.xml in document")?;
+    let opf_path = rootfile_path(&container).ok_or("No rootfile in META-INF/container.xml")?;
+
+    let opf_text =
+      read_zip_text(&mut zip, &opf_path).ok_or("Missing OPF package document in EPUB")?;
+    let opf = XmlDoc::parse(strip_bom(&opf_text))?;
+
+    let metadata = read_dublin_core(&opf);
+    let base_dir = opf_path.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("");
+
+    let mut blocks = Vec::new();
+    for href in spine_hrefs(&opf) {
+      let path = resolve_path(base_dir, &href);
+      let Some(xhtml) = read_zip_text(&mut zip, &path) else {
+        continue;
+      };
+      let document = parse_html().one(strip_bom(&xhtml));
+      let root = document
+        .select_first("body")
+        .map(|b| b.as_node().clone())
+        .unwrap_or(document);
+      let chapter_dir = path.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("");
+      blocks.extend(resolve_images(
+        parse_flow(&root),
+        chapter_dir,
+        &mut zip,
+        &self.options,
+      ));
+    }
+
+    Ok(Document {
+      blocks,
+      metadata,
+      notes: Vec::new(),
+      comments: Vec::new(),
+    })
+  }
+
+  fn name(&self) -> &'static str {
+    "epub"
+  }
+}
+
+fn read_zip_text<R: Read + Seek>(zip: &mut ZipArchive<R>, path: &str) -> Option<String> {
+  let mut file = zip.by_name(path).ok()?;
+  let mut s = String::new();
+  file.read_to_string(&mut s).ok()?;
+  Some(s)
+}
+
+fn strip_bom(s: &str) -> &str {
+  const BOM: char = '\u{FEFF}';
+  s.strip_prefix(BOM).unwrap_or(s)
+}
+
+fn is_tag(node: &Node, local: &str) -> bool {
+  node.is_element() && node.tag_name().name() == local
+}
+
+fn get_attr_local<'a>(node: &Node<'a, 'a>, local: &str) -> Option<&'a str> {
+  node
+    .attributes()
+    .find(|a| {
+      let name = a.name();
+      match name.rsplit_once(':') {
+        Some((_, l)) => l == local,
+        None => name == local,
+      }
+    })
+    .map(|a| a.value())
+}
+
+fn rootfile_path(container: &str) -> Option<String> {
+  let xml = XmlDoc::parse(strip_bom(container)).ok()?;
+  let rootfiles: Vec<Node> = xml
+    .descendants()
+    .filter(|n| is_tag(n, "rootfile"))
+    .collect();
+  rootfiles
+    .iter()
+    .find(|n| get_attr_local(n, "media-type") == Some("application/oebps-package+xml"))
+    .or_else(|| rootfiles.first())
+    .and_then(|n| get_attr_local(n, "full-path"))
+    .map(|s| s.to_string())
+}
+
+fn spine_hrefs(opf: &XmlDoc) -> Vec<String> {
+  let manifest: HashMap<&str, &str> = opf
+    .descendants()
+    .filter(|n| is_tag(n, "item"))
+    .filter_map(|n| Some((get_attr_local(&n, "id")?, get_attr_local(&n, "href")?)))
+    .collect();
+
+  opf
+    .descendants()
+    .filter(|n| is_tag(n, "itemref"))
+    .filter(|n| get_attr_local(n, "linear") != Some("no"))
+    .filter_map(|n| get_attr_local(&n, "idref"))
+    .filter_map(|id| manifest.get(id))
+    .map(|href| href.to_string())
+    .collect()
+}
+
+/// Resolves an `href` relative to `base_dir` (the OPF file's or a chapter's
+/// directory) into a ZIP entry path.
+fn resolve_path(base_dir: &str, href: &str) -> String {
+  let href = href.split('#').next().unwrap_or(href);
+  let mut parts: Vec<&str> = if base_dir.is_empty() {
+    Vec::new()
+  } else {
+    base_dir.split('/').collect()
+  };
+  for segment in href.split('/') {
+    match segment {
+      "" | "." => {}
+      ".." => {
+        parts.pop();
+      }
+      s => parts.push(s),
+    }
+  }
+  percent_decode(&parts.join("/"))
+}
+
+fn percent_decode(s: &str) -> String {
+  if !s.contains('%') {
+    return s.to_string();
+  }
+  let bytes = s.as_bytes();
+  let mut out = Vec::with_capacity(bytes.len());
+  let mut i = 0;
+  while i < bytes.len() {
+    if bytes[i] == b'%' && i + 2 < bytes.len() {
+      let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+      if let Some(v) = hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+        out.push(v);
+        i += 3;
+        continue;
+      }
+    }
+    out.push(bytes[i]);
+    i += 1;
+  }
+  String::from_utf8_lossy(&out).into_owned()
+}
+
+fn read_dublin_core(opf: &XmlDoc) -> DocumentMetadata {
+  let mut meta = DocumentMetadata::default();
+  let Some(metadata) = opf.descendants().find(|n| is_tag(n, "metadata")) else {
+    return meta;
+  };
+
+  let dc_text = |local: &str| {
+    metadata
+      .descendants()
+      .find(|n| is_tag(n, local))
+      .and_then(|n| n.text())
+      .map(|t| t.trim())
+      .filter(|t| !t.is_empty())
+  };
+
+  if let Some(title) = dc_text("title") {
+    meta.title = Some(title.to_string());
+  }
+  if let Some(author) = dc_text("creator") {
+    if !author.eq_ignore_ascii_case("unknown") {
+      meta.author = Some(author.to_string());
+    }
+  }
+  if let Some(date) = dc_text("date") {
+    meta.created = parse_dc_date(date);
+  }
+
+  meta
+}
+
+/// `dc:date` is W3CDTF: a full timestamp, or a date truncated to year/month.
+fn parse_dc_date(s: &str) -> Option<DateTime<Utc>> {
+  if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+    return Some(DateTime::<Utc>::from(dt));
+  }
+  let padded = match s.len() {
+    4 => format!("{s}-01-01"),
+    7 => format!("{s}-01"),
+    _ => s.to_string(),
+  };
+  let date = NaiveDate::parse_from_str(&padded, "%Y-%m-%d").ok()?;
+  Some(date.and_hms_opt(0, 0, 0)?.and_utc())
+}
+
+fn element_name(node: &NodeRef) -> Option<String> {
+  node
+    .as_element()
+    .map(|e| e.name.local.to_string().to_ascii_lowercase())
+}
+
+fn attr(node: &NodeRef, name: &str) -> Option<String> {
+  node
+    .as_element()
+    .and_then(|e| e.attributes.borrow().get(name).map(|v| v.to_string()))
+}
+
+fn is_block_element(name: &str) -> bool {
+  matches!(
+    name,
+    "p"
+      | "h1"
+      | "h2"
+      | "h3"
+      | "h4"
+      | "h5"
+      | "h6"
+      | "blockquote"
+      | "ul"
+      | "ol"
+      | "table"
+      | "pre"
+      | "div"
+      | "section"
+      | "article"
+      | "aside"
+      | "header"
+      | "footer"
+      | "main"
+      | "nav"
+      | "figure"
+      | "figcaption"
+      | "dl"
+      | "dt"
+      | "dd"
+      | "hr"
+      | "li"
+  )
+}
+
+fn is_skipped_element(name: &str) -> bool {
+  matches!(name, "script" | "style" | "head" | "title" | "svg" | "math")
+}
+
+/// Converts mixed block/inline content into blocks. Loose inline content
+/// between block elements is gathered into `Normal` paragraphs.
+fn parse_flow(node: &NodeRef) -> Vec<Block> {
+  let mut blocks = Vec::new();
+  let mut pending: Vec<Inline> = Vec::new();
+
+  for c in node.children() {
+    let Some(name) = element_name(&c) else {
+      pending.extend(parse_inline_node(&c));
+      continue;
+    };
+
+    if is_skipped_element(&name) {
+      continue;
+    }
+    if name == "img" {
+      flush_paragraph(&mut pending, &mut blocks);
+      if let Some(image) = image_from_element(&c) {
+        blocks.push(Block::Image(image));
+      }
+      continue;
+    }
+    if !is_block_element(&name) {
+      pending.extend(parse_inline_node(&c));
+      continue;
+    }
+
+    flush_paragraph(&mut pending, &mut blocks);
+    blocks.extend(parse_block_element(&c, &name));
+  }
+
+  flush_paragraph(&mut pending, &mut blocks);
+  blocks
+}
+
+fn flush_paragraph(pending: &mut Vec<Inline>, blocks: &mut Vec<Block>) {
+  let inlines = trim_inlines(std::mem::take(pending));
+  if inlines_have_visible_content(&inlines) {
+    blocks.push(Block::Paragraph(Paragraph {
+      kind: ParagraphKind::Normal,
+      inlines,
+      attrs: Default::default(),
+    }));
+  }
+}
+
+fn parse_block_element(node: &NodeRef, name: &str) -> Vec<Block> {
+  match name {
+    "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+      let level = name[1..].parse::<u8>().unwrap_or(1);
+      paragraph_block(node, ParagraphKind::Heading(level))
+    }
+    "p" => {
+      let images: Vec<Image> = node
+        .descendants()
+        .filter(|d| element_name(d).as_deref() == Some("img"))
+        .filter_map(|d| image_from_element(&d))
+        .collect();
+      if !images.is_empty() && node.text_contents().trim().is_empty() {
+        return images.into_iter().map(Block::Image).collect();
+      }
+      paragraph_block(node, ParagraphKind::Normal)
+    }
+    "blockquote" => parse_flow(node)
+      .into_iter()
+      .map(|b| match b {
+        Block::Paragraph(p) if p.kind == ParagraphKind::Normal => Block::Paragraph(Paragraph {
+          kind: ParagraphKind::Blockquote,
+          inlines: p.inlines,
+          attrs: p.attrs,
+        }),
+        other => other,
+      })
+      .collect(),
+    "ul" | "ol" => {
+      let list_type = if name == "ol" {
+        ListType::Ordered
+      } else {
+        ListType::Unordered
+      };
+      let items: Vec<ListItem> = node
+        .children()
+        .filter(|c| element_name(c).as_deref() == Some("li"))
+        .map(|li| ListItem {
+          blocks: parse_flow(&li),
+        })
+        .collect();
+      if items.is_empty() {
+        Vec::new()
+      } else {
+        vec![Block::List(List { items, list_type })]
+      }
+    }
+    "table" => {
+      let table = parse_table(node);
+      if table.rows.is_empty() {
+        Vec::new()
+      } else {
+        vec![Block::Table(table)]
+      }
+    }
+    "pre" => {
+      let code = node.text_contents();
+      if code.trim().is_empty() {
+        Vec::new()
+      } else {
+        vec![Block::Paragraph(Paragraph {
+          kind: ParagraphKind::Normal,
+          inlines: vec![Inline::Code(code)],
+          attrs: Default::default(),
+        })]
+      }
+    }
+    "hr" => Vec::new(),
+    _ => parse_flow(node),
+  }
+}
+
+fn paragraph_block(node: &NodeRef, kind: ParagraphKind) -> Vec<Block> {
+  let inlines = trim_inlines(parse_inline_children(node));
+  if inlines_have_visible_content(&inlines) {
+    vec![Block::Paragraph(Paragraph {
+      kind,
+      inlines,
+      attrs: Default::default(),
+    })]
+  } else {
+    Vec::new()
+  }
+}
+
+fn parse_inline_children(node: &NodeRef) -> Vec<Inline> {
+  node
+    .children()
+    .flat_map(|c| parse_inline_node(&c))
+    .collect()
+}
+
+fn parse_inline_node(node: &NodeRef) -> Vec<Inline> {
+  if let Some(text) = node.as_text() {
+    let collapsed = collapse_whitespace(&text.borrow());
+    if collapsed.is_empty() {
+      return Vec::new();
+    }
+    return vec![Inline::Text(collapsed)];
+  }
+
+  let Some(name) = element_name(node) else {
+    return Vec::new();
+  };
+
+  match name.as_str() {
+    "br" => vec![Inline::LineBreak],
+    "strong" | "b" => vec![Inline::Strong(parse_inline_children(node))],
+    "em" | "i" | "cite" => vec![Inline::Em(parse_inline_children(node))],
+    "del" | "s" | "strike" => vec![Inline::Del(parse_inline_children(node))],
+    "sup" => vec![Inline::Sup(parse_inline_children(node))],
+    "sub" => vec![Inline::Sub(parse_inline_children(node))],
+    "code" | "kbd" | "samp" | "tt" => vec![Inline::Code(node.text_contents())],
+    "a" => {
+      let children = parse_inline_children(node);
+      match attr(node, "href") {
+        Some(href) => vec![Inline::Link { href, children }],
+        None => children,
+      }
+    }
+    n if is_skipped_element(n) || n == "img" => Vec::new(),
+    _ => parse_inline_children(node),
+  }
+}
+
+fn collapse_whitespace(s: &str) -> String {
+  let mut out = String::with_capacity(s.len());
+  let mut prev_space = false;
+  for ch in s.chars() {
+    if ch.is_whitespace() {
+      if !prev_space {
+        out.push(' ');
+      }
+      prev_space = true;
+    } else {
+      out.push(ch);
+      prev_space = false;
+    }
+  }
+  out
+}
+
+fn trim_inlines(mut inlines: Vec<Inline>) -> Vec<Inline> {
+  if let Some(Inline::Text(t)) = inlines.first_mut() {
+    *t = t.trim_start().to_string();
+  }
+  if let Some(Inline::Text(t)) = inlines.last_mut() {
+    *t = t.trim_end().to_string();
+  }
+  inlines.retain(|i| !matches!(i, Inline::Text(t) if t.is_empty()));
+  inlines
+}
+
+/// Reads an `<img>`. Its `src` is kept as written; images stored in the
+/// archive are resolved later by [`resolve_images`].
+fn image_from_element(node: &NodeRef) -> Option<Image> {
+  let src = attr(node, "src").filter(|s| !s.trim().is_empty())?;
+  let alt = attr(node, "alt").filter(|a| !a.trim().is_empty());
+  Some(Image { src, alt })
+}
+
+/// Resolves the images of a chapter, including those nested in lists and
+/// tables. External and `data:` images are kept as they are; images stored
+/// in the archive are looked up relative to the chapter's directory.
+fn resolve_images<R: Read + Seek>(
+  blocks: Vec<Block>,
+  chapter_dir: &str,
+  zip: &mut ZipArchive<R>,
+  options: &EpubParseOptions,
+) -> Vec<Block> {
+  blocks
+    .into_iter()
+    .filter_map(|block| match block {
+      Block::Image(image) => image_from_archive(image, chapter_dir, zip, options).map(Block::Image),
+      Block::List(mut list) => {
+        for item in &mut list.items {
+          item.blocks = resolve_images(std::mem::take(&mut item.blocks), chapter_dir, zip, options);
+        }
+        Some(Block::List(list))
+      }
+      Block::Table(mut table) => {
+        for cell in table.rows.iter_mut().flat_map(|row| row.cells.iter_mut()) {
+          cell.blocks = resolve_images(std::mem::take(&mut cell.blocks), chapter_dir, zip, options);
+        }
+        Some(Block::Table(table))
+      }
+      other => Some(other),
+    })
+    .collect()
+}
+
+fn image_from_archive<R: Read + Seek>(
+  image: Image,
+  chapter_dir: &str,
+  zip: &mut ZipArchive<R>,
+  options: &EpubParseOptions,
+) -> Option<Image> {
+  let src = image.src.as_str();
+  if src.starts_with("http://") || src.starts_with("https://") || src.starts_with("data:") {
+    return Some(image);
+  }
+  // archive images are dropped unless the caller asked for data URIs
+  if !options.embed_images {
+    return None;
+  }
+
+  let path = resolve_path(chapter_dir, src);
+  let mime = image_mime_type(&path)?;
+  let mut file = zip.by_name(&path).ok()?;
+  if let Some(max) = options.max_image_size_bytes {
+    if file.size() > max as u64 {
+      return None;
+    }
+  }
+  let mut bytes = Vec::new();
+  file.read_to_end(&mut bytes).ok()?;
+
+  Some(Image {
+    src: format!("data:{mime};base64,{}", BASE64_STANDARD.encode(&bytes)),
+    alt: image.alt,
+  })
+}
+
+fn parse_table(node: &NodeRef) -> Table {
+  let mut rows = Vec::new();
+  collect_table_rows(node, TableRowKind::Body, &mut rows);
+  Table { rows }
+}
+
+fn collect_table_rows(node: &NodeRef, kind: TableRowKind, rows: &mut Vec<TableRow>) {
+  for c in node.children() {
+    match element_name(&c).as_deref() {
+      Some("thead") => collect_table_rows(&c, TableRowKind::Header, rows),
+      Some("tbody") => collect_table_rows(&c, TableRowKind::Body, rows),
+      Some("tfoot") => collect_table_rows(&c, TableRowKind::Footer, rows),
+      Some("tr") => rows.push(parse_table_row(&c, kind)),
+      _ => {}
+    }
+  }
+}
+
+fn parse_table_row(tr: &NodeRef, kind: TableRowKind) -> TableRow {
+  let mut cells = Vec::new();
+  let mut all_header_cells = true;
+  for c in tr.children() {
+    let name = element_name(&c);
+    let is_header = name.as_deref() == Some("th");
+    if !is_header && name.as_deref() != Some("td") {
+      continue;
+    }
+    all_header_cells &= is_header;
+    cells.push(TableCell {
+      blocks: parse_flow(&c),
+      colspan: span_attr(&c, "colspan"),
+      rowspan: span_attr(&c, "rowspan"),
+    });
+  }
+
+  let kind = if kind == TableRowKind::Body && all_header_cells && !cells.is_empty() {
+    TableRowKind::Header
+  } else {
+    kind
+  };
+  TableRow { cells, kind }
+}
+
+fn span_attr(node: &NodeRef, name: &str) -> NonZeroU32 {
+  attr(node, name)
+    .and_then(|v| v.trim().parse::<u32>().ok())
+    .and_then(NonZeroU32::new)
+    .unwrap_or_else(|| NonZeroU32::new(1).unwrap())
+}
+
+fn inlines_have_visible_content(inlines: &[Inline]) -> bool {
+  inlines.iter().any(inline_is_visible)
+}
+
+fn inline_is_visible(i: &Inline) -> bool {
+  match i {
+    Inline::Text(t) => !t.trim().is_empty(),
+    Inline::LineBreak => false,
+    Inline::Link { children, .. } => inlines_have_visible_content(children),
+    Inline::Strong(c) | Inline::Em(c) | Inline::Del(c) | Inline::Sup(c) | Inline::Sub(c) => {
+      inlines_have_visible_content(c)
+    }
+    Inline::Code(c) => !c.trim().is_empty(),
+    Inline::FootnoteRef(_) | Inline::EndnoteRef(_) | Inline::CommentRef(_) => true,
+    Inline::Bookmark(_) => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::io::Write;
+  use zip::write::{SimpleFileOptions, ZipWriter};
+
+  fn build_epub(opf: &str, chapters: &[(&str, &str)]) -> Vec<u8> {
+    build_epub_with_parts(opf, chapters, &[])
+  }
+
+  fn build_epub_with_parts(
+    opf: &str,
+    chapters: &[(&str, &str)],
+    parts: &[(&str, &[u8])],
+  ) -> Vec<u8> {
+    let mut zip = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    zip
+      .start_file("mimetype", SimpleFileOptions::default())
+      .unwrap();
+    zip.write_all(b"application/epub+zip").unwrap();
+    zip
+      .start_file("META-INF/container.xml", SimpleFileOptions::default())
+      .unwrap();
+    zip
+      .write_all(
+        br#"<container xmlns="urn:oasis:names:tc:opendocument:xmlns:container"><rootfiles>
+          <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+        </rootfiles></container>"#,
+      )
+      .unwrap();
+    zip
+      .start_file("OEBPS/content.opf", SimpleFileOptions::default())
+      .unwrap();
+    zip.write_all(opf.as_bytes()).unwrap();
+    for (name, body) in chapters {
+      zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+      write!(
+        zip,
+        r#"<html xmlns="http://www.w3.org/1999/xhtml"><body>{body}</body></html>"#
+      )
+      .unwrap();
+    }
+    for (name, bytes) in parts {
+      zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+      zip.write_all(bytes).unwrap();
+    }
+    zip.finish().unwrap().into_inner()
+  }
+
+  /// A package whose spine is the single chapter `OEBPS/text/chapter.xhtml`.
+  const SINGLE_CHAPTER_OPF: &str = r#"<package xmlns="http://www.idpf.org/2007/opf">
+    <manifest><item id="c" href="text/chapter.xhtml" media-type="application/xhtml+xml"/></manifest>
+    <spine><itemref idref="c"/></spine>
+  </package>"#;
+
+  fn paragraph_texts(doc: &Document) -> Vec<String> {
+    doc
+      .blocks
+      .iter()
+      .map(|b| match b {
+        Block::Paragraph(p) => p
+          .inlines
+          .iter()
+          .map(|i| match i {
+            Inline::Text(t) => t.as_str(),
+            _ => "",
+          })
+          .collect(),
+        other => panic!("expected a paragraph, got {other:?}"),
+      })
+      .collect()
+  }
+
+  #[test]
+  fn chapters_follow_spine_order() {
+    let opf = r#"<package xmlns="http://www.idpf.org/2007/opf" xmlns:dc="http://purl.org/dc/elements/1.1/">
+      <metadata><dc:title>Book</dc:title><dc:creator>Ann</dc:creator><dc:date>2020-05</dc:date></metadata>
+      <manifest>
+        <item id="c1" href="text/one.xhtml" media-type="application/xhtml+xml"/>
+        <item id="c2" href="text/chapter%20two.xhtml" media-type="application/xhtml+xml"/>
+        <item id="cover" href="cover.xhtml" media-type="application/xhtml+xml"/>
+      </manifest>
+      <spine>
+        <itemref idref="cover" linear="no"/>
+        <itemref idref="c2"/>
+        <itemref idref="c1"/>
+      </spine>
+    </package>"#;
+    let data = build_epub(
+      opf,
+      &[
+        ("OEBPS/text/one.xhtml", "<p>One</p>"),
+        ("OEBPS/text/chapter two.xhtml", "<p>Two</p>"),
+        ("OEBPS/cover.xhtml", "<p>Cover</p>"),
+      ],
+    );
+
+    let doc = EpubProvider::new().parse_buffer(&data).unwrap();
+    assert_eq!(paragraph_texts(&doc), vec!["Two", "One"]);
+    assert_eq!(doc.metadata.title.as_deref(), Some("Book"));
+    assert_eq!(doc.metadata.author.as_deref(), Some("Ann"));
+    assert_eq!(
+      doc.metadata.created.map(|d| d.to_rfc3339()),
+      Some("2020-05-01T00:00:00+00:00".to_string())
+    );
+  }
+
+  #[test]
+  fn resolve_path_is_relative_to_the_opf() {
+    assert_eq!(
+      resolve_path("OEBPS", "text/one.xhtml"),
+      "OEBPS/text/one.xhtml"
+    );
+    assert_eq!(resolve_path("", "./one.xhtml#start"), "one.xhtml");
+    assert_eq!(
+      resolve_path("OEBPS/text", "../images/cover.png"),
+      "OEBPS/images/cover.png"
+    );
+    assert_eq!(
+      resolve_path("OEBPS", "chapter%20two.xhtml"),
+      "OEBPS/chapter two.xhtml"
+    );
+  }
+
+  #[test]
+  fn percent_decode_handles_utf8_and_malformed_escapes() {
+    assert_eq!(percent_decode("plain.xhtml"), "plain.xhtml");
+    assert_eq!(percent_decode("a%20b"), "a b");
+    assert_eq!(percent_decode("caf%C3%A9"), "café");
+    assert_eq!(percent_decode("100%zz"), "100%zz");
+    assert_eq!(percent_decode("end%2"), "end%2");
+  }
+
+  #[test]
+  fn maps_xhtml_elements_to_blocks() {
+    let body = r#"<h1>One</h1><h2>Two</h2><h3>Three</h3><h4>Four</h4><h5>Five</h5><h6>Six</h6>
+      <blockquote><p>Quoted</p></blockquote>
+      <ul><li>Bullet</li></ul>
+      <ol><li>First</li><li>Second</li></ol>
+      <table><thead><tr><th>Name</th></tr></thead><tbody><tr><td>Ann</td></tr></tbody></table>
+      <p><img src="https://example.com/cover.png" alt="Cover"/></p>"#;
+    let data = build_epub(SINGLE_CHAPTER_OPF, &[("OEBPS/text/chapter.xhtml", body)]);
+
+    let doc = EpubProvider::new().parse_buffer(&data).unwrap();
+    let kinds: Vec<_> = doc
+      .blocks
+      .iter()
+      .filter_map(|b| match b {
+        Block::Paragraph(p) => Some(p.kind),
+        _ => None,
+      })
+      .collect();
+    assert_eq!(
+      kinds,
+      vec![
+        ParagraphKind::Heading(1),
+        ParagraphKind::Heading(2),
+        ParagraphKind::Heading(3),
+        ParagraphKind::Heading(4),
+        ParagraphKind::Heading(5),
+        ParagraphKind::Heading(6),
+        ParagraphKind::Blockquote,
+      ]
+    );
+
+    let lists: Vec<_> = doc
+      .blocks
+      .iter()
+      .filter_map(|b| match b {
+        Block::List(l) => Some((l.list_type, l.items.len())),
+        _ => None,
+      })
+      .collect();
+    assert_eq!(
+      lists,
+      vec![(ListType::Unordered, 1), (ListType::Ordered, 2)]
+    );
+
+    let Some(Block::Table(table)) = doc.blocks.iter().find(|b| matches!(b, Block::Table(_))) else {
+      panic!("expected a table, got {:?}", doc.blocks);
+    };
+    let row_kinds: Vec<_> = table.rows.iter().map(|r| r.kind).collect();
+    assert_eq!(row_kinds, vec![TableRowKind::Header, TableRowKind::Body]);
+
+    assert_eq!(
+      doc.blocks.last(),
+      Some(&Block::Image(Image {
+        src: "https://example.com/cover.png".to_string(),
+        alt: Some("Cover".to_string()),
+      }))
+    );
+  }
+
+  #[test]
+  fn archive_images_are_resolved_against_the_chapter() {
+    let body = r#"<p><img src="../images/pic%201.png" alt="Pic"/></p>
+      <ul><li><img src="../images/pic%201.png"/></li></ul>"#;
+    let png = b"\x89PNG\r\n\x1a\n";
+    let data = build_epub_with_parts(
+      SINGLE_CHAPTER_OPF,
+      &[("OEBPS/text/chapter.xhtml", body)],
+      &[("OEBPS/images/pic 1.png", png)],
+    );
+
+    let doc = EpubProvider::new().parse_buffer(&data).unwrap();
+    assert!(!doc.blocks.iter().any(|b| matches!(b, Block::Image(_))));
+
+    let doc = EpubProvider::with_options(EpubParseOptions {
+      embed_images: true,
+      ..Default::default()
+    })
+    .parse_buffer(&data)
+    .unwrap();
+    let Some(Block::Image(image)) = doc.blocks.first() else {
+      panic!("expected an image, got {:?}", doc.blocks);
+    };
+    assert_eq!(image.alt.as_deref(), Some("Pic"));
+    assert_eq!(
+      BASE64_STANDARD
+        .decode(image.src.trim_start_matches("data:image/png;base64,"))
+        .unwrap(),
+      png
+    );
+    let Some(Block::List(list)) = doc.blocks.get(1) else {
+      panic!("expected a list, got {:?}", doc.blocks);
+    };
+    assert!(matches!(
+      list.items[0].blocks.as_slice(),
+      [Block::Image(Image { src, .. })] if src.starts_with("data:image/png;base64,")
+    ));
+
+    let doc = EpubProvider::with_options(EpubParseOptions {
+      embed_images: true,
+      max_image_size_bytes: Some(png.len() as u32 - 1),
+    })
+    .parse_buffer(&data)
+    .unwrap();
+    assert!(!doc.blocks.iter().any(|b| matches!(b, Block::Image(_))));
+  }
+}
@@ -1,10 +1,15 @@
 use super::doc::DocProvider;
-use super::docx::DocxProvider;
-use super::odt::OdtProvider;
+use super::docx::{DocxParseOptions, DocxProvider, TrackedChangeMode};
+use super::epub::{EpubParseOptions, EpubProvider};
+use super::odt::{OdtParseOptions, OdtProvider};
+use super::pptx::PptxProvider;
 use super::rtf::RtfProvider;
+use super::xlsx::{XlsxParseOptions, XlsxProvider};
 use super::DocumentProvider;
-use super::xlsx::XlsxProvider;
+use cfb::CompoundFile;
 use napi_derive::napi;
+use std::io::{Cursor, Read};
+use zip::ZipArchive;
 
 #[napi]
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -14,6 +19,33 @@ pub enum DocumentType {
   Rtf,
   Odt,
   Xlsx,
+  Pptx,
+  Epub,
+}
+
+/// Parse options forwarded to the providers that support them. Formats
+/// without options of their own ignore these.
+#[derive(Debug, Clone)]
+pub struct ProviderOptions {
+  /// DOCX only.
+  pub tracked_changes: TrackedChangeMode,
+  /// DOCX, ODT and EPUB only.
+  pub embed_images: bool,
+  /// DOCX, ODT and EPUB only.
+  pub max_image_size_bytes: Option<u32>,
+  /// XLSX only.
+  pub only_sheets: Option<Vec<String>>,
+}
+
+impl Default for ProviderOptions {
+  fn default() -> Self {
+    Self {
+      tracked_changes: TrackedChangeMode::Accept,
+      embed_images: false,
+      max_image_size_bytes: None,
+      only_sheets: None,
+    }
+  }
 }
 
 pub struct ProviderFactory {
@@ -22,6 +54,8 @@ pub struct ProviderFactory {
   rtf_provider: RtfProvider,
   odt_provider: OdtProvider,
   xlsx_provider: XlsxProvider,
+  pptx_provider: PptxProvider,
+  epub_provider: EpubProvider,
 }
 
 impl ProviderFactory {
@@ -32,16 +66,167 @@ impl ProviderFactory {
       rtf_provider: RtfProvider::new(),
       odt_provider: OdtProvider::new(),
       xlsx_provider: XlsxProvider::new(),
+      pptx_provider: PptxProvider::new(),
+      epub_provider: EpubProvider::new(),
     }
   }
 
-  pub fn get_provider(&self, doc_type: DocumentType) -> &dyn DocumentProvider {
-    match doc_type {
-      DocumentType::Doc => &self.doc_provider,
-      DocumentType::Docx => &self.docx_provider,
-      DocumentType::Rtf => &self.rtf_provider,
-      DocumentType::Odt => &self.odt_provider,
-      DocumentType::Xlsx => &self.xlsx_provider,
+  /// Returns the shared provider for `doc_type`, or a freshly configured one
+  /// when `options` are given for a format that supports them.
+  pub fn get_provider(
+    &self,
+    doc_type: DocumentType,
+    options: Option<&ProviderOptions>,
+  ) -> Box<dyn DocumentProvider + '_> {
+    match (doc_type, options) {
+      (DocumentType::Docx, Some(options)) => {
+        Box::new(DocxProvider::with_options(DocxParseOptions {
+          tracked_changes: options.tracked_changes,
+          embed_images: options.embed_images,
+          max_image_size_bytes: options.max_image_size_bytes,
+        }))
+      }
+      (DocumentType::Odt, Some(options)) => Box::new(OdtProvider::with_options(OdtParseOptions {
+        embed_images: options.embed_images,
+        max_image_size_bytes: options.max_image_size_bytes,
+      })),
+      (DocumentType::Epub, Some(options)) => {
+        Box::new(EpubProvider::with_options(EpubParseOptions {
+          embed_images: options.embed_images,
+          max_image_size_bytes: options.max_image_size_bytes,
+        }))
+      }
+      (DocumentType::Xlsx, Some(options)) => {
+        Box::new(XlsxProvider::with_options(XlsxParseOptions {
+          only_sheets: options.only_sheets.clone(),
+        }))
+      }
+      (DocumentType::Doc, _) => Box::new(&self.doc_provider),
+      (DocumentType::Docx, None) => Box::new(&self.docx_provider),
+      (DocumentType::Rtf, _) => Box::new(&self.rtf_provider),
+      (DocumentType::Odt, None) => Box::new(&self.odt_provider),
+      (DocumentType::Xlsx, None) => Box::new(&self.xlsx_provider),
+      (DocumentType::Pptx, _) => Box::new(&self.pptx_provider),
+      (DocumentType::Epub, None) => Box::new(&self.epub_provider),
     }
   }
+
+  /// Guesses the document type from the buffer's magic bytes, looking inside
+  /// ZIP and OLE2 containers to tell their formats apart. PDFs and unknown
+  /// formats return `None`, as no provider handles them.
+  pub fn detect_type_from_bytes(data: &[u8]) -> Option<DocumentType> {
+    if data.starts_with(b"PK\x03\x04") {
+      detect_zip_type(data)
+    } else if data.starts_with(b"{\\rtf") {
+      Some(DocumentType::Rtf)
+    } else if data.starts_with(b"\xD0\xCF\x11\xE0") {
+      detect_ole_type(data)
+    } else {
+      None
+    }
+  }
+}
+
+fn detect_zip_type(data: &[u8]) -> Option<DocumentType> {
+  let mut zip = ZipArchive::new(Cursor::new(data)).ok()?;
+
+  // ODF and EPUB store their media type in an uncompressed `mimetype` entry
+  if let Ok(mut file) = zip.by_name("mimetype") {
+    let mut mimetype = String::new();
+    if file.read_to_string(&mut mimetype).is_ok() {
+      match mimetype.trim() {
+        "application/epub+zip" => return Some(DocumentType::Epub),
+        "application/vnd.oasis.opendocument.text" => return Some(DocumentType::Odt),
+        _ => {}
+      }
+    }
+  }
+
+  if zip.index_for_name("word/document.xml").is_some() {
+    Some(DocumentType::Docx)
+  } else if zip.index_for_name("xl/workbook.xml").is_some() {
+    Some(DocumentType::Xlsx)
+  } else if zip.index_for_name("ppt/presentation.xml").is_some() {
+    Some(DocumentType::Pptx)
+  } else if zip.index_for_name("META-INF/container.xml").is_some() {
+    Some(DocumentType::Epub)
+  } else {
+    None
+  }
+}
+
+fn detect_ole_type(data: &[u8]) -> Option<DocumentType> {
+  let cfb = CompoundFile::open(Cursor::new(data)).ok()?;
+  if cfb.is_stream("/WordDocument") {
+    Some(DocumentType::Doc)
+  } else if cfb.is_stream("/Workbook") || cfb.is_stream("/Book") {
+    // Legacy .xls workbooks are read by the same calamine-backed provider
+    Some(DocumentType::Xlsx)
+  } else {
+    None
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::io::Write;
+  use zip::write::{SimpleFileOptions, ZipWriter};
+
+  fn build_zip(entries: &[(&str, &str)]) -> Vec<u8> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, contents) in entries {
+      zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+      zip.write_all(contents.as_bytes()).unwrap();
+    }
+    zip.finish().unwrap().into_inner()
+  }
+
+  #[test]
+  fn detects_zip_based_formats() {
+    let cases = [
+      (vec![("word/document.xml", "")], DocumentType::Docx),
+      (vec![("xl/workbook.xml", "")], DocumentType::Xlsx),
+      (vec![("ppt/presentation.xml", "")], DocumentType::Pptx),
+      (
+        vec![("mimetype", "application/vnd.oasis.opendocument.text")],
+        DocumentType::Odt,
+      ),
+      (
+        vec![("mimetype", "application/epub+zip")],
+        DocumentType::Epub,
+      ),
+      (vec![("META-INF/container.xml", "")], DocumentType::Epub),
+    ];
+
+    for (entries, expected) in cases {
+      assert_eq!(
+        ProviderFactory::detect_type_from_bytes(&build_zip(&entries)),
+        Some(expected)
+      );
+    }
+    assert_eq!(
+      ProviderFactory::detect_type_from_bytes(&build_zip(&[("readme.txt", "")])),
+      None
+    );
+  }
+
+  #[test]
+  fn detects_rtf_and_ole_formats() {
+    assert_eq!(
+      ProviderFactory::detect_type_from_bytes(br"{\rtf1\ansi Hello}"),
+      Some(DocumentType::Rtf)
+    );
+
+    let mut ole = CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+    ole.create_stream("/WordDocument").unwrap();
+    let ole = ole.into_inner().into_inner();
+    assert_eq!(
+      ProviderFactory::detect_type_from_bytes(&ole),
+      Some(DocumentType::Doc)
+    );
+
+    assert_eq!(ProviderFactory::detect_type_from_bytes(b"%PDF-1.7"), None);
+    assert_eq!(ProviderFactory::detect_type_from_bytes(b""), None);
+  }
 }
@@ -3,8 +3,10 @@ use std::error::Error;
 
 pub mod doc;
 pub mod docx;
+pub mod epub;
 pub mod factory;
 pub mod odt;
+pub mod pptx;
 pub mod rtf;
 pub mod xlsx;
 
@@ -14,3 +16,13 @@ pub trait DocumentProvider {
   #[allow(dead_code)]
   fn name(&self) -> &'static str;
 }
+
+impl<P: DocumentProvider + ?Sized> DocumentProvider for &P {
+  fn parse_buffer(&self, data: &[u8]) -> Result<Document, Box<dyn Error + Send + Sync>> {
+    (**self).parse_buffer(data)
+  }
+
+  fn name(&self) -> &'static str {
+    (**self).name()
+  }
+}
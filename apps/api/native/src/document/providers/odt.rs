@@ -1,5 +1,7 @@
 use crate::document::model::*;
+use crate::document::providers::docx::image_mime_type;
 use crate::document::providers::DocumentProvider;
+use base64::prelude::*;
 use chrono::{DateTime, Utc};
 use roxmltree::{Document as XmlDoc, Node};
 use std::collections::HashMap;
@@ -8,11 +10,25 @@ use std::io::{Read, Seek};
 use std::num::NonZeroU32;
 use zip::read::ZipArchive;
 
-pub struct OdtProvider;
+#[derive(Debug, Clone, Default)]
+pub struct OdtParseOptions {
+  /// Inline images stored in `Pictures/` as base64 `data:` URIs.
+  pub embed_images: bool,
+  /// Embedded images larger than this are dropped instead of inlined.
+  pub max_image_size_bytes: Option<u32>,
+}
+
+pub struct OdtProvider {
+  options: OdtParseOptions,
+}
 
 impl OdtProvider {
   pub fn new() -> Self {
-    Self
+    Self::with_options(OdtParseOptions::default())
+  }
+
+  pub fn with_options(options: OdtParseOptions) -> Self {
+    Self { options }
   }
 }
 
@@ -37,7 +53,14 @@ impl DocumentProvider for OdtProvider {
       .find(|n| is_tag(n, "text") && n.ancestors().any(|a| is_tag(&a, "body")));
 
     if let Some(text_node) = body_text {
-      blocks = parse_block_children_odt(&text_node, &styles, &mut notes, &mut comments, &mut zip);
+      blocks = parse_block_children_odt(
+        &text_node,
+        &styles,
+        &mut notes,
+        &mut comments,
+        &mut zip,
+        &self.options,
+      );
     }
 
     Ok(Document {
@@ -285,6 +308,7 @@ fn parse_block_children_odt<R: Read + Seek>(
   notes: &mut Vec<Note>,
   comments: &mut Vec<Comment>,
   zip: &mut ZipArchive<R>,
+  options: &OdtParseOptions,
 ) -> Vec<Block> {
   let mut blocks: Vec<Block> = Vec::new();
 
@@ -296,7 +320,7 @@ fn parse_block_children_odt<R: Read + Seek>(
         }
       }
     } else if is_tag(&child_n, "p") {
-      if let Some(img) = image_from_paragraph(&child_n, zip) {
+      if let Some(img) = image_from_paragraph(&child_n, zip, options) {
         blocks.push(Block::Image(img));
       } else if let Some(p) = parse_paragraph(&child_n, styles, notes, comments) {
         if paragraph_has_visible_content(&p) {
@@ -332,6 +356,7 @@ fn parse_block_children_odt<R: Read + Seek>(
         notes,
         comments,
         zip,
+        options,
         inherited_style_name,
       ) {
         if unwrapped {
@@ -345,11 +370,11 @@ fn parse_block_children_odt<R: Read + Seek>(
         blocks.push(Block::List(l));
       }
     } else if is_tag(&child_n, "table") {
-      if let Some(t) = parse_table(&child_n, styles, notes, comments, zip) {
+      if let Some(t) = parse_table(&child_n, styles, notes, comments, zip, options) {
         blocks.push(Block::Table(t));
       }
     } else {
-      let mut inner = parse_block_children_odt(&child_n, styles, notes, comments, zip);
+      let mut inner = parse_block_children_odt(&child_n, styles, notes, comments, zip, options);
       blocks.append(&mut inner);
     }
   }
@@ -391,7 +416,11 @@ fn parse_paragraph(
   let kind = paragraph_kind(node, styles);
   let base = paragraph_text_props(node, styles);
   let inlines = parse_inlines_with_base(node, styles, notes, comments, base);
-  Some(Paragraph { kind, inlines })
+  Some(Paragraph {
+    kind,
+    inlines,
+    attrs: Default::default(),
+  })
 }
 
 fn paragraph_kind(p: &Node, styles: &OdtStylesInfo) -> ParagraphKind {
@@ -543,6 +572,14 @@ fn parse_inlines(
         }
       }
 
+      let date = c
+        .children()
+        .find(|n| is_tag(n, "date"))
+        .and_then(|n| n.text())
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .map(str::to_string);
+
       let mut cblocks: Vec<Block> = Vec::new();
       for p in c.children().filter(|n| is_tag(n, "p")) {
         let inl = parse_inlines(&p, styles, notes, comments);
@@ -550,6 +587,7 @@ fn parse_inlines(
           cblocks.push(Block::Paragraph(Paragraph {
             kind: ParagraphKind::Normal,
             inlines: inl,
+            attrs: Default::default(),
           }));
         }
       }
@@ -557,6 +595,7 @@ fn parse_inlines(
         id: CommentId(cid.clone()),
         author_name: author,
         author_initials: initials,
+        date,
         blocks: cblocks,
       });
       out.push(Inline::CommentRef(CommentId(cid)));
@@ -652,7 +691,11 @@ fn parse_note_body_blocks(
     let base = paragraph_text_props(&p, styles);
     let inl = parse_inlines_with_base(&p, styles, notes, comments, base);
     if inlines_have_visible_content(&inl) {
-      blocks.push(Block::Paragraph(Paragraph { kind, inlines: inl }));
+      blocks.push(Block::Paragraph(Paragraph {
+        kind,
+        inlines: inl,
+        attrs: Default::default(),
+      }));
     }
   }
   blocks
@@ -686,6 +729,7 @@ fn parse_list_with_inherit<R: Read + Seek>(
   notes: &mut Vec<Note>,
   comments: &mut Vec<Comment>,
   zip: &mut ZipArchive<R>,
+  options: &OdtParseOptions,
   inherit_style_name: Option<&str>,
 ) -> Option<List> {
   let style_name = get_attr_local(node, "style-name").or(inherit_style_name);
@@ -701,7 +745,7 @@ fn parse_list_with_inherit<R: Read + Seek>(
   let mut items: Vec<ListItem> = Vec::new();
   for it in children(node, "list-item") {
     let mut blocks = Vec::new();
-    let mut inner = parse_block_children_odt(&it, styles, notes, comments, zip);
+    let mut inner = parse_block_children_odt(&it, styles, notes, comments, zip, options);
     blocks.append(&mut inner);
     items.push(ListItem { blocks });
   }
@@ -714,12 +758,13 @@ fn parse_table<R: Read + Seek>(
   notes: &mut Vec<Note>,
   comments: &mut Vec<Comment>,
   zip: &mut ZipArchive<R>,
+  options: &OdtParseOptions,
 ) -> Option<Table> {
   let mut rows: Vec<TableRow> = Vec::new();
-  for tr in children(node, "table-row") {
+  for (tr, kind) in table_rows(node, TableRowKind::Body) {
     let mut cells: Vec<TableCell> = Vec::new();
     for tc in children(&tr, "table-cell") {
-      let mut blocks = parse_block_children_odt(&tc, styles, notes, comments, zip);
+      let mut blocks = parse_block_children_odt(&tc, styles, notes, comments, zip, options);
       let colspan = get_attr_local(&tc, "number-columns-spanned")
         .and_then(|v| v.parse::<u32>().ok())
         .and_then(NonZeroU32::new)
@@ -734,31 +779,201 @@ fn parse_table<R: Read + Seek>(
         rowspan,
       });
     }
-    rows.push(TableRow {
-      cells,
-      kind: TableRowKind::Body,
-    });
+    rows.push(TableRow { cells, kind });
   }
   Some(Table { rows })
 }
 
-fn image_from_paragraph<R: Read + Seek>(p: &Node, zip: &mut ZipArchive<R>) -> Option<Image> {
+/// Collects the rows of a table in document order, along with the row kind
+/// implied by the `table-header-rows` / `table-footer-rows` wrapper they sit in.
+fn table_rows<'a>(node: &Node<'a, 'a>, kind: TableRowKind) -> Vec<(Node<'a, 'a>, TableRowKind)> {
+  let mut rows = Vec::new();
+  for child in node.children().filter(|n| n.is_element()) {
+    match child.tag_name().name() {
+      "table-row" => rows.push((child, kind)),
+      "table-header-rows" => rows.extend(table_rows(&child, TableRowKind::Header)),
+      "table-footer-rows" => rows.extend(table_rows(&child, TableRowKind::Footer)),
+      "table-rows" | "table-row-group" => rows.extend(table_rows(&child, kind)),
+      _ => {}
+    }
+  }
+  rows
+}
+
+fn image_from_paragraph<R: Read + Seek>(
+  p: &Node,
+  zip: &mut ZipArchive<R>,
+  options: &OdtParseOptions,
+) -> Option<Image> {
   let img = p.descendants().find(|n| is_tag(n, "image"))?;
   let href = get_attr_local(&img, "href")?;
-  image_from_href(href, zip, None)
+  image_from_href(href, zip, options, None)
 }
 
 fn image_from_href<R: Read + Seek>(
   href: &str,
-  _zip: &mut ZipArchive<R>,
+  zip: &mut ZipArchive<R>,
+  options: &OdtParseOptions,
   alt: Option<String>,
 ) -> Option<Image> {
-  // only include external images (http/https URLs)
   if href.starts_with("http://") || href.starts_with("https://") {
     return Some(Image {
       src: href.to_string(),
       alt,
     });
   }
-  None
+  // embedded images are dropped unless the caller asked for data URIs
+  if !options.embed_images {
+    return None;
+  }
+
+  let path = href.trim_start_matches("./");
+  let mime = image_mime_type(path)?;
+  let mut file = zip.by_name(path).ok()?;
+  if let Some(max) = options.max_image_size_bytes {
+    if file.size() > max as u64 {
+      return None;
+    }
+  }
+  let mut bytes = Vec::new();
+  file.read_to_end(&mut bytes).ok()?;
+
+  Some(Image {
+    src: format!("data:{mime};base64,{}", BASE64_STANDARD.encode(&bytes)),
+    alt,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::io::Write;
+  use zip::write::{SimpleFileOptions, ZipWriter};
+
+  fn build_odt_with_parts(body: &str, parts: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut zip = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for (name, bytes) in parts {
+      zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+      zip.write_all(bytes).unwrap();
+    }
+    zip
+      .start_file("content.xml", SimpleFileOptions::default())
+      .unwrap();
+    write!(
+      zip,
+      r#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0"
+  xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0"
+  xmlns:draw="urn:oasis:names:tc:opendocument:xmlns:drawing:1.0"
+  xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0"
+  xmlns:xlink="http://www.w3.org/1999/xlink"
+  xmlns:dc="http://purl.org/dc/elements/1.1/">
+<office:body><office:text>{body}</office:text></office:body>
+</office:document-content>"#
+    )
+    .unwrap();
+    zip.finish().unwrap().into_inner()
+  }
+
+  #[test]
+  fn embedded_images_become_data_uris_when_requested() {
+    let body = r#"<text:p><draw:frame draw:name="Image1">
+      <draw:image xlink:href="Pictures/image1.png" xlink:type="simple"/>
+    </draw:frame></text:p>"#;
+    let png = b"\x89PNG\r\n\x1a\n";
+    let data = build_odt_with_parts(body, &[("Pictures/image1.png", png)]);
+
+    let doc = OdtProvider::new().parse_buffer(&data).unwrap();
+    assert!(!doc.blocks.iter().any(|b| matches!(b, Block::Image(_))));
+
+    let doc = OdtProvider::with_options(OdtParseOptions {
+      embed_images: true,
+      ..Default::default()
+    })
+    .parse_buffer(&data)
+    .unwrap();
+    let Some(Block::Image(image)) = doc.blocks.first() else {
+      panic!("expected an image, got {:?}", doc.blocks);
+    };
+    assert!(image.src.starts_with("data:image/png;base64,"));
+    assert_eq!(
+      BASE64_STANDARD
+        .decode(image.src.trim_start_matches("data:image/png;base64,"))
+        .unwrap(),
+      png
+    );
+
+    let doc = OdtProvider::with_options(OdtParseOptions {
+      embed_images: true,
+      max_image_size_bytes: Some(png.len() as u32 - 1),
+    })
+    .parse_buffer(&data)
+    .unwrap();
+    assert!(!doc.blocks.iter().any(|b| matches!(b, Block::Image(_))));
+  }
+
+  #[test]
+  fn annotations_keep_their_date() {
+    let body = r#"<text:p>Reviewed<office:annotation>
+      <dc:creator>Ada</dc:creator>
+      <dc:date>2024-03-01T09:30:00.123</dc:date>
+      <text:p>Looks good</text:p>
+    </office:annotation></text:p>"#;
+    let data = build_odt_with_parts(body, &[]);
+
+    let doc = OdtProvider::new().parse_buffer(&data).unwrap();
+    assert_eq!(doc.comments.len(), 1);
+    assert_eq!(doc.comments[0].author_name.as_deref(), Some("Ada"));
+    assert_eq!(
+      doc.comments[0].date.as_deref(),
+      Some("2024-03-01T09:30:00.123")
+    );
+  }
+
+  #[test]
+  fn header_and_footer_rows_keep_their_kind() {
+    let body = r#"<table:table table:name="Totals">
+      <table:table-column table:number-columns-repeated="2"/>
+      <table:table-header-rows>
+        <table:table-row>
+          <table:table-cell><text:p>Item</text:p></table:table-cell>
+          <table:table-cell><text:p>Cost</text:p></table:table-cell>
+        </table:table-row>
+      </table:table-header-rows>
+      <table:table-row>
+        <table:table-cell><text:p>Tea</text:p></table:table-cell>
+        <table:table-cell><text:p>3</text:p></table:table-cell>
+      </table:table-row>
+      <table:table-footer-rows>
+        <table:table-row>
+          <table:table-cell><text:p>Total</text:p></table:table-cell>
+          <table:table-cell><text:p>3</text:p></table:table-cell>
+        </table:table-row>
+      </table:table-footer-rows>
+    </table:table>"#;
+    let data = build_odt_with_parts(body, &[]);
+
+    let doc = OdtProvider::new().parse_buffer(&data).unwrap();
+    let Some(Block::Table(table)) = doc.blocks.first() else {
+      panic!("expected a table, got {:?}", doc.blocks);
+    };
+    let kinds: Vec<TableRowKind> = table.rows.iter().map(|r| r.kind).collect();
+    assert_eq!(
+      kinds,
+      vec![
+        TableRowKind::Header,
+        TableRowKind::Body,
+        TableRowKind::Footer
+      ]
+    );
+
+    let html = crate::document::renderers::html::HtmlRenderer::new().render(&doc);
+    let thead = html.find("<thead>").expect("missing <thead>");
+    let tbody = html.find("<tbody>").expect("missing <tbody>");
+    let tfoot = html.find("<tfoot>").expect("missing <tfoot>");
+    assert!(thead < tbody && tbody < tfoot);
+    assert!(html[thead..tbody].contains("Item"));
+    assert!(html[tbody..tfoot].contains("Tea"));
+    assert!(html[tfoot..].contains("Total"));
+  }
 }
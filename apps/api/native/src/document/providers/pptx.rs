@@ -0,0 +1,620 @@
+use crate::document::model::*;
+use crate::document::providers::DocumentProvider;
+use chrono::{DateTime, Utc};
+use roxmltree::{Document as XmlDoc, Node};
+use std::collections::HashMap;
+use std::error::Error;
+use std::io::{Read, Seek};
+use std::num::NonZeroU32;
+use zip::read::ZipArchive;
+
+pub struct PptxProvider;
+
+impl PptxProvider {
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl DocumentProvider for PptxProvider {
+  fn parse_buffer(&self, data: &[u8]) -> Result<Document, Box<dyn Error + Send + Sync>> {
+    let cursor = std::io::Cursor::new(data);
+    let mut zip = ZipArchive::new(cursor)?;
+
+    let slide_paths = slide_paths_in_order(&mut zip);
+    if slide_paths.is_empty() {
+      return Err("Missing ppt/slides/*.xml in presentation".into());
+    }
+
+    let metadata = read_core_properties(&mut zip).unwrap_or_default();
+
+    let mut blocks = Vec::new();
+    for path in &slide_paths {
+      let Some(slide_xml) = read_zip_text(&mut zip, path) else {
+        continue;
+      };
+      let Ok(xml) = XmlDoc::parse(strip_bom(&slide_xml)) else {
+        continue;
+      };
+      let rels = read_relationships(&mut zip, &slide_rels_path(path));
+
+      if let Some(tree) = xml.descendants().find(|n| is_tag(n, "spTree")) {
+        parse_shape_tree(&tree, &rels, &mut blocks);
+      }
+    }
+
+    Ok(Document {
+      blocks,
+      metadata,
+      notes: Vec::new(),
+      comments: Vec::new(),
+    })
+  }
+
+  fn name(&self) -> &'static str {
+    "pptx"
+  }
+}
+
+fn read_zip_text<R: Read + Seek>(zip: &mut ZipArchive<R>, path: &str) -> Option<String> {
+  let mut file = zip.by_name(path).ok()?;
+  let mut s = String::new();
+  file.read_to_string(&mut s).ok()?;
+  Some(s)
+}
+
+fn strip_bom(s: &str) -> &str {
+  const BOM: char = '\u{FEFF}';
+  s.strip_prefix(BOM).unwrap_or(s)
+}
+
+#[derive(Debug, Clone, Default)]
+struct Relationships {
+  targets: HashMap<String, String>,
+}
+
+impl Relationships {
+  fn get(&self, id: &str) -> Option<&str> {
+    self.targets.get(id).map(|s| s.as_str())
+  }
+}
+
+fn read_relationships<R: Read + Seek>(zip: &mut ZipArchive<R>, path: &str) -> Relationships {
+  let xml_text = match read_zip_text(zip, path) {
+    Some(s) => s,
+    None => return Relationships::default(),
+  };
+  let xml = match XmlDoc::parse(strip_bom(&xml_text)) {
+    Ok(d) => d,
+    Err(_) => return Relationships::default(),
+  };
+  let mut map = HashMap::new();
+  for rel in xml.descendants().filter(|n| is_tag(n, "Relationship")) {
+    if let (Some(id), Some(target)) = (get_attr_local(&rel, "Id"), get_attr_local(&rel, "Target")) {
+      map.insert(id.to_string(), target.to_string());
+    }
+  }
+  Relationships { targets: map }
+}
+
+fn slide_rels_path(slide_path: &str) -> String {
+  match slide_path.rsplit_once('/') {
+    Some((dir, file)) => format!("{dir}/_rels/{file}.rels"),
+    None => format!("_rels/{slide_path}.rels"),
+  }
+}
+
+/// Slides in presentation order (`p:sldIdLst`), falling back to the numeric
+/// order of `ppt/slides/slideN.xml` when `presentation.xml` can't be used.
+fn slide_paths_in_order<R: Read + Seek>(zip: &mut ZipArchive<R>) -> Vec<String> {
+  let rels = read_relationships(zip, "ppt/_rels/presentation.xml.rels");
+  let mut ordered = Vec::new();
+
+  if let Some(text) = read_zip_text(zip, "ppt/presentation.xml") {
+    if let Ok(xml) = XmlDoc::parse(strip_bom(&text)) {
+      for sld in xml.descendants().filter(|n| is_tag(n, "sldId")) {
+        // `p:sldId` carries both a numeric `id` and the relationship `r:id`.
+        let Some(target) = sld
+          .attributes()
+          .find(|a| a.name() == "id" && a.namespace().is_some())
+          .and_then(|a| rels.get(a.value()))
+        else {
+          continue;
+        };
+        let path = match target.strip_prefix('/') {
+          Some(absolute) => absolute.to_string(),
+          None => format!("ppt/{target}"),
+        };
+        if zip.by_name(&path).is_ok() {
+          ordered.push(path);
+        }
+      }
+    }
+  }
+
+  if !ordered.is_empty() {
+    return ordered;
+  }
+
+  let mut numbered: Vec<(u32, String)> = zip
+    .file_names()
+    .filter_map(|name| {
+      let n = name
+        .strip_prefix("ppt/slides/slide")?
+        .strip_suffix(".xml")?
+        .parse::<u32>()
+        .ok()?;
+      Some((n, name.to_string()))
+    })
+    .collect();
+  numbered.sort_by_key(|(n, _)| *n);
+  numbered.into_iter().map(|(_, name)| name).collect()
+}
+
+fn read_core_properties<R: Read + Seek>(zip: &mut ZipArchive<R>) -> Option<DocumentMetadata> {
+  let text = read_zip_text(zip, "docProps/core.xml")?;
+  let xml = XmlDoc::parse(strip_bom(&text)).ok()?;
+  let mut meta = DocumentMetadata::default();
+
+  if let Some(title) = xml
+    .descendants()
+    .find(|n| is_tag(n, "title"))
+    .and_then(|n| n.text())
+  {
+    let trimmed = title.trim();
+    if !trimmed.is_empty() {
+      meta.title = Some(trimmed.to_string());
+    }
+  }
+  if let Some(author) = xml
+    .descendants()
+    .find(|n| is_tag(n, "creator"))
+    .and_then(|n| n.text())
+  {
+    let trimmed = author.trim();
+    if !trimmed.is_empty() && !trimmed.eq_ignore_ascii_case("unknown") {
+      meta.author = Some(trimmed.to_string());
+    }
+  }
+  if let Some(created) = xml
+    .descendants()
+    .find(|n| is_tag(n, "created"))
+    .and_then(|n| n.text())
+  {
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(created) {
+      meta.created = Some(DateTime::<Utc>::from(dt));
+    }
+  }
+
+  Some(meta)
+}
+
+fn is_tag(node: &Node, local: &str) -> bool {
+  node.is_element() && node.tag_name().name() == local
+}
+
+fn get_attr_local<'a>(node: &Node<'a, 'a>, local: &str) -> Option<&'a str> {
+  node
+    .attributes()
+    .find(|a| {
+      let name = a.name();
+      match name.rsplit_once(':') {
+        Some((_, l)) => l == local,
+        None => name == local,
+      }
+    })
+    .map(|a| a.value())
+}
+
+fn child<'a>(node: &Node<'a, 'a>, local: &str) -> Option<Node<'a, 'a>> {
+  node
+    .children()
+    .find(|n| n.is_element() && n.tag_name().name() == local)
+}
+
+fn children<'a, 'b>(
+  node: &Node<'a, 'a>,
+  local: &'b str,
+) -> impl Iterator<Item = Node<'a, 'a>> + use<'a, 'b> {
+  node
+    .children()
+    .filter(move |n| n.is_element() && n.tag_name().name() == local)
+}
+
+fn parse_shape_tree(tree: &Node, rels: &Relationships, out: &mut Vec<Block>) {
+  for shape in tree.children().filter(|n| n.is_element()) {
+    if is_tag(&shape, "sp") {
+      out.extend(parse_text_shape(&shape, rels));
+    } else if is_tag(&shape, "pic") {
+      if let Some(image) = parse_picture(&shape, rels) {
+        out.push(Block::Image(image));
+      }
+    } else if is_tag(&shape, "graphicFrame") {
+      if let Some(tbl) = shape.descendants().find(|n| is_tag(n, "tbl")) {
+        let table = parse_table(&tbl, rels);
+        if !table.rows.is_empty() {
+          out.push(Block::Table(table));
+        }
+      }
+    } else if is_tag(&shape, "grpSp") {
+      parse_shape_tree(&shape, rels, out);
+    }
+  }
+}
+
+fn is_title_shape(shape: &Node) -> bool {
+  shape
+    .descendants()
+    .find(|n| is_tag(n, "ph"))
+    .and_then(|ph| get_attr_local(&ph, "type"))
+    .is_some_and(|t| t == "title" || t == "ctrTitle")
+}
+
+fn parse_text_shape(shape: &Node, rels: &Relationships) -> Vec<Block> {
+  let Some(body) = child(shape, "txBody") else {
+    return Vec::new();
+  };
+  let kind = if is_title_shape(shape) {
+    ParagraphKind::Heading(1)
+  } else {
+    ParagraphKind::Normal
+  };
+  parse_text_body(&body, kind, rels)
+}
+
+fn parse_text_body(body: &Node, kind: ParagraphKind, rels: &Relationships) -> Vec<Block> {
+  children(body, "p")
+    .map(|p| Paragraph {
+      kind,
+      inlines: parse_paragraph_inlines(&p, rels),
+      attrs: Default::default(),
+    })
+    .filter(paragraph_has_visible_content)
+    .map(Block::Paragraph)
+    .collect()
+}
+
+fn parse_paragraph_inlines(p: &Node, rels: &Relationships) -> Vec<Inline> {
+  let mut out = Vec::new();
+  for c in p.children().filter(|n| n.is_element()) {
+    if is_tag(&c, "r") || is_tag(&c, "fld") {
+      out.extend(parse_run(&c, rels));
+    } else if is_tag(&c, "br") {
+      out.push(Inline::LineBreak);
+    }
+  }
+  out
+}
+
+fn parse_run(run: &Node, rels: &Relationships) -> Vec<Inline> {
+  let Some(text) = child(run, "t").and_then(|t| t.text()) else {
+    return Vec::new();
+  };
+  if text.is_empty() {
+    return Vec::new();
+  }
+
+  let mut inlines = vec![Inline::Text(text.to_string())];
+  let Some(rpr) = child(run, "rPr") else {
+    return inlines;
+  };
+
+  if get_attr_local(&rpr, "strike").is_some_and(|v| v != "noStrike") {
+    inlines = vec![Inline::Del(inlines)];
+  }
+  if get_attr_local(&rpr, "i").is_some_and(is_true) {
+    inlines = vec![Inline::Em(inlines)];
+  }
+  if get_attr_local(&rpr, "b").is_some_and(is_true) {
+    inlines = vec![Inline::Strong(inlines)];
+  }
+  match get_attr_local(&rpr, "baseline").and_then(|v| v.parse::<i32>().ok()) {
+    Some(b) if b > 0 => inlines = vec![Inline::Sup(inlines)],
+    Some(b) if b < 0 => inlines = vec![Inline::Sub(inlines)],
+    _ => {}
+  }
+
+  let href = child(&rpr, "hlinkClick")
+    .and_then(|h| get_attr_local(&h, "id"))
+    .and_then(|id| rels.get(id));
+  if let Some(href) = href {
+    inlines = vec![Inline::Link {
+      href: href.to_string(),
+      children: inlines,
+    }];
+  }
+
+  inlines
+}
+
+fn is_true(v: &str) -> bool {
+  v == "1" || v.eq_ignore_ascii_case("true")
+}
+
+fn parse_picture(pic: &Node, rels: &Relationships) -> Option<Image> {
+  let blip = pic.descendants().find(|n| is_tag(n, "blip"))?;
+  let rel_id = get_attr_local(&blip, "link").or_else(|| get_attr_local(&blip, "embed"))?;
+  let target = rels.get(rel_id)?;
+  // only include external images (http/https URLs)
+  if !(target.starts_with("http://") || target.starts_with("https://")) {
+    return None;
+  }
+  let alt = pic
+    .descendants()
+    .find(|n| is_tag(n, "cNvPr"))
+    .and_then(|n| get_attr_local(&n, "descr").or_else(|| get_attr_local(&n, "title")))
+    .filter(|s| !s.trim().is_empty())
+    .map(|s| s.to_string());
+  Some(Image {
+    src: target.to_string(),
+    alt,
+  })
+}
+
+fn parse_table(tbl: &Node, rels: &Relationships) -> Table {
+  let mut rows = Vec::new();
+  for tr in children(tbl, "tr") {
+    let mut cells = Vec::new();
+    for tc in children(&tr, "tc") {
+      // Cells covered by a neighbour's span are emitted as merge placeholders.
+      if get_attr_local(&tc, "hMerge").is_some_and(is_true)
+        || get_attr_local(&tc, "vMerge").is_some_and(is_true)
+      {
+        continue;
+      }
+      let blocks = child(&tc, "txBody")
+        .map(|body| parse_text_body(&body, ParagraphKind::Normal, rels))
+        .unwrap_or_default();
+      let colspan = get_attr_local(&tc, "gridSpan")
+        .and_then(|v| v.parse::<u32>().ok())
+        .and_then(NonZeroU32::new)
+        .unwrap_or_else(|| NonZeroU32::new(1).unwrap());
+      let rowspan = get_attr_local(&tc, "rowSpan")
+        .and_then(|v| v.parse::<u32>().ok())
+        .and_then(NonZeroU32::new)
+        .unwrap_or_else(|| NonZeroU32::new(1).unwrap());
+      cells.push(TableCell {
+        blocks,
+        colspan,
+        rowspan,
+      });
+    }
+    rows.push(TableRow {
+      cells,
+      kind: TableRowKind::Body,
+    });
+  }
+
+  let first_row_is_header = child(tbl, "tblPr")
+    .and_then(|pr| get_attr_local(&pr, "firstRow"))
+    .is_some_and(is_true);
+  if first_row_is_header {
+    if let Some(first) = rows.first_mut() {
+      first.kind = TableRowKind::Header;
+    }
+  }
+
+  Table { rows }
+}
+
+fn paragraph_has_visible_content(p: &Paragraph) -> bool {
+  inlines_have_visible_content(&p.inlines)
+}
+
+fn inlines_have_visible_content(inlines: &[Inline]) -> bool {
+  inlines.iter().any(inline_is_visible)
+}
+
+fn inline_is_visible(i: &Inline) -> bool {
+  match i {
+    Inline::Text(t) => !t.trim().is_empty(),
+    Inline::LineBreak => false,
+    Inline::Link { children, .. } => inlines_have_visible_content(children),
+    Inline::Strong(c) | Inline::Em(c) | Inline::Del(c) | Inline::Sup(c) | Inline::Sub(c) => {
+      inlines_have_visible_content(c)
+    }
+    Inline::Code(c) => !c.trim().is_empty(),
+    Inline::FootnoteRef(_) | Inline::EndnoteRef(_) | Inline::CommentRef(_) => true,
+    Inline::Bookmark(_) => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::io::Write;
+  use zip::write::{SimpleFileOptions, ZipWriter};
+
+  const NS: &str = r#"xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main"
+  xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"
+  xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships""#;
+
+  fn slide(shapes: &str) -> String {
+    format!(r#"<p:sld {NS}><p:cSld><p:spTree>{shapes}</p:spTree></p:cSld></p:sld>"#)
+  }
+
+  fn rels(entries: &[(&str, &str)]) -> String {
+    let rels: String = entries
+      .iter()
+      .map(|(id, target)| format!(r#"<Relationship Id="{id}" Target="{target}"/>"#))
+      .collect();
+    format!(
+      r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">{rels}</Relationships>"#
+    )
+  }
+
+  fn build_pptx(entries: &[(&str, String)]) -> Vec<u8> {
+    let mut zip = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for (name, contents) in entries {
+      zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+      zip.write_all(contents.as_bytes()).unwrap();
+    }
+    zip.finish().unwrap().into_inner()
+  }
+
+  fn text_shape(ph: Option<&str>, runs: &str) -> String {
+    let ph = ph
+      .map(|t| format!(r#"<p:nvSpPr><p:nvPr><p:ph type="{t}"/></p:nvPr></p:nvSpPr>"#))
+      .unwrap_or_default();
+    format!(r#"<p:sp>{ph}<p:txBody><a:p>{runs}</a:p></p:txBody></p:sp>"#)
+  }
+
+  fn paragraph_text(block: &Block) -> (ParagraphKind, Vec<Inline>) {
+    let Block::Paragraph(p) = block else {
+      panic!("expected a paragraph, got {block:?}");
+    };
+    (p.kind, p.inlines.clone())
+  }
+
+  #[test]
+  fn slide_text_follows_presentation_order() {
+    let presentation = format!(
+      r#"<p:presentation {NS}><p:sldIdLst>
+        <p:sldId id="256" r:id="rId2"/>
+        <p:sldId id="257" r:id="rId1"/>
+      </p:sldIdLst></p:presentation>"#
+    );
+    let data = build_pptx(&[
+      ("ppt/presentation.xml", presentation),
+      (
+        "ppt/_rels/presentation.xml.rels",
+        rels(&[("rId1", "slides/slide1.xml"), ("rId2", "slides/slide2.xml")]),
+      ),
+      (
+        "ppt/slides/slide1.xml",
+        slide(&text_shape(None, "<a:r><a:t>Second</a:t></a:r>")),
+      ),
+      (
+        "ppt/slides/slide2.xml",
+        slide(&format!(
+          "{}{}",
+          text_shape(Some("title"), "<a:r><a:t>Intro</a:t></a:r>"),
+          text_shape(
+            None,
+            r#"<a:r><a:rPr b="1"/><a:t>Bold</a:t></a:r><a:r><a:rPr baseline="30000"/><a:t>2</a:t></a:r>"#
+          ),
+        )),
+      ),
+    ]);
+
+    let doc = PptxProvider::new().parse_buffer(&data).unwrap();
+    assert_eq!(doc.blocks.len(), 3);
+    assert_eq!(
+      paragraph_text(&doc.blocks[0]),
+      (
+        ParagraphKind::Heading(1),
+        vec![Inline::Text("Intro".into())]
+      )
+    );
+    assert_eq!(
+      paragraph_text(&doc.blocks[1]),
+      (
+        ParagraphKind::Normal,
+        vec![
+          Inline::Strong(vec![Inline::Text("Bold".into())]),
+          Inline::Sup(vec![Inline::Text("2".into())]),
+        ]
+      )
+    );
+    assert_eq!(
+      paragraph_text(&doc.blocks[2]),
+      (ParagraphKind::Normal, vec![Inline::Text("Second".into())])
+    );
+  }
+
+  #[test]
+  fn slides_fall_back_to_numeric_order() {
+    let data = build_pptx(&[
+      (
+        "ppt/slides/slide10.xml",
+        slide(&text_shape(None, "<a:r><a:t>Ten</a:t></a:r>")),
+      ),
+      (
+        "ppt/slides/slide2.xml",
+        slide(&text_shape(None, "<a:r><a:t>Two</a:t></a:r>")),
+      ),
+    ]);
+
+    let doc = PptxProvider::new().parse_buffer(&data).unwrap();
+    let texts: Vec<_> = doc.blocks.iter().map(|b| paragraph_text(b).1).collect();
+    assert_eq!(
+      texts,
+      vec![
+        vec![Inline::Text("Two".into())],
+        vec![Inline::Text("Ten".into())]
+      ]
+    );
+
+    assert!(PptxProvider::new()
+      .parse_buffer(&build_pptx(&[("ppt/presentation.xml", String::new())]))
+      .is_err());
+  }
+
+  #[test]
+  fn tables_keep_header_and_spans() {
+    let cell = |text: &str, attrs: &str| {
+      format!(
+        r#"<a:tc {attrs}><a:txBody><a:p><a:r><a:t>{text}</a:t></a:r></a:p></a:txBody></a:tc>"#
+      )
+    };
+    let table = format!(
+      r#"<p:graphicFrame><a:graphic><a:graphicData><a:tbl>
+        <a:tblPr firstRow="1"/>
+        <a:tr>{}{}</a:tr>
+        <a:tr>{}{}</a:tr>
+      </a:tbl></a:graphicData></a:graphic></p:graphicFrame>"#,
+      cell("Name", r#"gridSpan="2""#),
+      cell("", r#"hMerge="1""#),
+      cell("a", ""),
+      cell("b", ""),
+    );
+    let data = build_pptx(&[("ppt/slides/slide1.xml", slide(&table))]);
+
+    let doc = PptxProvider::new().parse_buffer(&data).unwrap();
+    let Some(Block::Table(table)) = doc.blocks.first() else {
+      panic!("expected a table, got {:?}", doc.blocks);
+    };
+    assert_eq!(table.rows.len(), 2);
+    assert_eq!(table.rows[0].kind, TableRowKind::Header);
+    assert_eq!(table.rows[0].cells.len(), 1);
+    assert_eq!(table.rows[0].cells[0].colspan.get(), 2);
+    assert_eq!(table.rows[1].kind, TableRowKind::Body);
+    assert_eq!(table.rows[1].cells.len(), 2);
+    assert_eq!(
+      paragraph_text(&table.rows[1].cells[1].blocks[0]).1,
+      vec![Inline::Text("b".into())]
+    );
+  }
+
+  #[test]
+  fn only_external_images_are_kept() {
+    let pic = |id: &str, rel: &str| {
+      format!(
+        r#"<p:pic><p:nvPicPr><p:cNvPr id="{id}" name="Picture" descr="Chart"/></p:nvPicPr>
+          <p:blipFill><a:blip r:embed="{rel}"/></p:blipFill></p:pic>"#
+      )
+    };
+    let data = build_pptx(&[
+      (
+        "ppt/slides/slide1.xml",
+        slide(&format!("{}{}", pic("1", "rId1"), pic("2", "rId2"))),
+      ),
+      (
+        "ppt/slides/_rels/slide1.xml.rels",
+        rels(&[
+          ("rId1", "../media/image1.png"),
+          ("rId2", "https://example.com/chart.png"),
+        ]),
+      ),
+    ]);
+
+    let doc = PptxProvider::new().parse_buffer(&data).unwrap();
+    assert_eq!(
+      doc.blocks,
+      vec![Block::Image(Image {
+        src: "https://example.com/chart.png".to_string(),
+        alt: Some("Chart".to_string()),
+      })]
+    );
+  }
+}
@@ -1,6 +1,7 @@
 use crate::document::model::*;
 use crate::document::providers::DocumentProvider;
 use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::num::NonZeroU32;
 
@@ -127,6 +128,325 @@ fn extract_creatim(buf: &[u8]) -> Option<DateTime<Utc>> {
   Some(DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc))
 }
 
+/// Maps an `\outlinelevelN` value (0-based, 0..=8) to a heading level, or
+/// `None` for body text.
+fn outline_level_to_heading(level: i32) -> Option<u8> {
+  if (0..=8).contains(&level) {
+    Some((level + 1).min(6) as u8)
+  } else {
+    None
+  }
+}
+
+/// Maps heading paragraph style numbers (`\sN`) from the stylesheet to their
+/// heading level, either from the style's `\outlinelevelN` or a `heading N`
+/// style name.
+fn extract_heading_styles(src: &[u8]) -> HashMap<i32, u8> {
+  let mut styles = HashMap::new();
+  let Some(start) = find_group_start(src, b"{\\stylesheet") else {
+    return styles;
+  };
+  let Some(end) = find_matching_brace(src, start) else {
+    return styles;
+  };
+  let sheet = &src[start + 1..end - 1];
+
+  let mut i = 0usize;
+  while i < sheet.len() {
+    if sheet[i] != b'{' {
+      i += 1;
+      continue;
+    }
+    let Some(entry_end) = find_matching_brace(sheet, i) else {
+      break;
+    };
+    let entry = &sheet[i + 1..entry_end - 1];
+    i = entry_end;
+
+    let mut style: Option<i32> = None;
+    let mut outline_level: Option<i32> = None;
+    let mut name = String::new();
+    let mut depth = 0usize;
+    let mut j = 0usize;
+    while j < entry.len() {
+      match entry[j] {
+        b'{' => depth += 1,
+        b'}' => depth = depth.saturating_sub(1),
+        b'\\' if depth == 0 => {
+          if let Some((word, val, nj)) = read_control_word(entry, j + 1) {
+            match word.as_str() {
+              "s" => style = val,
+              "outlinelevel" => outline_level = val,
+              _ => {}
+            }
+            j = nj;
+            continue;
+          }
+        }
+        b';' if depth == 0 => break,
+        b'\r' | b'\n' => {}
+        byte if depth == 0 => push_byte_as_text(byte, &mut name),
+        _ => {}
+      }
+      j += 1;
+    }
+
+    let Some(style) = style else {
+      continue;
+    };
+    let level = match outline_level {
+      Some(level) => outline_level_to_heading(level),
+      None => name
+        .trim()
+        .to_ascii_lowercase()
+        .strip_prefix("heading ")
+        .and_then(|n| n.trim().parse::<i32>().ok())
+        .and_then(|n| outline_level_to_heading(n - 1)),
+    };
+    if let Some(level) = level {
+      styles.insert(style, level);
+    }
+  }
+
+  styles
+}
+
+/// Returns the contents of each top-level group in `buf`, without braces.
+fn child_groups(buf: &[u8]) -> Vec<&[u8]> {
+  let mut groups = Vec::new();
+  let mut i = 0usize;
+  while i < buf.len() {
+    if buf[i] != b'{' {
+      i += 1;
+      continue;
+    }
+    let Some(end) = find_matching_brace(buf, i) else {
+      break;
+    };
+    groups.push(&buf[i + 1..end - 1]);
+    i = end;
+  }
+  groups
+}
+
+/// Returns the control words of `buf` that are not inside a nested group.
+fn top_level_control_words(buf: &[u8]) -> Vec<(String, Option<i32>)> {
+  let mut words = Vec::new();
+  let mut depth = 0usize;
+  let mut i = 0usize;
+  while i < buf.len() {
+    match buf[i] {
+      b'{' => depth += 1,
+      b'}' => depth = depth.saturating_sub(1),
+      b'\\' if depth == 0 => {
+        if let Some((word, val, ni)) = read_control_word(buf, i + 1) {
+          words.push((word, val));
+          i = ni;
+          continue;
+        }
+      }
+      _ => {}
+    }
+    i += 1;
+  }
+  words
+}
+
+fn word_value(words: &[(String, Option<i32>)], name: &str) -> Option<i32> {
+  words.iter().find(|(w, _)| w == name).and_then(|(_, v)| *v)
+}
+
+/// Maps list override numbers (`\lsN`) to the list type of each of their
+/// levels, using `\levelnfc` from the list table.
+fn extract_list_types(src: &[u8]) -> HashMap<i32, Vec<ListType>> {
+  let mut overrides = HashMap::new();
+  let group = |needle: &[u8]| {
+    let start = find_group_start(src, needle)?;
+    let end = find_matching_brace(src, start)?;
+    Some(&src[start + 1..end - 1])
+  };
+
+  let mut lists: HashMap<i32, Vec<ListType>> = HashMap::new();
+  if let Some(table) = group(b"{\\*\\listtable") {
+    for list in child_groups(table) {
+      let Some(id) = word_value(&top_level_control_words(list), "listid") else {
+        continue;
+      };
+      let levels = child_groups(list)
+        .into_iter()
+        .map(top_level_control_words)
+        .filter(|words| words.first().is_some_and(|(w, _)| w == "listlevel"))
+        .map(|words| {
+          // 23 is a bullet and 255 is no number at all
+          match word_value(&words, "levelnfc").or_else(|| word_value(&words, "levelnfcn")) {
+            Some(23) | Some(255) | None => ListType::Unordered,
+            Some(_) => ListType::Ordered,
+          }
+        })
+        .collect();
+      lists.insert(id, levels);
+    }
+  }
+
+  if let Some(table) = group(b"{\\*\\listoverridetable") {
+    for list_override in child_groups(table) {
+      let words = top_level_control_words(list_override);
+      if let (Some(id), Some(ls)) = (word_value(&words, "listid"), word_value(&words, "ls")) {
+        if let Some(levels) = lists.get(&id) {
+          overrides.insert(ls, levels.clone());
+        }
+      }
+    }
+  }
+
+  overrides
+}
+
+/// Font numbers (`\fN`) of fixed-pitch fonts in the font table: `\fmodern`,
+/// `\fprq1`, or a name like Courier, Consolas or Lucida Console.
+fn extract_monospace_fonts(src: &[u8]) -> HashSet<i32> {
+  let mut fonts = HashSet::new();
+  let Some(start) = find_group_start(src, b"{\\fonttbl") else {
+    return fonts;
+  };
+  let Some(end) = find_matching_brace(src, start) else {
+    return fonts;
+  };
+  let table = &src[start + 1..end - 1];
+
+  // Entries are usually groups, but simple writers list them bare, separated by `;`
+  let mut entries = child_groups(table);
+  if entries.is_empty() {
+    entries = table.split(|&b| b == b';').collect();
+  }
+
+  for entry in entries {
+    let words = top_level_control_words(entry);
+    let Some(font) = word_value(&words, "f") else {
+      continue;
+    };
+
+    let mut name = String::new();
+    let mut depth = 0usize;
+    let mut i = 0usize;
+    while i < entry.len() {
+      match entry[i] {
+        b'{' => depth += 1,
+        b'}' => depth = depth.saturating_sub(1),
+        b'\\' if depth == 0 => {
+          if let Some((_, _, ni)) = read_control_word(entry, i + 1) {
+            i = ni;
+            continue;
+          }
+        }
+        b';' if depth == 0 => break,
+        b'\r' | b'\n' => {}
+        byte if depth == 0 => push_byte_as_text(byte, &mut name),
+        _ => {}
+      }
+      i += 1;
+    }
+
+    let name = name.to_ascii_lowercase();
+    let fixed_pitch = words
+      .iter()
+      .any(|(w, v)| w == "fmodern" || (w == "fprq" && *v == Some(1)));
+    if fixed_pitch
+      || ["courier", "mono", "consola", "console"]
+        .iter()
+        .any(|x| name.contains(x))
+    {
+      fonts.insert(font);
+    }
+  }
+
+  fonts
+}
+
+/// Accumulates consecutive list paragraphs, nesting deeper `\ilvl` levels
+/// inside the last item of the enclosing level.
+struct ListBuilder {
+  id: i32,
+  levels: Vec<(i32, List)>,
+}
+
+impl ListBuilder {
+  fn push_item(&mut self, level: i32, list_type: ListType, block: Block) {
+    while self.levels.len() > 1 && self.levels.last().is_some_and(|(l, _)| *l > level) {
+      self.close_level();
+    }
+    match self.levels.last_mut() {
+      Some((l, _)) if *l > level => *l = level,
+      Some((l, _)) if *l == level => {}
+      _ => self.levels.push((
+        level,
+        List {
+          items: Vec::new(),
+          list_type,
+        },
+      )),
+    }
+    if let Some((_, list)) = self.levels.last_mut() {
+      list.items.push(ListItem {
+        blocks: vec![block],
+      });
+    }
+  }
+
+  fn close_level(&mut self) {
+    let Some((_, list)) = self.levels.pop() else {
+      return;
+    };
+    if let Some((_, parent)) = self.levels.last_mut() {
+      match parent.items.last_mut() {
+        Some(item) => item.blocks.push(Block::List(list)),
+        None => parent.items.push(ListItem {
+          blocks: vec![Block::List(list)],
+        }),
+      }
+    }
+  }
+
+  fn finalize(mut self) -> Option<Block> {
+    while self.levels.len() > 1 {
+      self.close_level();
+    }
+    self.levels.pop().map(|(_, list)| Block::List(list))
+  }
+}
+
+struct RtfLists {
+  types: HashMap<i32, Vec<ListType>>,
+  current: Option<ListBuilder>,
+}
+
+impl RtfLists {
+  fn push_item(&mut self, id: i32, level: i32, block: Block, blocks: &mut Vec<Block>) {
+    if self.current.as_ref().is_some_and(|b| b.id != id) {
+      self.flush(blocks);
+    }
+    let list_type = self
+      .types
+      .get(&id)
+      .and_then(|levels| levels.get(level.max(0) as usize))
+      .copied()
+      .unwrap_or(ListType::Unordered);
+    self
+      .current
+      .get_or_insert_with(|| ListBuilder {
+        id,
+        levels: Vec::new(),
+      })
+      .push_item(level, list_type, block);
+  }
+
+  fn flush(&mut self, blocks: &mut Vec<Block>) {
+    if let Some(block) = self.current.take().and_then(ListBuilder::finalize) {
+      blocks.push(block);
+    }
+  }
+}
+
 #[derive(Default)]
 struct TableBuilder {
   rows: Vec<TableRow>,
@@ -219,6 +539,17 @@ fn parse_rtf_body_to_blocks(src: &[u8]) -> Vec<Block> {
     strike: bool,
     sup: bool,
     sub: bool,
+    code: bool,
+  }
+
+  // Paragraph properties persist across \par until the next \pard
+  #[derive(Clone, Copy, Default)]
+  struct ParagraphProps {
+    in_table_cell: bool,
+    heading: Option<u8>,
+    list_id: Option<i32>,
+    list_level: i32,
+    alignment: Option<TextAlignment>,
   }
 
   #[derive(Clone)]
@@ -234,9 +565,15 @@ fn parse_rtf_body_to_blocks(src: &[u8]) -> Vec<Block> {
   let mut cur_inlines: Vec<Inline> = Vec::new();
   let mut text_buf = String::new();
   let mut table_builder: Option<TableBuilder> = None;
-  let mut in_table_cell = false;
   let mut uc_skip: usize = 1;
   let mut pending_uc_skip: usize = 0;
+  let heading_styles = extract_heading_styles(src);
+  let monospace_fonts = extract_monospace_fonts(src);
+  let mut para = ParagraphProps::default();
+  let mut lists = RtfLists {
+    types: extract_list_types(src),
+    current: None,
+  };
 
   const SKIP_DESTS: &[&str] = &[
     "fonttbl",
@@ -265,6 +602,8 @@ fn parse_rtf_body_to_blocks(src: &[u8]) -> Vec<Block> {
     "pict",
     "object",
     "info",
+    "listtext",
+    "pntext",
   ];
 
   fn style_wrap(mut node: Inline, st: &State) -> Inline {
@@ -287,7 +626,13 @@ fn parse_rtf_body_to_blocks(src: &[u8]) -> Vec<Block> {
 
   fn push_text_buf(text_buf: &mut String, cur: &mut Vec<Inline>, st: &State) {
     if !text_buf.is_empty() {
-      let node = style_wrap(Inline::Text(text_buf.clone()), st);
+      let text = text_buf.clone();
+      let node = if st.code {
+        Inline::Code(text)
+      } else {
+        Inline::Text(text)
+      };
+      let node = style_wrap(node, st);
       cur.push(node);
       text_buf.clear();
     }
@@ -312,19 +657,37 @@ fn parse_rtf_body_to_blocks(src: &[u8]) -> Vec<Block> {
     text_buf: &mut String,
     blocks: &mut Vec<Block>,
     table: &mut Option<TableBuilder>,
+    lists: &mut RtfLists,
     st: &State,
-    in_table_cell: bool,
+    para: ParagraphProps,
   ) {
     push_text_buf(text_buf, cur, st);
+    let list = para.list_id.filter(|_| !para.in_table_cell);
     if has_visible_content(cur) {
+      let kind = match para.heading {
+        Some(level) if !para.in_table_cell => ParagraphKind::Heading(level),
+        _ => ParagraphKind::Normal,
+      };
       let block = Block::Paragraph(Paragraph {
-        kind: ParagraphKind::Normal,
+        kind,
         inlines: std::mem::take(cur),
+        attrs: ParagraphAttrs {
+          alignment: para.alignment,
+        },
       });
-      push_block_target(block, blocks, table, in_table_cell);
+      if let Some(id) = list {
+        flush_table(blocks, table);
+        lists.push_item(id, para.list_level, block, blocks);
+      } else {
+        lists.flush(blocks);
+        push_block_target(block, blocks, table, para.in_table_cell);
+      }
     } else {
       cur.clear();
-      if !in_table_cell {
+      if list.is_none() {
+        lists.flush(blocks);
+      }
+      if !para.in_table_cell {
         flush_table(blocks, table);
       }
     }
@@ -489,10 +852,10 @@ fn parse_rtf_body_to_blocks(src: &[u8]) -> Vec<Block> {
               "trowd" => {
                 let builder = table_builder.get_or_insert_with(TableBuilder::default);
                 builder.start_row();
-                in_table_cell = false;
+                para.in_table_cell = false;
               }
               "intbl" => {
-                in_table_cell = true;
+                para.in_table_cell = true;
               }
               "cell" => {
                 flush_paragraph(
@@ -500,19 +863,23 @@ fn parse_rtf_body_to_blocks(src: &[u8]) -> Vec<Block> {
                   &mut text_buf,
                   &mut blocks,
                   &mut table_builder,
+                  &mut lists,
                   &state,
-                  true,
+                  ParagraphProps {
+                    in_table_cell: true,
+                    ..para
+                  },
                 );
                 if let Some(builder) = table_builder.as_mut() {
                   builder.finish_cell();
                 }
-                in_table_cell = false;
+                para.in_table_cell = false;
               }
               "row" => {
                 if let Some(builder) = table_builder.as_mut() {
                   builder.finish_row();
                 }
-                in_table_cell = false;
+                para.in_table_cell = false;
               }
               "cellx" | "clvertalb" | "clvertalc" | "clvertalt" => {}
               "b" => {
@@ -546,18 +913,53 @@ fn parse_rtf_body_to_blocks(src: &[u8]) -> Vec<Block> {
                 state.sup = false;
                 state.sub = false;
               }
+              "f" => {
+                flush_before_change(&mut text_buf, &mut cur_inlines, &state);
+                state.code = val.is_some_and(|v| monospace_fonts.contains(&v));
+              }
               "plain" => {
                 flush_before_change(&mut text_buf, &mut cur_inlines, &state);
                 state = State::default();
               }
+              "pard" => {
+                para = ParagraphProps {
+                  in_table_cell: para.in_table_cell,
+                  ..ParagraphProps::default()
+                };
+              }
+              "s" => {
+                para.heading = val.and_then(|v| heading_styles.get(&v).copied());
+              }
+              "outlinelevel" => {
+                para.heading = val.and_then(outline_level_to_heading);
+              }
+              "ql" => {
+                para.alignment = Some(TextAlignment::Left);
+              }
+              "qr" => {
+                para.alignment = Some(TextAlignment::Right);
+              }
+              "qc" => {
+                para.alignment = Some(TextAlignment::Center);
+              }
+              "qj" => {
+                para.alignment = Some(TextAlignment::Justify);
+              }
+              "ls" => {
+                para.list_id = val;
+              }
+              "ilvl" => {
+                para.list_level = val.unwrap_or(0).max(0);
+              }
               "par" => {
                 flush_paragraph(
                   &mut cur_inlines,
                   &mut text_buf,
                   &mut blocks,
                   &mut table_builder,
+                  &mut lists,
                   &state,
-                  in_table_cell,
+                  para,
                 );
               }
               "uc" => {
@@ -582,8 +984,9 @@ fn parse_rtf_body_to_blocks(src: &[u8]) -> Vec<Block> {
               &mut text_buf,
               &mut blocks,
               &mut table_builder,
+              &mut lists,
               &state,
-              in_table_cell,
+              para,
             );
           }
 
@@ -626,11 +1029,13 @@ fn parse_rtf_body_to_blocks(src: &[u8]) -> Vec<Block> {
       &mut text_buf,
       &mut blocks,
       &mut table_builder,
+      &mut lists,
       &state,
-      in_table_cell,
+      para,
     );
   }
 
+  lists.flush(&mut blocks);
   flush_table(&mut blocks, &mut table_builder);
 
   blocks
@@ -762,3 +1167,212 @@ fn skip_word_and_space(src: &[u8], mut i: usize) -> usize {
   }
   i
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn paragraph_text(p: &Paragraph) -> String {
+    p.inlines
+      .iter()
+      .filter_map(|i| match i {
+        Inline::Text(t) => Some(t.as_str()),
+        _ => None,
+      })
+      .collect::<String>()
+      .trim()
+      .to_string()
+  }
+
+  fn paragraph_kinds(blocks: &[Block]) -> Vec<(ParagraphKind, String)> {
+    blocks
+      .iter()
+      .filter_map(|b| match b {
+        Block::Paragraph(p) => Some((p.kind, paragraph_text(p))),
+        _ => None,
+      })
+      .collect()
+  }
+
+  #[test]
+  fn test_outline_level_headings() {
+    let rtf = br"{\rtf1\ansi
+{\pard\outlinelevel0 Title\par}
+{\pard\outlinelevel1 Section\par}
+{\pard Body text\par}
+{\pard\outlinelevel2 Subsection\par}
+{\pard\outlinelevel8 Deep\par}
+{\pard\outlinelevel9 Not a heading\par}
+}";
+
+    assert_eq!(
+      paragraph_kinds(&parse_rtf_body_to_blocks(rtf)),
+      vec![
+        (ParagraphKind::Heading(1), "Title".to_string()),
+        (ParagraphKind::Heading(2), "Section".to_string()),
+        (ParagraphKind::Normal, "Body text".to_string()),
+        (ParagraphKind::Heading(3), "Subsection".to_string()),
+        (ParagraphKind::Heading(6), "Deep".to_string()),
+        (ParagraphKind::Normal, "Not a heading".to_string()),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_stylesheet_headings() {
+    let rtf = br"{\rtf1\ansi
+{\stylesheet{\ql Normal;}{\s1\ql\outlinelevel0 heading 1;}{\s2\ql\sbasedon0 Heading 2;}{\*\cs10 Default Paragraph Font;}}
+\pard\s1 Introduction\par
+\pard\s2 Background\par
+\pard\plain Plain paragraph\par
+}";
+
+    assert_eq!(
+      paragraph_kinds(&parse_rtf_body_to_blocks(rtf)),
+      vec![
+        (ParagraphKind::Heading(1), "Introduction".to_string()),
+        (ParagraphKind::Heading(2), "Background".to_string()),
+        (ParagraphKind::Normal, "Plain paragraph".to_string()),
+      ]
+    );
+  }
+
+  fn list_items(list: &List) -> Vec<String> {
+    list
+      .items
+      .iter()
+      .map(|item| {
+        item
+          .blocks
+          .iter()
+          .map(|b| match b {
+            Block::Paragraph(p) => paragraph_text(p),
+            Block::List(l) => format!("[{}]", list_items(l).join(", ")),
+            other => format!("{:?}", other),
+          })
+          .collect::<Vec<_>>()
+          .join(" ")
+      })
+      .collect()
+  }
+
+  const LIST_TABLE: &[u8] = br"{\*\listtable
+{\list\listtemplateid1{\listlevel\levelnfc23\levelstartat1{\leveltext\'01\u-3913 ?;}}{\listlevel\levelnfc0\levelstartat1{\leveltext\'02\'01.;}}\listid100}
+{\list\listtemplateid2{\listlevel\levelnfc0\levelstartat1{\leveltext\'02\'00.;}}\listid200}}
+{\*\listoverridetable{\listoverride\listid100\listoverridecount0\ls1}{\listoverride\listid200\listoverridecount0\ls2}}";
+
+  #[test]
+  fn test_lists() {
+    let mut rtf = br"{\rtf1\ansi".to_vec();
+    rtf.extend_from_slice(LIST_TABLE);
+    rtf.extend_from_slice(
+      br"
+\pard Intro\par
+\pard\ls1\ilvl0{\listtext\'b7\tab}Apples\par
+\pard\ls1\ilvl1{\listtext 1.\tab}Red\par
+\pard\ls1\ilvl1{\listtext 2.\tab}Green\par
+\pard\ls1\ilvl0{\listtext\'b7\tab}Pears\par
+\pard\ls2{\listtext 1.\tab}First\par
+\pard\ls2{\listtext 2.\tab}Second\par
+\pard Outro\par
+}",
+    );
+
+    let blocks = parse_rtf_body_to_blocks(&rtf);
+    assert_eq!(blocks.len(), 4);
+    assert!(matches!(&blocks[0], Block::Paragraph(_)));
+    assert!(matches!(&blocks[3], Block::Paragraph(_)));
+
+    let Block::List(bullets) = &blocks[1] else {
+      panic!("expected a list, got {:?}", blocks[1]);
+    };
+    assert_eq!(bullets.list_type, ListType::Unordered);
+    assert_eq!(list_items(bullets), vec!["Apples [Red, Green]", "Pears"]);
+    let Some(Block::List(nested)) = bullets.items[0].blocks.get(1) else {
+      panic!("expected a nested list");
+    };
+    assert_eq!(nested.list_type, ListType::Ordered);
+
+    let Block::List(numbered) = &blocks[2] else {
+      panic!("expected a list, got {:?}", blocks[2]);
+    };
+    assert_eq!(numbered.list_type, ListType::Ordered);
+    assert_eq!(list_items(numbered), vec!["First", "Second"]);
+  }
+
+  #[test]
+  fn test_list_without_list_table_is_unordered() {
+    let rtf = br"{\rtf1\ansi
+\pard\ls5 One\par
+\pard\ls5 Two\par
+}";
+
+    let blocks = parse_rtf_body_to_blocks(rtf);
+    assert_eq!(blocks.len(), 1);
+    let Block::List(list) = &blocks[0] else {
+      panic!("expected a list, got {:?}", blocks[0]);
+    };
+    assert_eq!(list.list_type, ListType::Unordered);
+    assert_eq!(list_items(list), vec!["One", "Two"]);
+  }
+
+  #[test]
+  fn test_monospace_fonts() {
+    assert_eq!(
+      extract_monospace_fonts(
+        br"{\rtf1{\fonttbl{\f0\froman Times New Roman;}{\f1\fmodern\fprq1 Courier New;}{\f2\fswiss Consolas;}}}"
+      ),
+      HashSet::from([1, 2])
+    );
+    assert_eq!(
+      extract_monospace_fonts(br"{\rtf1{\fonttbl\f0\fswiss Helvetica;\f1\fnil Menlo Mono;}}"),
+      HashSet::from([1])
+    );
+
+    let rtf = br"{\rtf1\ansi\deff0{\fonttbl{\f0\froman Times;}{\f1\fmodern Courier;}}
+\pard Run {\f1 cargo test} now, then \f1 exit\f0  done.\par
+}";
+    let blocks = parse_rtf_body_to_blocks(rtf);
+    let Some(Block::Paragraph(p)) = blocks.first() else {
+      panic!("expected a paragraph, got {blocks:?}");
+    };
+    assert_eq!(
+      p.inlines,
+      vec![
+        Inline::Text("Run ".to_string()),
+        Inline::Code("cargo test".to_string()),
+        Inline::Text(" now, then ".to_string()),
+        Inline::Code("exit".to_string()),
+        Inline::Text(" done.".to_string()),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_paragraph_alignment() {
+    let rtf = br"{\rtf1\ansi
+\pard\qc Centered\par
+Still centered\par
+\pard\qr Right\par
+\pard\qj Justified\par
+\pard Default\par
+}";
+    let alignments: Vec<_> = parse_rtf_body_to_blocks(rtf)
+      .into_iter()
+      .map(|b| match b {
+        Block::Paragraph(p) => p.attrs.alignment,
+        other => panic!("expected a paragraph, got {other:?}"),
+      })
+      .collect();
+    assert_eq!(
+      alignments,
+      vec![
+        Some(TextAlignment::Center),
+        Some(TextAlignment::Center),
+        Some(TextAlignment::Right),
+        Some(TextAlignment::Justify),
+        None,
+      ]
+    );
+  }
+}
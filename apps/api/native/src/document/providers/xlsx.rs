@@ -7,11 +7,23 @@ use std::num::NonZeroU32;
 
 const ONE: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(1) };
 
-pub struct XlsxProvider;
+#[derive(Debug, Clone, Default)]
+pub struct XlsxParseOptions {
+  /// Restrict output to these sheet names, in workbook order.
+  pub only_sheets: Option<Vec<String>>,
+}
+
+pub struct XlsxProvider {
+  options: XlsxParseOptions,
+}
 
 impl XlsxProvider {
   pub fn new() -> Self {
-    Self
+    Self::with_options(XlsxParseOptions::default())
+  }
+
+  pub fn with_options(options: XlsxParseOptions) -> Self {
+    Self { options }
   }
 }
 
@@ -23,10 +35,17 @@ impl DocumentProvider for XlsxProvider {
     let mut blocks: Vec<Block> = Vec::new();
 
     for sheet_name in workbook.sheet_names() {
+      if let Some(only) = &self.options.only_sheets {
+        if !only.contains(&sheet_name) {
+          continue;
+        }
+      }
+
       // Add sheet heading
       blocks.push(Block::Paragraph(Paragraph {
-        kind: ParagraphKind::Heading(2),
+        kind: ParagraphKind::Heading(1),
         inlines: vec![Inline::Text(sheet_name.clone())],
+        attrs: Default::default(),
       }));
 
       if let Ok(range) = workbook.worksheet_range(&sheet_name) {
@@ -41,6 +60,7 @@ impl DocumentProvider for XlsxProvider {
               vec![Block::Paragraph(Paragraph {
                 kind: ParagraphKind::Normal,
                 inlines: vec![Inline::Text(text)],
+                attrs: Default::default(),
               })]
             };
             cells.push(TableCell {
@@ -85,3 +105,140 @@ fn data_type_to_string(cell: &Data) -> String {
     Data::Error(e) => format!("#ERROR({e:?})"),
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::io::Write;
+  use zip::write::{SimpleFileOptions, ZipWriter};
+
+  fn build_xlsx(sheets: &[(&str, &str)]) -> Vec<u8> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let opts = SimpleFileOptions::default();
+
+    let mut workbook_sheets = String::new();
+    let mut rels = String::new();
+    for (i, (name, _)) in sheets.iter().enumerate() {
+      let n = i + 1;
+      workbook_sheets.push_str(&format!(
+        r#"<sheet name="{name}" sheetId="{n}" r:id="rId{n}"/>"#
+      ));
+      rels.push_str(&format!(
+        r#"<Relationship Id="rId{n}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet{n}.xml"/>"#
+      ));
+    }
+
+    zip.start_file("[Content_Types].xml", opts).unwrap();
+    write!(
+      zip,
+      r#"<?xml version="1.0" encoding="UTF-8"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+<Default Extension="xml" ContentType="application/xml"/>
+<Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>
+</Types>"#
+    )
+    .unwrap();
+    zip.start_file("xl/workbook.xml", opts).unwrap();
+    write!(
+      zip,
+      r#"<?xml version="1.0" encoding="UTF-8"?>
+<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"
+  xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+<sheets>{workbook_sheets}</sheets>
+</workbook>"#
+    )
+    .unwrap();
+    zip.start_file("xl/_rels/workbook.xml.rels", opts).unwrap();
+    write!(
+      zip,
+      r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">{rels}</Relationships>"#
+    )
+    .unwrap();
+
+    for (i, (_, value)) in sheets.iter().enumerate() {
+      zip
+        .start_file(format!("xl/worksheets/sheet{}.xml", i + 1), opts)
+        .unwrap();
+      write!(
+        zip,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+<sheetData><row r="1"><c r="A1" t="inlineStr"><is><t>{value}</t></is></c></row></sheetData>
+</worksheet>"#
+      )
+      .unwrap();
+    }
+
+    zip.finish().unwrap().into_inner()
+  }
+
+  fn headings(doc: &Document) -> Vec<String> {
+    doc
+      .blocks
+      .iter()
+      .filter_map(|b| match b {
+        Block::Paragraph(Paragraph {
+          kind: ParagraphKind::Heading(1),
+          inlines,
+          ..
+        }) => match inlines.as_slice() {
+          [Inline::Text(t)] => Some(t.clone()),
+          _ => None,
+        },
+        _ => None,
+      })
+      .collect()
+  }
+
+  #[test]
+  fn emits_a_heading_before_each_sheet_table() {
+    let data = build_xlsx(&[("First", "a"), ("Second", "b"), ("Third", "c")]);
+    let doc = XlsxProvider::new().parse_buffer(&data).unwrap();
+
+    assert_eq!(headings(&doc), ["First", "Second", "Third"]);
+    assert_eq!(doc.blocks.len(), 6);
+    for pair in doc.blocks.chunks(2) {
+      assert!(matches!(pair[0], Block::Paragraph(_)));
+      assert!(matches!(pair[1], Block::Table(_)));
+    }
+  }
+
+  #[test]
+  fn only_sheets_restricts_output() {
+    let data = build_xlsx(&[("First", "a"), ("Second", "b"), ("Third", "c")]);
+    let doc = XlsxProvider::with_options(XlsxParseOptions {
+      only_sheets: Some(vec!["Third".to_string(), "First".to_string()]),
+    })
+    .parse_buffer(&data)
+    .unwrap();
+
+    assert_eq!(headings(&doc), ["First", "Third"]);
+  }
+
+  #[test]
+  fn only_sheets_is_applied_through_the_converter() {
+    use crate::document::{ConversionOptions, DocumentConverter, DocumentType};
+
+    let data = build_xlsx(&[("First", "a"), ("Second", "b"), ("Third", "c")]);
+    let html = DocumentConverter::new()
+      .convert_buffer_to_html_with_options(
+        &data,
+        DocumentType::Xlsx,
+        ConversionOptions {
+          embed_images: false,
+          include_comments: false,
+          include_footnotes: false,
+          heading_id_prefix: None,
+          max_image_size_bytes: None,
+          only_sheets: Some(vec!["Second".to_string()]),
+        },
+      )
+      .unwrap();
+
+    assert!(html.contains("Second"));
+    assert!(!html.contains("First"));
+    assert!(!html.contains("Third"));
+  }
+}
@@ -1,24 +1,83 @@
 use crate::document::model::*;
+use crate::document::toc::inline_text;
 use maud::{html, Markup, DOCTYPE};
+use std::cell::RefCell;
+use std::collections::HashMap;
 
-pub struct HtmlRenderer;
+const BOLD_STYLE: &str = "font-weight:bold";
+const ITALIC_STYLE: &str = "font-style:italic";
+const STRIKE_STYLE: &str = "text-decoration:line-through";
+const SUP_STYLE: &str = "vertical-align:super";
+const SUB_STYLE: &str = "vertical-align:sub";
+const CODE_STYLE: &str = "font-family:monospace";
+const TABLE_STYLE: &str = "border-collapse:collapse;border:1px solid #ccc";
+const CELL_STYLE: &str = "border:1px solid #ccc;padding:4px";
+
+#[derive(Debug, Clone)]
+pub struct HtmlRenderOptions {
+  pub include_comments: bool,
+  /// Covers both footnotes and endnotes.
+  pub include_footnotes: bool,
+  /// When set, headings get an `id="{prefix}-{slug}"` anchor.
+  pub heading_id_prefix: Option<String>,
+  /// Emit bare `<img>` tags instead of wrapping captioned images in `<figure>`.
+  pub no_figure_wrap: bool,
+  /// Add minimal `style` attributes for environments without stylesheets.
+  /// When off, paragraph alignment is emitted as a `firecrawl-align-*` class.
+  pub inline_styles: bool,
+}
+
+impl Default for HtmlRenderOptions {
+  fn default() -> Self {
+    Self {
+      include_comments: true,
+      include_footnotes: true,
+      heading_id_prefix: None,
+      no_figure_wrap: false,
+      inline_styles: false,
+    }
+  }
+}
+
+pub struct HtmlRenderer {
+  options: HtmlRenderOptions,
+  /// Times each heading id has been used in the current `render` call.
+  heading_ids: RefCell<HashMap<String, usize>>,
+}
 
 impl HtmlRenderer {
   pub fn new() -> Self {
-    Self
+    Self::with_options(HtmlRenderOptions::default())
+  }
+
+  pub fn with_options(options: HtmlRenderOptions) -> Self {
+    Self {
+      options,
+      heading_ids: RefCell::new(HashMap::new()),
+    }
   }
 
   pub fn render(&self, document: &Document) -> String {
+    self.heading_ids.borrow_mut().clear();
     let title = document.metadata.title.as_deref().unwrap_or("Document");
 
-    let footnotes: Vec<&Note> = document
-      .notes
+    let notes: &[Note] = if self.options.include_footnotes {
+      &document.notes
+    } else {
+      &[]
+    };
+    let comments: &[Comment] = if self.options.include_comments {
+      &document.comments
+    } else {
+      &[]
+    };
+
+    let footnotes: Vec<&Note> = notes
       .iter()
       .filter(|n| matches!(n.kind, NoteKind::Footnote))
       .collect();
 
-    let endnotes: Vec<&Note> = document
-      .notes
+    let endnotes: Vec<&Note> = notes
       .iter()
       .filter(|n| matches!(n.kind, NoteKind::Endnote))
       .collect();
@@ -60,10 +119,10 @@ impl HtmlRenderer {
                     }
                 }
 
-                @if !document.comments.is_empty() {
+                @if !comments.is_empty() {
                     section id="comments" {
                         h2 { "Comments" }
-                        @for comment in &document.comments {
+                        @for comment in comments {
                             article id={ "comment-" (&comment.id.0) } {
                                 @if let Some(author) = &comment.author_name {
                                     header {
@@ -111,19 +170,51 @@ impl HtmlRenderer {
   }
 
   fn render_paragraph(&self, p: &Paragraph) -> Markup {
+    // Alignment is a class unless inline styles were asked for
+    let (align_class, align_style) = match p.attrs.alignment {
+      Some(alignment) if self.options.inline_styles => (None, Some(alignment_style(alignment))),
+      Some(alignment) => (Some(alignment_class(alignment)), None),
+      None => (None, None),
+    };
     match p.kind {
-      ParagraphKind::Normal => html! { p { (self.render_inlines(&p.inlines)) } },
-      ParagraphKind::Blockquote => html! {
-          blockquote { p { (self.render_inlines(&p.inlines)) } }
+      ParagraphKind::Normal => html! {
+          p class=[align_class] style=[align_style] { (self.render_inlines(&p.inlines)) }
       },
-      ParagraphKind::Heading(level) => match level {
-        1 => html! { h1 { (self.render_inlines(&p.inlines)) } },
-        2 => html! { h2 { (self.render_inlines(&p.inlines)) } },
-        3 => html! { h3 { (self.render_inlines(&p.inlines)) } },
-        4 => html! { h4 { (self.render_inlines(&p.inlines)) } },
-        5 => html! { h5 { (self.render_inlines(&p.inlines)) } },
-        _ => html! { h6 { (self.render_inlines(&p.inlines)) } },
+      ParagraphKind::Blockquote => html! {
+          blockquote {
+              p class=[align_class] style=[align_style] { (self.render_inlines(&p.inlines)) }
+          }
       },
+      ParagraphKind::Heading(level) => {
+        let id = self.heading_id(&p.inlines);
+        match level {
+          1 => html! { h1 id=[id] { (self.render_inlines(&p.inlines)) } },
+          2 => html! { h2 id=[id] { (self.render_inlines(&p.inlines)) } },
+          3 => html! { h3 id=[id] { (self.render_inlines(&p.inlines)) } },
+          4 => html! { h4 id=[id] { (self.render_inlines(&p.inlines)) } },
+          5 => html! { h5 id=[id] { (self.render_inlines(&p.inlines)) } },
+          _ => html! { h6 id=[id] { (self.render_inlines(&p.inlines)) } },
+        }
+      }
+    }
+  }
+
+  fn heading_id(&self, inlines: &[Inline]) -> Option<String> {
+    let prefix = self.options.heading_id_prefix.as_deref()?;
+    let slug = slugify(&inline_text(inlines));
+    if slug.is_empty() {
+      return None;
+    }
+    let id = format!("{prefix}-{slug}");
+
+    // Repeated headings get `-2`, `-3`, ... so every anchor stays unique
+    let mut seen = self.heading_ids.borrow_mut();
+    let count = seen.entry(id.clone()).or_insert(0);
+    *count += 1;
+    if *count == 1 {
+      Some(id)
+    } else {
+      Some(format!("{id}-{count}"))
     }
   }
 
@@ -141,7 +232,7 @@ impl HtmlRenderer {
     }
 
     html! {
-        table {
+        table style=[self.style(TABLE_STYLE)] {
             @if !head_rows.is_empty() {
                 thead { @for row in head_rows { (self.render_table_row(row, true)) } }
             }
@@ -154,6 +245,7 @@ impl HtmlRenderer {
   }
 
   fn render_table_row(&self, row: &TableRow, header: bool) -> Markup {
+    let style = self.style(CELL_STYLE);
     html! {
         tr {
             @for cell in &row.cells {
@@ -164,23 +256,23 @@ impl HtmlRenderer {
 
                 @if header {
                     @if let (Some(cs), Some(rs)) = (cs_attr, rs_attr) {
-                        th colspan=(cs) rowspan=(rs) { (self.render_blocks_inline(&cell.blocks)) }
+                        th colspan=(cs) rowspan=(rs) style=[style] { (self.render_blocks_inline(&cell.blocks)) }
                     } @else if let Some(cs) = cs_attr {
-                        th colspan=(cs) { (self.render_blocks_inline(&cell.blocks)) }
+                        th colspan=(cs) style=[style] { (self.render_blocks_inline(&cell.blocks)) }
                     } @else if let Some(rs) = rs_attr {
-                        th rowspan=(rs) { (self.render_blocks_inline(&cell.blocks)) }
+                        th rowspan=(rs) style=[style] { (self.render_blocks_inline(&cell.blocks)) }
                     } @else {
-                        th { (self.render_blocks_inline(&cell.blocks)) }
+                        th style=[style] { (self.render_blocks_inline(&cell.blocks)) }
                     }
                 } @else {
                     @if let (Some(cs), Some(rs)) = (cs_attr, rs_attr) {
-                        td colspan=(cs) rowspan=(rs) { (self.render_blocks_inline(&cell.blocks)) }
+                        td colspan=(cs) rowspan=(rs) style=[style] { (self.render_blocks_inline(&cell.blocks)) }
                     } @else if let Some(cs) = cs_attr {
-                        td colspan=(cs) { (self.render_blocks_inline(&cell.blocks)) }
+                        td colspan=(cs) style=[style] { (self.render_blocks_inline(&cell.blocks)) }
                     } @else if let Some(rs) = rs_attr {
-                        td rowspan=(rs) { (self.render_blocks_inline(&cell.blocks)) }
+                        td rowspan=(rs) style=[style] { (self.render_blocks_inline(&cell.blocks)) }
                     } @else {
-                        td { (self.render_blocks_inline(&cell.blocks)) }
+                        td style=[style] { (self.render_blocks_inline(&cell.blocks)) }
                     }
                 }
             }
@@ -201,11 +293,21 @@ impl HtmlRenderer {
 
   fn render_image(&self, i: &Image) -> Markup {
     match &i.alt {
-      Some(alt) => html! { img src=(i.src) alt=(alt); },
-      None => html! { img src=(i.src); },
+      Some(alt) if !self.options.no_figure_wrap => html! {
+          figure class="firecrawl-figure" {
+              img class="firecrawl-image" src=(i.src) alt=(alt);
+              figcaption { (alt) }
+          }
+      },
+      Some(alt) => html! { img class="firecrawl-image" src=(i.src) alt=(alt); },
+      None => html! { img class="firecrawl-image" src=(i.src); },
     }
   }
 
+  fn style(&self, css: &'static str) -> Option<&'static str> {
+    self.options.inline_styles.then_some(css)
+  }
+
   fn render_inlines(&self, inlines: &[Inline]) -> Markup {
     html! { @for inline in inlines { (self.render_inline(inline)) } }
   }
@@ -219,12 +321,28 @@ impl HtmlRenderer {
         html! { a href=(href) { (self.render_inlines(children)) } }
       }
 
-      Inline::Strong(children) => html! { strong { (self.render_inlines(children)) } },
-      Inline::Em(children) => html! { em { (self.render_inlines(children)) } },
-      Inline::Del(children) => html! { del { (self.render_inlines(children)) } },
-      Inline::Code(code) => html! { code { (code) } },
-      Inline::Sup(children) => html! { sup { (self.render_inlines(children)) } },
-      Inline::Sub(children) => html! { sub { (self.render_inlines(children)) } },
+      Inline::Strong(children) => {
+        html! { strong style=[self.style(BOLD_STYLE)] { (self.render_inlines(children)) } }
+      }
+      Inline::Em(children) => {
+        html! { em style=[self.style(ITALIC_STYLE)] { (self.render_inlines(children)) } }
+      }
+      Inline::Del(children) => {
+        html! { del style=[self.style(STRIKE_STYLE)] { (self.render_inlines(children)) } }
+      }
+      Inline::Code(code) => html! { code style=[self.style(CODE_STYLE)] { (code) } },
+      Inline::Sup(children) => {
+        html! { sup style=[self.style(SUP_STYLE)] { (self.render_inlines(children)) } }
+      }
+      Inline::Sub(children) => {
+        html! { sub style=[self.style(SUB_STYLE)] { (self.render_inlines(children)) } }
+      }
+
+      // References are dropped along with the sections they point to
+      Inline::FootnoteRef(_) | Inline::EndnoteRef(_) if !self.options.include_footnotes => {
+        html! {}
+      }
+      Inline::CommentRef(_) if !self.options.include_comments => html! {},
 
       Inline::FootnoteRef(id) => {
         html! { sup { a href={ "#footnote-" (&id.0) } { (&id.0) } } }
@@ -235,3 +353,254 @@ impl HtmlRenderer {
     }
   }
 }
+
+fn alignment_style(alignment: TextAlignment) -> &'static str {
+  match alignment {
+    TextAlignment::Left => "text-align:left",
+    TextAlignment::Right => "text-align:right",
+    TextAlignment::Center => "text-align:center",
+    TextAlignment::Justify => "text-align:justify",
+  }
+}
+
+fn alignment_class(alignment: TextAlignment) -> &'static str {
+  match alignment {
+    TextAlignment::Left => "firecrawl-align-left",
+    TextAlignment::Right => "firecrawl-align-right",
+    TextAlignment::Center => "firecrawl-align-center",
+    TextAlignment::Justify => "firecrawl-align-justify",
+  }
+}
+
+/// Lowercases `text`, joins words with `-` and drops everything that isn't
+/// alphanumeric.
+fn slugify(text: &str) -> String {
+  text
+    .split_whitespace()
+    .map(|word| {
+      word
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect::<String>()
+    })
+    .filter(|word| !word.is_empty())
+    .collect::<Vec<_>>()
+    .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn document() -> Document {
+    Document {
+      blocks: vec![
+        Block::Paragraph(Paragraph {
+          kind: ParagraphKind::Heading(2),
+          inlines: vec![
+            Inline::Text("What is new in ".to_string()),
+            Inline::Em(vec![Inline::Text("v2.0".to_string())]),
+          ],
+          attrs: Default::default(),
+        }),
+        Block::Paragraph(Paragraph {
+          kind: ParagraphKind::Normal,
+          inlines: vec![
+            Inline::Text("Body".to_string()),
+            Inline::FootnoteRef(NoteId("1".to_string())),
+            Inline::CommentRef(CommentId("c1".to_string())),
+          ],
+          attrs: Default::default(),
+        }),
+      ],
+      metadata: DocumentMetadata::default(),
+      notes: vec![Note {
+        id: NoteId("1".to_string()),
+        kind: NoteKind::Footnote,
+        blocks: Vec::new(),
+      }],
+      comments: vec![Comment {
+        id: CommentId("c1".to_string()),
+        author_name: None,
+        author_initials: None,
+        date: None,
+        blocks: Vec::new(),
+      }],
+    }
+  }
+
+  #[test]
+  fn default_options_render_everything() {
+    let html = HtmlRenderer::new().render(&document());
+    assert!(html.contains("<h2>What is new in <em>v2.0</em></h2>"));
+    assert!(html.contains(r##"href="#footnote-1""##));
+    assert!(html.contains(r#"id="footnotes""#));
+    assert!(html.contains(r#"id="comments""#));
+  }
+
+  #[test]
+  fn options_control_heading_ids_notes_and_comments() {
+    let html = HtmlRenderer::with_options(HtmlRenderOptions {
+      include_comments: false,
+      include_footnotes: false,
+      heading_id_prefix: Some("heading".to_string()),
+      ..Default::default()
+    })
+    .render(&document());
+
+    assert!(html.contains(r#"<h2 id="heading-what-is-new-in-v20">"#));
+    assert!(html.contains("<p>Body</p>"));
+    assert!(!html.contains("footnote"));
+    assert!(!html.contains("comment"));
+  }
+
+  #[test]
+  fn wraps_captioned_images_in_figures() {
+    let mut doc = document();
+    doc.blocks = vec![
+      Block::Image(Image {
+        src: "https://example.com/a.png".to_string(),
+        alt: Some("A chart".to_string()),
+      }),
+      Block::Image(Image {
+        src: "https://example.com/b.png".to_string(),
+        alt: None,
+      }),
+    ];
+
+    let html = HtmlRenderer::new().render(&doc);
+    assert!(html.contains(
+      r#"<figure class="firecrawl-figure"><img class="firecrawl-image" src="https://example.com/a.png" alt="A chart"><figcaption>A chart</figcaption></figure>"#
+    ));
+    assert!(html.contains(r#"<img class="firecrawl-image" src="https://example.com/b.png">"#));
+
+    let html = HtmlRenderer::with_options(HtmlRenderOptions {
+      no_figure_wrap: true,
+      ..Default::default()
+    })
+    .render(&doc);
+    assert!(!html.contains("<figure"));
+    assert!(html
+      .contains(r#"<img class="firecrawl-image" src="https://example.com/a.png" alt="A chart">"#));
+  }
+
+  #[test]
+  fn disambiguates_duplicate_heading_ids() {
+    let heading = |text: &str| {
+      Block::Paragraph(Paragraph {
+        kind: ParagraphKind::Heading(2),
+        inlines: vec![Inline::Text(text.to_string())],
+        attrs: Default::default(),
+      })
+    };
+    let mut doc = document();
+    doc.blocks = vec![
+      heading("Usage"),
+      heading("Examples"),
+      heading("Usage"),
+      heading("Usage!"),
+    ];
+
+    let renderer = HtmlRenderer::with_options(HtmlRenderOptions {
+      heading_id_prefix: Some("sec".to_string()),
+      ..Default::default()
+    });
+
+    for _ in 0..2 {
+      let html = renderer.render(&doc);
+      assert!(html.contains(r#"<h2 id="sec-usage">Usage</h2>"#));
+      assert!(html.contains(r#"<h2 id="sec-examples">Examples</h2>"#));
+      assert!(html.contains(r#"<h2 id="sec-usage-2">Usage</h2>"#));
+      assert!(html.contains(r#"<h2 id="sec-usage-3">Usage!</h2>"#));
+    }
+  }
+
+  #[test]
+  fn inline_styles_are_emitted_as_attributes() {
+    let one = std::num::NonZeroU32::new(1).unwrap();
+    let mut doc = document();
+    doc.blocks = vec![
+      Block::Paragraph(Paragraph {
+        kind: ParagraphKind::Normal,
+        inlines: vec![
+          Inline::Strong(vec![Inline::Text("b".to_string())]),
+          Inline::Em(vec![Inline::Text("i".to_string())]),
+          Inline::Del(vec![Inline::Text("s".to_string())]),
+          Inline::Sup(vec![Inline::Text("2".to_string())]),
+          Inline::Sub(vec![Inline::Text("x".to_string())]),
+          Inline::Code("let x;".to_string()),
+        ],
+        attrs: Default::default(),
+      }),
+      Block::Table(Table {
+        rows: vec![TableRow {
+          cells: vec![TableCell {
+            blocks: vec![Block::Paragraph(Paragraph {
+              kind: ParagraphKind::Normal,
+              inlines: vec![Inline::Text("cell".to_string())],
+              attrs: Default::default(),
+            })],
+            colspan: one,
+            rowspan: one,
+          }],
+          kind: TableRowKind::Header,
+        }],
+      }),
+    ];
+
+    let plain = HtmlRenderer::new().render(&doc);
+    assert!(!plain.contains("style="));
+
+    let html = HtmlRenderer::with_options(HtmlRenderOptions {
+      inline_styles: true,
+      ..Default::default()
+    })
+    .render(&doc);
+    assert!(!html.contains("<style"));
+    for expected in [
+      r#"<strong style="font-weight:bold">b</strong>"#,
+      r#"<em style="font-style:italic">i</em>"#,
+      r#"<del style="text-decoration:line-through">s</del>"#,
+      r#"<sup style="vertical-align:super">2</sup>"#,
+      r#"<sub style="vertical-align:sub">x</sub>"#,
+      r#"<code style="font-family:monospace">let x;</code>"#,
+      r#"<table style="border-collapse:collapse;border:1px solid #ccc">"#,
+      r#"<th style="border:1px solid #ccc;padding:4px">cell</th>"#,
+    ] {
+      assert!(html.contains(expected), "missing {expected} in {html}");
+    }
+  }
+
+  #[test]
+  fn paragraph_alignment_renders_as_class_or_style() {
+    let mut doc = document();
+    doc.blocks = vec![
+      Block::Paragraph(Paragraph {
+        kind: ParagraphKind::Normal,
+        inlines: vec![Inline::Text("right".to_string())],
+        attrs: ParagraphAttrs {
+          alignment: Some(TextAlignment::Right),
+        },
+      }),
+      Block::Paragraph(Paragraph {
+        kind: ParagraphKind::Normal,
+        inlines: vec![Inline::Text("plain".to_string())],
+        attrs: Default::default(),
+      }),
+    ];
+
+    let html = HtmlRenderer::new().render(&doc);
+    assert!(!html.contains("style="));
+    assert!(html.contains(r#"<p class="firecrawl-align-right">right</p>"#));
+    assert!(html.contains("<p>plain</p>"));
+
+    let html = HtmlRenderer::with_options(HtmlRenderOptions {
+      inline_styles: true,
+      ..Default::default()
+    })
+    .render(&doc);
+    assert!(html.contains(r#"<p style="text-align:right">right</p>"#));
+    assert!(html.contains("<p>plain</p>"));
+  }
+}
@@ -0,0 +1,421 @@
+use crate::document::model::*;
+
+pub struct MarkdownRenderer;
+
+impl MarkdownRenderer {
+  pub fn new() -> Self {
+    Self
+  }
+
+  pub fn render(&self, document: &Document) -> String {
+    let mut out = self.render_blocks(&document.blocks);
+
+    let notes: Vec<String> = document
+      .notes
+      .iter()
+      .map(|note| {
+        let label = note_label(note.kind, &note.id);
+        let body = self.render_blocks(&note.blocks);
+        format!("[^{label}]: {}", indent_continuation(&body, "    "))
+      })
+      .collect();
+
+    if !notes.is_empty() {
+      if !out.is_empty() {
+        out.push_str("\n\n");
+      }
+      out.push_str(&notes.join("\n\n"));
+    }
+
+    out.push('\n');
+    out
+  }
+
+  fn render_blocks(&self, blocks: &[Block]) -> String {
+    blocks
+      .iter()
+      .map(|b| self.render_block(b))
+      .filter(|s| !s.is_empty())
+      .collect::<Vec<_>>()
+      .join("\n\n")
+  }
+
+  fn render_block(&self, block: &Block) -> String {
+    match block {
+      Block::Paragraph(p) => self.render_paragraph(p),
+      Block::Table(t) => self.render_table(t),
+      Block::List(l) => self.render_list(l),
+      Block::Image(i) => self.render_image(i),
+    }
+  }
+
+  fn render_paragraph(&self, p: &Paragraph) -> String {
+    let text = self.render_inlines(&p.inlines);
+    let text = text.trim();
+    if text.is_empty() {
+      return String::new();
+    }
+
+    match p.kind {
+      ParagraphKind::Normal => text.to_string(),
+      ParagraphKind::Heading(level) => {
+        let level = level.clamp(1, 6) as usize;
+        // headings can't span lines in Markdown
+        format!("{} {}", "#".repeat(level), text.replace("  \n", " "))
+      }
+      ParagraphKind::Blockquote => text
+        .lines()
+        .map(|line| format!("> {line}"))
+        .collect::<Vec<_>>()
+        .join("\n"),
+    }
+  }
+
+  fn render_list(&self, l: &List) -> String {
+    l.items
+      .iter()
+      .enumerate()
+      .map(|(i, item)| {
+        let marker = match l.list_type {
+          ListType::Ordered => format!("{}. ", i + 1),
+          ListType::Unordered => "- ".to_string(),
+        };
+        let body = self.render_list_item(item);
+        let indent = " ".repeat(marker.len());
+        format!("{marker}{}", indent_continuation(&body, &indent))
+      })
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+
+  fn render_list_item(&self, item: &ListItem) -> String {
+    // Keep a paragraph directly followed by its nested list tight, so the
+    // output reads as a single nested list rather than a loose one.
+    let mut out = String::new();
+    for (i, block) in item.blocks.iter().enumerate() {
+      let rendered = self.render_block(block);
+      if rendered.is_empty() {
+        continue;
+      }
+      if !out.is_empty() {
+        let tight = i > 0
+          && matches!(block, Block::List(_))
+          && matches!(item.blocks[i - 1], Block::Paragraph(_));
+        out.push_str(if tight { "\n" } else { "\n\n" });
+      }
+      out.push_str(&rendered);
+    }
+    out
+  }
+
+  fn render_table(&self, t: &Table) -> String {
+    if t.rows.is_empty() {
+      return String::new();
+    }
+
+    let columns = t
+      .rows
+      .iter()
+      .map(|r| {
+        r.cells
+          .iter()
+          .map(|c| c.colspan.get() as usize)
+          .sum::<usize>()
+      })
+      .max()
+      .unwrap_or(0)
+      .max(1);
+
+    // Pipe tables need exactly one header row; fall back to the first row.
+    let header_index = t
+      .rows
+      .iter()
+      .position(|r| matches!(r.kind, TableRowKind::Header))
+      .unwrap_or(0);
+
+    let mut lines = Vec::with_capacity(t.rows.len() + 1);
+    lines.push(self.render_table_row(&t.rows[header_index], columns));
+    lines.push(format!("|{}", " --- |".repeat(columns)));
+    for (i, row) in t.rows.iter().enumerate() {
+      if i != header_index {
+        lines.push(self.render_table_row(row, columns));
+      }
+    }
+    lines.join("\n")
+  }
+
+  fn render_table_row(&self, row: &TableRow, columns: usize) -> String {
+    let mut cells: Vec<String> = Vec::with_capacity(columns);
+    for cell in &row.cells {
+      cells.push(self.render_table_cell(cell));
+      for _ in 1..cell.colspan.get() {
+        cells.push(String::new());
+      }
+    }
+    cells.resize(columns.max(cells.len()), String::new());
+
+    let mut out = String::from("|");
+    for cell in cells {
+      out.push(' ');
+      out.push_str(&cell);
+      out.push_str(" |");
+    }
+    out
+  }
+
+  fn render_table_cell(&self, cell: &TableCell) -> String {
+    self
+      .render_blocks(&cell.blocks)
+      .replace('|', "\\|")
+      .replace("  \n", "<br>")
+      .replace("\n\n", "<br>")
+      .replace('\n', " ")
+  }
+
+  fn render_image(&self, i: &Image) -> String {
+    let alt = i.alt.as_deref().unwrap_or("");
+    format!("![{}]({})", escape_brackets(alt), i.src)
+  }
+
+  fn render_inlines(&self, inlines: &[Inline]) -> String {
+    inlines.iter().map(|i| self.render_inline(i)).collect()
+  }
+
+  fn render_inline(&self, inline: &Inline) -> String {
+    match inline {
+      Inline::Text(t) => t.clone(),
+      Inline::LineBreak => "  \n".to_string(),
+
+      Inline::Link { href, children } => {
+        format!("[{}]({})", self.render_inlines(children), href)
+      }
+
+      Inline::Strong(children) => wrap("**", &self.render_inlines(children)),
+      Inline::Em(children) => wrap("*", &self.render_inlines(children)),
+      Inline::Del(children) => wrap("~~", &self.render_inlines(children)),
+      Inline::Code(code) => render_code_span(code),
+      Inline::Sup(children) => format!("<sup>{}</sup>", self.render_inlines(children)),
+      Inline::Sub(children) => format!("<sub>{}</sub>", self.render_inlines(children)),
+
+      Inline::FootnoteRef(id) => format!("[^{}]", note_label(NoteKind::Footnote, id)),
+      Inline::EndnoteRef(id) => format!("[^{}]", note_label(NoteKind::Endnote, id)),
+      Inline::CommentRef(_) | Inline::Bookmark(_) => String::new(),
+    }
+  }
+}
+
+/// Wraps `text` in an emphasis delimiter, keeping surrounding whitespace
+/// outside of it (`** bold**` isn't recognised as emphasis).
+fn wrap(delim: &str, text: &str) -> String {
+  let trimmed = text.trim();
+  if trimmed.is_empty() {
+    return text.to_string();
+  }
+  let leading = &text[..text.len() - text.trim_start().len()];
+  let trailing = &text[text.trim_end().len()..];
+  format!("{leading}{delim}{trimmed}{delim}{trailing}")
+}
+
+fn render_code_span(code: &str) -> String {
+  let longest_run = code
+    .split(|c| c != '`')
+    .map(|run| run.len())
+    .max()
+    .unwrap_or(0);
+  let fence = "`".repeat(longest_run + 1);
+  if code.starts_with('`') || code.ends_with('`') {
+    format!("{fence} {code} {fence}")
+  } else {
+    format!("{fence}{code}{fence}")
+  }
+}
+
+fn note_label(kind: NoteKind, id: &NoteId) -> String {
+  let id: String = id
+    .0
+    .chars()
+    .map(|c| {
+      if c.is_whitespace() || c == ']' {
+        '-'
+      } else {
+        c
+      }
+    })
+    .collect();
+  match kind {
+    NoteKind::Footnote => id,
+    NoteKind::Endnote => format!("endnote-{id}"),
+  }
+}
+
+fn escape_brackets(s: &str) -> String {
+  s.replace('[', "\\[").replace(']', "\\]")
+}
+
+fn indent_continuation(s: &str, indent: &str) -> String {
+  let mut lines = s.lines();
+  let mut out = lines.next().unwrap_or("").to_string();
+  for line in lines {
+    out.push('\n');
+    if !line.is_empty() {
+      out.push_str(indent);
+      out.push_str(line);
+    }
+  }
+  out
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::num::NonZeroU32;
+
+  fn text(s: &str) -> Inline {
+    Inline::Text(s.to_string())
+  }
+
+  fn para(kind: ParagraphKind, inlines: Vec<Inline>) -> Block {
+    Block::Paragraph(Paragraph {
+      kind,
+      inlines,
+      attrs: Default::default(),
+    })
+  }
+
+  fn cell(s: &str, colspan: u32) -> TableCell {
+    TableCell {
+      blocks: vec![para(ParagraphKind::Normal, vec![text(s)])],
+      colspan: NonZeroU32::new(colspan).unwrap(),
+      rowspan: NonZeroU32::new(1).unwrap(),
+    }
+  }
+
+  fn document(blocks: Vec<Block>) -> Document {
+    Document {
+      blocks,
+      metadata: DocumentMetadata::default(),
+      notes: Vec::new(),
+      comments: Vec::new(),
+    }
+  }
+
+  fn render(blocks: Vec<Block>) -> String {
+    MarkdownRenderer::new().render(&document(blocks))
+  }
+
+  #[test]
+  fn renders_headings_on_a_single_line() {
+    assert_eq!(
+      render(vec![
+        para(ParagraphKind::Heading(2), vec![text("Intro")]),
+        para(
+          ParagraphKind::Heading(9),
+          vec![text("Split"), Inline::LineBreak, text("heading")]
+        ),
+        para(ParagraphKind::Blockquote, vec![text("quoted")]),
+      ]),
+      "## Intro\n\n###### Split heading\n\n> quoted\n"
+    );
+  }
+
+  #[test]
+  fn renders_nested_lists() {
+    let list = Block::List(List {
+      list_type: ListType::Ordered,
+      items: vec![
+        ListItem {
+          blocks: vec![
+            para(ParagraphKind::Normal, vec![text("first")]),
+            Block::List(List {
+              list_type: ListType::Unordered,
+              items: vec![ListItem {
+                blocks: vec![para(ParagraphKind::Normal, vec![text("inner")])],
+              }],
+            }),
+          ],
+        },
+        ListItem {
+          blocks: vec![para(ParagraphKind::Normal, vec![text("second")])],
+        },
+      ],
+    });
+
+    assert_eq!(render(vec![list]), "1. first\n   - inner\n2. second\n");
+  }
+
+  #[test]
+  fn renders_pipe_tables() {
+    let table = Block::Table(Table {
+      rows: vec![
+        TableRow {
+          cells: vec![cell("1", 1), cell("a|b", 1)],
+          kind: TableRowKind::Body,
+        },
+        TableRow {
+          cells: vec![cell("Wide", 2)],
+          kind: TableRowKind::Header,
+        },
+      ],
+    });
+
+    assert_eq!(
+      render(vec![table]),
+      "| Wide |  |\n| --- | --- |\n| 1 | a\\|b |\n"
+    );
+  }
+
+  #[test]
+  fn renders_links_images_and_emphasis() {
+    assert_eq!(
+      render(vec![
+        para(
+          ParagraphKind::Normal,
+          vec![
+            text("See "),
+            Inline::Link {
+              href: "https://example.com".to_string(),
+              children: vec![Inline::Strong(vec![text("docs ")])],
+            },
+            Inline::Em(vec![text("now")]),
+          ],
+        ),
+        Block::Image(Image {
+          src: "https://example.com/a.png".to_string(),
+          alt: Some("[chart]".to_string()),
+        }),
+      ]),
+      "See [**docs** ](https://example.com)*now*\n\n![\\[chart\\]](https://example.com/a.png)\n"
+    );
+  }
+
+  #[test]
+  fn escapes_code_spans_and_note_labels() {
+    let mut doc = document(vec![para(
+      ParagraphKind::Normal,
+      vec![
+        Inline::Code("a`b".to_string()),
+        text(" "),
+        Inline::Code("`tick".to_string()),
+        Inline::FootnoteRef(NoteId("note 1".to_string())),
+        Inline::EndnoteRef(NoteId("2".to_string())),
+      ],
+    )]);
+    doc.notes = vec![
+      Note {
+        id: NoteId("note 1".to_string()),
+        kind: NoteKind::Footnote,
+        blocks: vec![para(ParagraphKind::Normal, vec![text("Foot.")])],
+      },
+      Note {
+        id: NoteId("2".to_string()),
+        kind: NoteKind::Endnote,
+        blocks: vec![para(ParagraphKind::Normal, vec![text("End.")])],
+      },
+    ];
+
+    assert_eq!(
+      MarkdownRenderer::new().render(&doc),
+      "``a`b`` `` `tick ``[^note-1][^endnote-2]\n\n[^note-1]: Foot.\n\n[^endnote-2]: End.\n"
+    );
+  }
+}
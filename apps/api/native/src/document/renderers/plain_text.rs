@@ -0,0 +1,227 @@
+use crate::document::model::*;
+
+pub struct PlainTextRenderer;
+
+impl PlainTextRenderer {
+  pub fn new() -> Self {
+    Self
+  }
+
+  pub fn render(&self, document: &Document) -> String {
+    let mut sections = Vec::new();
+
+    let body = self.render_blocks(&document.blocks);
+    if !body.is_empty() {
+      sections.push(body);
+    }
+
+    for note in &document.notes {
+      let text = self.render_blocks(&note.blocks);
+      if !text.is_empty() {
+        sections.push(format!("[{}] {}", note.id.0, text));
+      }
+    }
+
+    sections.join("\n\n")
+  }
+
+  fn render_blocks(&self, blocks: &[Block]) -> String {
+    blocks
+      .iter()
+      .map(|b| self.render_block(b))
+      .filter(|s| !s.is_empty())
+      .collect::<Vec<_>>()
+      .join("\n\n")
+  }
+
+  pub fn render_block(&self, block: &Block) -> String {
+    match block {
+      Block::Paragraph(p) => self.render_inlines(&p.inlines),
+      Block::Table(t) => self.render_table(t),
+      Block::List(l) => self.render_list(l, 0),
+      Block::Image(i) => i
+        .alt
+        .as_deref()
+        .map(normalize_whitespace)
+        .unwrap_or_default(),
+    }
+  }
+
+  fn render_list(&self, l: &List, depth: usize) -> String {
+    let indent = "  ".repeat(depth);
+    let mut lines = Vec::new();
+    for item in &l.items {
+      let mut text_parts = Vec::new();
+      let mut nested = Vec::new();
+      for block in &item.blocks {
+        match block {
+          Block::List(sub) => nested.push(self.render_list(sub, depth + 1)),
+          other => text_parts.push(self.render_block(other)),
+        }
+      }
+      let text = normalize_whitespace(&text_parts.join(" "));
+      lines.push(format!("{indent}- {text}").trim_end().to_string());
+      lines.extend(nested.into_iter().filter(|s| !s.is_empty()));
+    }
+    lines.join("\n")
+  }
+
+  fn render_table(&self, t: &Table) -> String {
+    t.rows
+      .iter()
+      .map(|row| {
+        row
+          .cells
+          .iter()
+          .map(|cell| normalize_whitespace(&self.render_blocks(&cell.blocks)))
+          .collect::<Vec<_>>()
+          .join("\t")
+      })
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+
+  fn render_inlines(&self, inlines: &[Inline]) -> String {
+    let mut leaves = Vec::new();
+    collect_leaves(inlines, &mut leaves);
+    normalize_whitespace(&leaves.join(" "))
+  }
+}
+
+fn collect_leaves<'a>(inlines: &'a [Inline], out: &mut Vec<&'a str>) {
+  for inline in inlines {
+    match inline {
+      Inline::Text(t) | Inline::Code(t) => out.push(t),
+      Inline::Link { children, .. }
+      | Inline::Strong(children)
+      | Inline::Em(children)
+      | Inline::Del(children)
+      | Inline::Sup(children)
+      | Inline::Sub(children) => collect_leaves(children, out),
+      Inline::LineBreak
+      | Inline::FootnoteRef(_)
+      | Inline::EndnoteRef(_)
+      | Inline::CommentRef(_)
+      | Inline::Bookmark(_) => {}
+    }
+  }
+}
+
+fn normalize_whitespace(s: &str) -> String {
+  s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::num::NonZeroU32;
+
+  fn text(s: &str) -> Inline {
+    Inline::Text(s.to_string())
+  }
+
+  fn para(kind: ParagraphKind, inlines: Vec<Inline>) -> Block {
+    Block::Paragraph(Paragraph {
+      kind,
+      inlines,
+      attrs: Default::default(),
+    })
+  }
+
+  fn cell(s: &str) -> TableCell {
+    TableCell {
+      blocks: vec![para(ParagraphKind::Normal, vec![text(s)])],
+      colspan: NonZeroU32::new(1).unwrap(),
+      rowspan: NonZeroU32::new(1).unwrap(),
+    }
+  }
+
+  #[test]
+  fn renders_structure_without_markup() {
+    let document = Document {
+      blocks: vec![
+        para(ParagraphKind::Heading(1), vec![text("Report")]),
+        para(
+          ParagraphKind::Normal,
+          vec![
+            text("Some"),
+            Inline::Strong(vec![text("bold")]),
+            Inline::Em(vec![text("  text  ")]),
+            Inline::FootnoteRef(NoteId("1".to_string())),
+          ],
+        ),
+        Block::List(List {
+          list_type: ListType::Ordered,
+          items: vec![
+            ListItem {
+              blocks: vec![para(ParagraphKind::Normal, vec![text("first")])],
+            },
+            ListItem {
+              blocks: vec![para(
+                ParagraphKind::Normal,
+                vec![Inline::Code("second()".to_string())],
+              )],
+            },
+          ],
+        }),
+        Block::Table(Table {
+          rows: vec![
+            TableRow {
+              cells: vec![cell("a"), cell("b")],
+              kind: TableRowKind::Header,
+            },
+            TableRow {
+              cells: vec![cell("1"), cell("2")],
+              kind: TableRowKind::Body,
+            },
+          ],
+        }),
+      ],
+      metadata: DocumentMetadata::default(),
+      notes: vec![Note {
+        id: NoteId("1".to_string()),
+        kind: NoteKind::Footnote,
+        blocks: vec![para(ParagraphKind::Normal, vec![text("A footnote.")])],
+      }],
+      comments: Vec::new(),
+    };
+
+    let rendered = PlainTextRenderer::new().render(&document);
+
+    assert_eq!(
+      rendered,
+      "Report\n\nSome bold text\n\n- first\n- second()\n\na\tb\n1\t2\n\n[1] A footnote."
+    );
+    for artefact in ["<", ">", "**", "#", "`"] {
+      assert!(!rendered.contains(artefact), "unexpected {artefact:?}");
+    }
+  }
+
+  #[test]
+  fn nests_lists_with_indentation() {
+    let document = Document {
+      blocks: vec![Block::List(List {
+        list_type: ListType::Unordered,
+        items: vec![ListItem {
+          blocks: vec![
+            para(ParagraphKind::Normal, vec![text("outer")]),
+            Block::List(List {
+              list_type: ListType::Unordered,
+              items: vec![ListItem {
+                blocks: vec![para(ParagraphKind::Normal, vec![text("inner")])],
+              }],
+            }),
+          ],
+        }],
+      })],
+      metadata: DocumentMetadata::default(),
+      notes: Vec::new(),
+      comments: Vec::new(),
+    };
+
+    assert_eq!(
+      PlainTextRenderer::new().render(&document),
+      "- outer\n  - inner"
+    );
+  }
+}
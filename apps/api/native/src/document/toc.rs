@@ -0,0 +1,152 @@
+use crate::document::model::*;
+use serde_json::{json, Value};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TocEntry {
+  pub level: u8,
+  pub title: String,
+  pub bookmark_id: Option<BookmarkId>,
+}
+
+impl TocEntry {
+  pub fn to_json(&self) -> Value {
+    json!({
+      "level": self.level,
+      "title": self.title,
+      "bookmarkId": self.bookmark_id.as_ref().map(|b| b.0.as_str()),
+    })
+  }
+}
+
+/// Builds a table of contents from the document's heading paragraphs.
+pub fn extract_toc(doc: &Document) -> Vec<TocEntry> {
+  doc
+    .blocks
+    .iter()
+    .filter_map(|block| match block {
+      Block::Paragraph(Paragraph {
+        kind: ParagraphKind::Heading(level),
+        inlines,
+        ..
+      }) => {
+        let title = inline_text(inlines);
+        if title.is_empty() {
+          return None;
+        }
+        Some(TocEntry {
+          level: *level,
+          title,
+          bookmark_id: first_bookmark(inlines),
+        })
+      }
+      _ => None,
+    })
+    .collect()
+}
+
+pub fn toc_to_json(entries: &[TocEntry]) -> String {
+  Value::Array(entries.iter().map(TocEntry::to_json).collect()).to_string()
+}
+
+pub(crate) fn inline_text(inlines: &[Inline]) -> String {
+  fn collect(inlines: &[Inline], out: &mut String) {
+    for inline in inlines {
+      match inline {
+        Inline::Text(t) | Inline::Code(t) => out.push_str(t),
+        Inline::LineBreak => out.push(' '),
+        Inline::Link { children, .. }
+        | Inline::Strong(children)
+        | Inline::Em(children)
+        | Inline::Del(children)
+        | Inline::Sup(children)
+        | Inline::Sub(children) => collect(children, out),
+        Inline::FootnoteRef(_)
+        | Inline::EndnoteRef(_)
+        | Inline::CommentRef(_)
+        | Inline::Bookmark(_) => {}
+      }
+    }
+  }
+
+  let mut out = String::new();
+  collect(inlines, &mut out);
+  out.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn first_bookmark(inlines: &[Inline]) -> Option<BookmarkId> {
+  inlines.iter().find_map(|inline| match inline {
+    // Word drops a `_GoBack` bookmark at the last edit position
+    Inline::Bookmark(id) if id.0 != "_GoBack" => Some(id.clone()),
+    Inline::Link { children, .. }
+    | Inline::Strong(children)
+    | Inline::Em(children)
+    | Inline::Del(children)
+    | Inline::Sup(children)
+    | Inline::Sub(children) => first_bookmark(children),
+    _ => None,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn heading(level: u8, inlines: Vec<Inline>) -> Block {
+    Block::Paragraph(Paragraph {
+      kind: ParagraphKind::Heading(level),
+      inlines,
+      attrs: Default::default(),
+    })
+  }
+
+  fn text(s: &str) -> Inline {
+    Inline::Text(s.to_string())
+  }
+
+  #[test]
+  fn extract_toc_keeps_heading_levels_in_order() {
+    let doc = Document {
+      blocks: vec![
+        heading(
+          1,
+          vec![
+            Inline::Bookmark(BookmarkId("_GoBack".to_string())),
+            Inline::Bookmark(BookmarkId("_Toc1".to_string())),
+            text("Intro"),
+          ],
+        ),
+        Block::Paragraph(Paragraph {
+          kind: ParagraphKind::Normal,
+          inlines: vec![text("Body text")],
+          attrs: Default::default(),
+        }),
+        heading(2, vec![Inline::Strong(vec![text("Back")]), text("ground")]),
+        heading(3, vec![text("  Details"), Inline::LineBreak, text("more ")]),
+        heading(2, vec![Inline::LineBreak]),
+        heading(1, vec![text("Results")]),
+      ],
+      metadata: DocumentMetadata::default(),
+      notes: Vec::new(),
+      comments: Vec::new(),
+    };
+
+    let entry = |level, title: &str, bookmark: Option<&str>| TocEntry {
+      level,
+      title: title.to_string(),
+      bookmark_id: bookmark.map(|b| BookmarkId(b.to_string())),
+    };
+    assert_eq!(
+      extract_toc(&doc),
+      vec![
+        entry(1, "Intro", Some("_Toc1")),
+        entry(2, "Background", None),
+        entry(3, "Details more", None),
+        entry(1, "Results", None),
+      ]
+    );
+    assert_eq!(
+      toc_to_json(&extract_toc(&doc)[..1]),
+      r#"[{"bookmarkId":"_Toc1","level":1,"title":"Intro"}]"#
+    );
+  }
+}
@@ -140,8 +140,19 @@ macro_rules! insert_meta_property {
   };
 }
 
+/// Twitter Card tags, mapped to the camelCase keys they're returned under.
+const TWITTER_CARD_META: &[(&str, &str)] = &[
+  ("twitter:card", "twitterCard"),
+  ("twitter:title", "twitterTitle"),
+  ("twitter:description", "twitterDescription"),
+  ("twitter:image", "twitterImage"),
+  ("twitter:site", "twitterSite"),
+  ("twitter:creator", "twitterCreator"),
+];
+
 fn _extract_metadata(
   html: &str,
+  include_structured_data: bool,
 ) -> Result<HashMap<String, Value>, Box<dyn std::error::Error + Send + Sync>> {
   let document = parse_html().one(html);
   let mut out = HashMap::<String, Value>::new();
@@ -234,6 +245,26 @@ fn _extract_metadata(
   insert_meta_name!(out, document, "dc.date.created", "dcDateCreated");
   insert_meta_name!(out, document, "dcterms.created", "dcTermsCreated");
 
+  // Pages use both `name=` and `property=` for Twitter tags
+  for (meta_name, out_name) in TWITTER_CARD_META {
+    if let Some(content) = document
+      .select(&format!(
+        "meta[name=\"{meta_name}\"][content], meta[property=\"{meta_name}\"][content]"
+      ))
+      .map_err(|_| "Failed to select twitter meta")?
+      .next()
+      .and_then(|meta| {
+        meta
+          .attributes
+          .borrow()
+          .get("content")
+          .map(|x| x.to_string())
+      })
+    {
+      out.insert(out_name.to_string(), Value::String(content));
+    }
+  }
+
   for meta in document
     .select("meta")
     .map_err(|_| "Failed to select meta")?
@@ -292,6 +323,40 @@ fn _extract_metadata(
     }
   }
 
+  // Inserted after the generic meta loop, which only merges into String or
+  // Array values.
+  if let Some(canonical) = document
+    .select("link[rel~=\"canonical\"][href]")
+    .map_err(|_| "Failed to select canonical link")?
+    .next()
+    .and_then(|x| x.attributes.borrow().get("href").map(|x| x.to_string()))
+  {
+    out.insert("canonicalUrl".to_string(), Value::String(canonical));
+  }
+
+  let mut hreflang = serde_json::Map::new();
+  for link in document
+    .select("link[rel~=\"alternate\"][hreflang][href]")
+    .map_err(|_| "Failed to select hreflang links")?
+  {
+    let attrs = link.attributes.borrow();
+    if let (Some(lang), Some(href)) = (attrs.get("hreflang"), attrs.get("href")) {
+      hreflang
+        .entry(lang.to_string())
+        .or_insert_with(|| Value::String(href.to_string()));
+    }
+  }
+  if !hreflang.is_empty() {
+    out.insert("hreflang".to_string(), Value::Object(hreflang));
+  }
+
+  if include_structured_data {
+    let structured_data = _extract_json_scripts(&document, "application/ld+json");
+    if !structured_data.is_empty() {
+      out.insert("structuredData".to_string(), Value::Array(structured_data));
+    }
+  }
+
   // Backfill title from og:title, twitter:title, or meta[name="title"] if primary extraction failed
   if !out.contains_key("title") {
     let fallback_title = out
@@ -311,16 +376,20 @@ fn _extract_metadata(
   Ok(out)
 }
 
-/// Extract metadata from HTML document.
+/// Extract metadata from HTML document. JSON-LD blobs are added under
+/// `structuredData` when `include_structured_data` is set.
 #[napi]
-pub async fn extract_metadata(html: Option<String>) -> napi::Result<HashMap<String, Value>> {
+pub async fn extract_metadata(
+  html: Option<String>,
+  include_structured_data: Option<bool>,
+) -> napi::Result<HashMap<String, Value>> {
   task::spawn_blocking(move || {
     let html = match html {
       Some(h) => h,
       None => return Ok(HashMap::new()),
     };
 
-    _extract_metadata(&html).map_err(to_napi_err)
+    _extract_metadata(&html, include_structured_data.unwrap_or(false)).map_err(to_napi_err)
   })
   .await
   .map_err(|e| {
@@ -331,6 +400,123 @@ pub async fn extract_metadata(html: Option<String>) -> napi::Result<HashMap<Stri
   })?
 }
 
+fn _extract_json_scripts(document: &NodeRef, type_attr: &str) -> Vec<Value> {
+  let mut out = Vec::new();
+  let Ok(scripts) = document.select(&format!("script[type=\"{type_attr}\"]")) else {
+    return out;
+  };
+
+  for script in scripts {
+    let text = script.text_contents();
+    let text = text.trim();
+    if text.is_empty() {
+      continue;
+    }
+
+    // Invalid blobs are common (trailing commas, HTML comments), skip them.
+    match serde_json::from_str::<Value>(text) {
+      Ok(Value::Array(items)) => out.extend(items),
+      Ok(value) => out.push(value),
+      Err(_) => {}
+    }
+  }
+
+  out
+}
+
+/// JSON-LD blobs, falling back to plain `application/json` scripts when the
+/// page has none.
+fn _extract_structured_data(html: &str) -> Vec<Value> {
+  let document = parse_html().one(html);
+  let json_ld = _extract_json_scripts(&document, "application/ld+json");
+  if !json_ld.is_empty() {
+    return json_ld;
+  }
+  _extract_json_scripts(&document, "application/json")
+}
+
+/// Extract structured data (JSON-LD) from HTML document.
+#[napi]
+pub async fn extract_structured_data(html: Option<String>) -> napi::Result<Vec<Value>> {
+  task::spawn_blocking(move || match html {
+    Some(h) => _extract_structured_data(&h),
+    None => Vec::new(),
+  })
+  .await
+  .map_err(|e| {
+    napi::Error::new(
+      napi::Status::GenericFailure,
+      format!("extract_structured_data join error: {e}"),
+    )
+  })
+}
+
+/// `http://schema.org/Product` and `schema:Product` both map to `Product`.
+fn _schema_org_type_name(t: &str) -> &str {
+  t.rsplit(['/', ':', '#']).next().unwrap_or(t)
+}
+
+fn _collect_schema_org_entities(value: &Value, out: &mut HashMap<String, Vec<Value>>) {
+  match value {
+    Value::Array(items) => {
+      for item in items {
+        _collect_schema_org_entities(item, out);
+      }
+    }
+    Value::Object(map) => {
+      if let Some(graph) = map.get("@graph") {
+        _collect_schema_org_entities(graph, out);
+      }
+
+      let types: Vec<&str> = match map.get("@type") {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(ts)) => ts.iter().filter_map(|t| t.as_str()).collect(),
+        _ => return,
+      };
+
+      let mut entity = map.clone();
+      entity.remove("@context");
+      entity.remove("@graph");
+      let entity = Value::Object(entity);
+
+      for t in types {
+        let name = _schema_org_type_name(t.trim());
+        if !name.is_empty() {
+          out
+            .entry(name.to_string())
+            .or_default()
+            .push(entity.clone());
+        }
+      }
+    }
+    _ => {}
+  }
+}
+
+fn _extract_schema_org_entities(html: &str) -> HashMap<String, Vec<Value>> {
+  let document = parse_html().one(html);
+  let mut out = HashMap::new();
+  for blob in _extract_json_scripts(&document, "application/ld+json") {
+    _collect_schema_org_entities(&blob, &mut out);
+  }
+  out
+}
+
+/// Extract JSON-LD entities from HTML document, grouped by schema.org type.
+#[napi]
+pub async fn extract_schema_org_entities(
+  html: String,
+) -> napi::Result<HashMap<String, Vec<Value>>> {
+  task::spawn_blocking(move || _extract_schema_org_entities(&html))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("extract_schema_org_entities join error: {e}"),
+      )
+    })
+}
+
 const EXCLUDE_NON_MAIN_TAGS: [&str; 42] = [
   "header",
   "footer",
@@ -403,6 +589,29 @@ pub struct TransformHtmlOptions {
   pub exclude_tags: Vec<String>,
   pub only_main_content: bool,
   pub omce_signatures: Option<Vec<String>>,
+  /// Extra selectors dropped alongside `EXCLUDE_NON_MAIN_TAGS` when
+  /// `only_main_content` is set.
+  pub additional_exclude_tags: Option<Vec<String>>,
+  /// Extra selectors that, like `FORCE_INCLUDE_MAIN_TAGS`, keep an otherwise
+  /// excluded element when it contains a match.
+  pub force_include_tags: Option<Vec<String>>,
+  /// Drop low-content, link-heavy blocks using a text density score.
+  pub remove_boilerplate: Option<bool>,
+  /// Minimum score a block needs to survive `remove_boilerplate`.
+  pub boilerplate_threshold: Option<f64>,
+}
+
+/// Inline and script URLs are not relative references and must be left as-is.
+const UNRESOLVED_SRC_SCHEMES: [&str; 2] = ["data:", "blob:"];
+const UNRESOLVED_HREF_SCHEMES: [&str; 4] = ["javascript:", "mailto:", "tel:", "data:"];
+
+fn _has_scheme(value: &str, schemes: &[&str]) -> bool {
+  let value = value.trim_start();
+  schemes.iter().any(|scheme| {
+    value
+      .get(..scheme.len())
+      .is_some_and(|prefix| prefix.eq_ignore_ascii_case(scheme))
+  })
 }
 
 struct ImageSource {
@@ -411,6 +620,149 @@ struct ImageSource {
   is_x: bool,
 }
 
+fn _parse_srcset(srcset: &str) -> Vec<ImageSource> {
+  srcset
+    .split(',')
+    .filter_map(|x| {
+      let tok: Vec<&str> = x.trim().split(' ').collect();
+      let last_token = tok[tok.len() - 1];
+      let (last_token, last_token_used) = if tok.len() > 1
+        && !last_token.is_empty()
+        && (last_token.ends_with('x') || last_token.ends_with('w'))
+      {
+        (last_token, true)
+      } else {
+        ("1x", false)
+      };
+
+      if let Some((last_index, _)) = last_token.char_indices().last() {
+        if let Ok(parsed_size) = last_token[..last_index].parse() {
+          Some(ImageSource {
+            url: if last_token_used {
+              tok[0..tok.len() - 1].join(" ")
+            } else {
+              tok.join(" ")
+            },
+            size: parsed_size,
+            is_x: last_token.ends_with('x'),
+          })
+        } else {
+          None
+        }
+      } else {
+        None
+      }
+    })
+    .collect()
+}
+
+/// Collapse `<picture>` into its `<img>`, pointing `src` at the largest
+/// `<source>` candidate (highest `w` descriptor, else highest density).
+fn _flatten_pictures(document: &NodeRef) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+  let pictures: Vec<_> = document
+    .select("picture")
+    .map_err(|_| "Failed to select pictures")?
+    .collect();
+  for picture in pictures {
+    let Ok(img) = picture.as_node().select_first("img") else {
+      continue;
+    };
+
+    let mut candidates = Vec::new();
+    if let Ok(sources) = picture.as_node().select("source") {
+      for source in sources {
+        let attrs = source.attributes.borrow();
+        if let Some(srcset) = attrs
+          .get("srcset")
+          .filter(|x| !x.trim().is_empty())
+          .or_else(|| attrs.get("data-srcset").filter(|x| !x.trim().is_empty()))
+        {
+          candidates.extend(_parse_srcset(srcset));
+        }
+      }
+    }
+
+    let widths = candidates.iter().any(|x| !x.is_x);
+    let biggest = candidates
+      .into_iter()
+      .filter(|x| !widths || !x.is_x)
+      .max_by(|a, b| {
+        a.size
+          .partial_cmp(&b.size)
+          .unwrap_or(std::cmp::Ordering::Equal)
+      });
+
+    if let Some(biggest) = biggest {
+      let mut attrs = img.attributes.borrow_mut();
+      attrs.insert("src", biggest.url);
+      attrs.remove("srcset");
+      attrs.remove("data-srcset");
+      attrs.remove("sizes");
+    }
+
+    let img = img.as_node().clone();
+    img.detach();
+    picture.as_node().insert_before(img);
+    picture.as_node().detach();
+  }
+
+  Ok(())
+}
+
+const BOILERPLATE_BLOCK_TAGS: &str =
+  "div, section, aside, nav, header, footer, form, ul, ol, table";
+const DEFAULT_BOILERPLATE_THRESHOLD: f64 = 20.0;
+
+fn _text_len(node: &NodeRef) -> usize {
+  node
+    .text_contents()
+    .chars()
+    .filter(|c| !c.is_whitespace())
+    .count()
+}
+
+/// Readability-style pass: a block scores `text_length / (1 + link_density)`,
+/// where `link_density` is the share of its text inside `<a>`. Blocks scoring
+/// under `threshold` are dropped unless they hold `main`/`article` content.
+/// Blocks without any text are left alone so image-only wrappers survive.
+fn _remove_boilerplate(
+  document: &NodeRef,
+  threshold: f64,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+  let blocks: Vec<_> = document
+    .select(BOILERPLATE_BLOCK_TAGS)
+    .map_err(|_| "Failed to select boilerplate blocks")?
+    .collect();
+
+  let mut nodes_to_drop = Vec::new();
+  for block in blocks {
+    let node = block.as_node();
+    let text_len = _text_len(node);
+    if text_len == 0 {
+      continue;
+    }
+    if node.select_first("main, article").is_ok() {
+      continue;
+    }
+
+    let link_len: usize = node
+      .select("a")
+      .map(|links| links.map(|link| _text_len(link.as_node())).sum())
+      .unwrap_or(0);
+    let link_density = link_len.min(text_len) as f64 / text_len as f64;
+    let score = text_len as f64 / (1.0 + link_density);
+    if score < threshold {
+      nodes_to_drop.push(node.clone());
+    }
+  }
+
+  for node in nodes_to_drop {
+    node.detach();
+  }
+
+  Ok(())
+}
+
 fn _transform_html_inner(
   opts: TransformHtmlOptions,
 ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
@@ -506,13 +858,27 @@ fn _transform_html_inner(
   }
 
   if opts.only_main_content {
-    for x in EXCLUDE_NON_MAIN_TAGS.iter() {
-      let x: Vec<_> = document
-        .select(x)
-        .map_err(|_| "Failed to select tags")?
-        .collect();
+    let exclude_tags = EXCLUDE_NON_MAIN_TAGS.iter().copied().chain(
+      opts
+        .additional_exclude_tags
+        .iter()
+        .flatten()
+        .map(String::as_str),
+    );
+    let force_include_tags: Vec<&str> = FORCE_INCLUDE_MAIN_TAGS
+      .iter()
+      .copied()
+      .chain(opts.force_include_tags.iter().flatten().map(String::as_str))
+      .collect();
+
+    for x in exclude_tags {
+      // User-supplied selectors may be invalid; skip them like `exclude_tags`.
+      let Ok(x) = document.select(x) else {
+        continue;
+      };
+      let x: Vec<_> = x.collect();
       for tag in x {
-        if !FORCE_INCLUDE_MAIN_TAGS.iter().any(|x| {
+        if !force_include_tags.iter().any(|x| {
           tag
             .as_node()
             .select(x)
@@ -524,6 +890,17 @@ fn _transform_html_inner(
     }
   }
 
+  if opts.remove_boilerplate.unwrap_or(false) {
+    _remove_boilerplate(
+      &document,
+      opts
+        .boilerplate_threshold
+        .unwrap_or(DEFAULT_BOILERPLATE_THRESHOLD),
+    )?;
+  }
+
+  _flatten_pictures(&document)?;
+
   // Resolve images, including lazy-loaded ones. Many sites ship a tiny
   // placeholder in `src` and keep the real image in `data-srcset` / `data-src`,
   // swapping it in via JS only once the image scrolls into view. When the real
@@ -545,39 +922,7 @@ fn _transform_html_inner(
     };
 
     if let Some(srcset) = srcset {
-      let mut sizes: Vec<ImageSource> = srcset
-        .split(',')
-        .filter_map(|x| {
-          let tok: Vec<&str> = x.trim().split(' ').collect();
-          let last_token = tok[tok.len() - 1];
-          let (last_token, last_token_used) = if tok.len() > 1
-            && !last_token.is_empty()
-            && (last_token.ends_with('x') || last_token.ends_with('w'))
-          {
-            (last_token, true)
-          } else {
-            ("1x", false)
-          };
-
-          if let Some((last_index, _)) = last_token.char_indices().last() {
-            if let Ok(parsed_size) = last_token[..last_index].parse() {
-              Some(ImageSource {
-                url: if last_token_used {
-                  tok[0..tok.len() - 1].join(" ")
-                } else {
-                  tok.join(" ")
-                },
-                size: parsed_size,
-                is_x: last_token.ends_with('x'),
-              })
-            } else {
-              None
-            }
-          } else {
-            None
-          }
-        })
-        .collect();
+      let mut sizes = _parse_srcset(&srcset);
 
       if sizes.iter().all(|x| x.is_x) {
         if let Some(src) = img.attributes.borrow().get("src").map(|x| x.to_string()) {
@@ -628,6 +973,9 @@ fn _transform_html_inner(
       .get("src")
       .map(|x| x.to_string())
       .ok_or("Failed to get src")?;
+    if _has_scheme(&old, &UNRESOLVED_SRC_SCHEMES) {
+      continue;
+    }
     if let Ok(new) = url.join(&old) {
       img.attributes.borrow_mut().insert("src", new.to_string());
     }
@@ -644,6 +992,9 @@ fn _transform_html_inner(
       .get("href")
       .map(|x| x.to_string())
       .ok_or("Failed to get href")?;
+    if _has_scheme(&old, &UNRESOLVED_HREF_SCHEMES) {
+      continue;
+    }
     if let Ok(new) = url.join(&old) {
       anchor
         .attributes
@@ -956,46 +1307,95 @@ pub async fn extract_images(html: String, base_url: String) -> napi::Result<Vec<
   res.map_err(to_napi_err)
 }
 
-/// Process multi-line links in markdown.
-#[napi]
-pub async fn post_process_markdown(markdown: String) -> napi::Result<String> {
-  let res = task::spawn_blocking(move || {
-    let mut link_open_count = 0usize;
-    let mut out = String::with_capacity(markdown.len());
-
-    for ch in markdown.chars() {
-      match ch {
-        '[' => {
-          link_open_count += 1;
-        }
-        ']' => {
-          link_open_count = link_open_count.saturating_sub(1);
-        }
-        _ => {}
-      }
+fn _post_process_markdown(markdown: &str) -> String {
+  let markdown = fix_image_alt_text(markdown);
+  let mut link_open_count = 0usize;
+  let mut out = String::with_capacity(markdown.len());
+  let mut escaped = false;
 
-      let inside_link_content = link_open_count > 0;
-      if inside_link_content && ch == '\n' {
-        out.push('\\');
-        out.push('\n');
-      } else {
-        out.push(ch);
+  for ch in markdown.chars() {
+    match ch {
+      '[' if !escaped => {
+        link_open_count += 1;
       }
+      ']' if !escaped => {
+        link_open_count = link_open_count.saturating_sub(1);
+      }
+      _ => {}
     }
+    escaped = ch == '\\' && !escaped;
 
-    remove_skip_to_content_links(&out)
-  })
-  .await
-  .map_err(|e| {
-    napi::Error::new(
-      napi::Status::GenericFailure,
-      format!("post_process_markdown join error: {e}"),
-    )
-  })?;
+    let inside_link_content = link_open_count > 0;
+    if inside_link_content && ch == '\n' {
+      out.push('\\');
+      out.push('\n');
+    } else {
+      out.push(ch);
+    }
+  }
+
+  remove_skip_to_content_links(&out)
+}
+
+/// Process multi-line links in markdown.
+#[napi]
+pub async fn post_process_markdown(markdown: String) -> napi::Result<String> {
+  let res = task::spawn_blocking(move || _post_process_markdown(&markdown))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("post_process_markdown join error: {e}"),
+      )
+    })?;
 
   Ok(res)
 }
 
+fn _html_to_markdown(
+  html: String,
+  base_url: Option<String>,
+) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+  let html = match base_url {
+    Some(url) => _transform_html_inner(TransformHtmlOptions {
+      html,
+      url,
+      include_tags: vec![],
+      exclude_tags: vec![],
+      only_main_content: false,
+      omce_signatures: None,
+      additional_exclude_tags: None,
+      force_include_tags: None,
+      remove_boilerplate: None,
+      boilerplate_threshold: None,
+    })?,
+    None => html,
+  };
+
+  let converter = htmd::HtmlToMarkdown::builder()
+    .skip_tags(vec!["script", "style", "noscript"])
+    .build();
+  let markdown = converter.convert(&html)?;
+
+  Ok(_post_process_markdown(&markdown))
+}
+
+/// Convert HTML to Markdown. When `base_url` is given, relative URLs are
+/// resolved against it first.
+#[napi]
+pub async fn html_to_markdown(html: String, base_url: Option<String>) -> napi::Result<String> {
+  let res = task::spawn_blocking(move || _html_to_markdown(html, base_url))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("html_to_markdown join error: {e}"),
+      )
+    })?;
+
+  res.map_err(to_napi_err)
+}
+
 fn remove_skip_to_content_links(input: &str) -> String {
   const LABEL: &str = "Skip to Content";
   let bytes = input.as_bytes();
@@ -1038,3 +1438,2052 @@ fn remove_skip_to_content_links(input: &str) -> String {
 
   out
 }
+
+/// Escapes brackets inside image alt text (`![a [b] c](url)` becomes
+/// `![a \[b\] c](url)`) and replaces images without alt text by their bare URL.
+fn fix_image_alt_text(input: &str) -> String {
+  let mut out = String::with_capacity(input.len());
+  let mut rest = input;
+
+  while let Some(start) = rest.find("![") {
+    out.push_str(&rest[..start]);
+    let after = &rest[start + 2..];
+
+    let Some(alt_end) = after.find("](") else {
+      out.push_str(&rest[start..]);
+      return out;
+    };
+    let alt = &after[..alt_end];
+    let target = &after[alt_end + 2..];
+
+    // The destination may itself contain balanced parentheses.
+    let mut depth = 0usize;
+    let close = target.char_indices().find_map(|(i, ch)| match ch {
+      '(' => {
+        depth += 1;
+        None
+      }
+      ')' if depth == 0 => Some(i),
+      ')' => {
+        depth -= 1;
+        None
+      }
+      _ => None,
+    });
+    let Some(close) = close.filter(|_| !alt.contains('\n')) else {
+      out.push_str("![");
+      rest = after;
+      continue;
+    };
+    let target = &target[..close];
+
+    if alt.trim().is_empty() {
+      let url = target.split_whitespace().next().unwrap_or_default();
+      out.push_str(url.trim_start_matches('<').trim_end_matches('>'));
+    } else {
+      out.push_str("![");
+      let mut escaped = false;
+      for ch in alt.chars() {
+        if (ch == '[' || ch == ']') && !escaped {
+          out.push('\\');
+        }
+        out.push(ch);
+        escaped = ch == '\\' && !escaped;
+      }
+      out.push_str("](");
+      out.push_str(target);
+      out.push(')');
+    }
+
+    rest = &after[alt_end + 2 + close + 1..];
+  }
+
+  out.push_str(rest);
+  out
+}
+
+#[derive(Serialize)]
+#[napi(object)]
+pub struct TableData {
+  pub headers: Vec<String>,
+  pub rows: Vec<Vec<String>>,
+}
+
+const MAX_TABLE_COLSPAN: usize = 1000;
+
+fn _is_element_named(node: &NodeRef, name: &str) -> bool {
+  node
+    .as_element()
+    .is_some_and(|e| e.name.local.as_ref() == name)
+}
+
+fn _owning_table(node: &NodeRef) -> Option<NodeRef> {
+  node.ancestors().find(|a| _is_element_named(a, "table"))
+}
+
+fn _extract_tables(html: &str) -> Result<Vec<TableData>, Box<dyn std::error::Error + Send + Sync>> {
+  let document = parse_html().one(html);
+  let mut out = Vec::new();
+
+  let tables: Vec<_> = document
+    .select("table")
+    .map_err(|_| "Failed to select tables")?
+    .collect();
+
+  for table in tables {
+    let table = table.as_node();
+    if _owning_table(table).is_some() {
+      continue;
+    }
+
+    let mut header_row: Option<Vec<String>> = None;
+    let mut rows: Vec<Vec<String>> = Vec::new();
+
+    let trs = table
+      .select("tr")
+      .map_err(|_| "Failed to select table rows")?
+      .filter(|tr| _owning_table(tr.as_node()).as_ref() == Some(table));
+
+    for (index, tr) in trs.enumerate() {
+      let mut cells = Vec::new();
+      let mut has_th = false;
+
+      for cell in tr.as_node().children() {
+        let is_th = _is_element_named(&cell, "th");
+        if !is_th && !_is_element_named(&cell, "td") {
+          continue;
+        }
+        has_th |= is_th;
+
+        let text = cell
+          .text_contents()
+          .split_whitespace()
+          .collect::<Vec<_>>()
+          .join(" ");
+        let colspan = cell
+          .as_element()
+          .and_then(|e| {
+            e.attributes
+              .borrow()
+              .get("colspan")
+              .map(|x| x.trim().to_string())
+          })
+          .and_then(|x| x.parse::<usize>().ok())
+          .unwrap_or(1)
+          .clamp(1, MAX_TABLE_COLSPAN);
+
+        for _ in 0..colspan {
+          cells.push(text.clone());
+        }
+      }
+
+      if index == 0 && has_th {
+        header_row = Some(cells);
+      } else if !cells.is_empty() {
+        rows.push(cells);
+      }
+    }
+
+    let headers = header_row.unwrap_or_else(|| {
+      let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+      (0..width).map(|i| i.to_string()).collect()
+    });
+
+    if headers.is_empty() && rows.is_empty() {
+      continue;
+    }
+
+    out.push(TableData { headers, rows });
+  }
+
+  Ok(out)
+}
+
+/// Extract tabular data from all top-level `<table>` elements.
+#[napi]
+pub async fn extract_tables(html: String) -> napi::Result<Vec<TableData>> {
+  let res = task::spawn_blocking(move || _extract_tables(&html))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("extract_tables join error: {e}"),
+      )
+    })?;
+
+  res.map_err(to_napi_err)
+}
+
+#[derive(Serialize)]
+#[napi(object)]
+pub struct CodeBlock {
+  pub language: Option<String>,
+  pub content: String,
+}
+
+const CODE_LANGUAGE_CLASS_PREFIXES: [&str; 3] = ["language-", "lang-", "highlight-source-"];
+
+fn _code_language(node: &NodeRef) -> Option<String> {
+  let element = node.as_element()?;
+  let attributes = element.attributes.borrow();
+
+  if let Some(lang) = attributes
+    .get("data-lang")
+    .or_else(|| attributes.get("lang"))
+  {
+    let lang = lang.trim();
+    if !lang.is_empty() {
+      return Some(lang.to_lowercase());
+    }
+  }
+
+  attributes
+    .get("class")?
+    .split_whitespace()
+    .find_map(|class| {
+      CODE_LANGUAGE_CLASS_PREFIXES
+        .iter()
+        .find_map(|prefix| class.strip_prefix(prefix))
+    })
+    .filter(|lang| !lang.is_empty())
+    .map(|lang| lang.to_lowercase())
+}
+
+fn _extract_code_blocks(
+  html: &str,
+) -> Result<Vec<CodeBlock>, Box<dyn std::error::Error + Send + Sync>> {
+  let document = parse_html().one(html);
+  let mut out = Vec::new();
+
+  let nodes = document
+    .select("pre, code")
+    .map_err(|_| "Failed to select code blocks")?;
+
+  for node in nodes {
+    let node = node.as_node();
+    let in_pre = node.ancestors().any(|a| _is_element_named(&a, "pre"));
+    if in_pre {
+      continue;
+    }
+
+    let language = if _is_element_named(node, "pre") {
+      // GitHub renders fenced blocks as `<div class="highlight-source-rust"><pre>`,
+      // most other renderers as `<pre><code class="language-rust">`.
+      let code = node.children().find(|c| _is_element_named(c, "code"));
+      code
+        .as_ref()
+        .and_then(_code_language)
+        .or_else(|| _code_language(node))
+        .or_else(|| node.parent().as_ref().and_then(_code_language))
+    } else {
+      _code_language(node)
+    };
+
+    let content = node.text_contents();
+    let content = content.strip_suffix('\n').unwrap_or(&content);
+    if content.trim().is_empty() {
+      continue;
+    }
+
+    out.push(CodeBlock {
+      language,
+      content: content.to_string(),
+    });
+  }
+
+  Ok(out)
+}
+
+/// Extract `<pre>` and standalone `<code>` blocks with their language hints.
+#[napi]
+pub async fn extract_code_blocks(html: String) -> napi::Result<Vec<CodeBlock>> {
+  let res = task::spawn_blocking(move || _extract_code_blocks(&html))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("extract_code_blocks join error: {e}"),
+      )
+    })?;
+
+  res.map_err(to_napi_err)
+}
+
+#[derive(Serialize, Default)]
+#[napi(object)]
+pub struct PaginationLinks {
+  pub prev: Option<String>,
+  pub next: Option<String>,
+}
+
+fn _has_rel(node: &NodeRef, rels: &[&str]) -> bool {
+  node.as_element().is_some_and(|e| {
+    e.attributes.borrow().get("rel").is_some_and(|rel| {
+      rel
+        .split_whitespace()
+        .any(|r| rels.iter().any(|x| r.eq_ignore_ascii_case(x)))
+    })
+  })
+}
+
+fn _find_rel_href(
+  document: &NodeRef,
+  base: &Url,
+  rels: &[&str],
+) -> Result<Option<String>, Box<dyn std::error::Error + Send + Sync>> {
+  // <link> elements take precedence over <a> elements.
+  for selector in ["link[rel][href]", "a[rel][href]"] {
+    let href = document
+      .select(selector)
+      .map_err(|_| "Failed to select pagination links")?
+      .filter(|x| _has_rel(x.as_node(), rels))
+      .find_map(|x| {
+        let href = x.attributes.borrow().get("href")?.trim().to_string();
+        base.join(&href).ok()
+      });
+
+    if let Some(href) = href {
+      return Ok(Some(href.to_string()));
+    }
+  }
+
+  Ok(None)
+}
+
+fn _extract_pagination_links(
+  html: &str,
+  base_url: &str,
+) -> Result<PaginationLinks, Box<dyn std::error::Error + Send + Sync>> {
+  let document = parse_html().one(html);
+  let base = Url::parse(&_extract_base_href_from_document(
+    &document,
+    &Url::parse(base_url)?,
+  )?)?;
+
+  Ok(PaginationLinks {
+    prev: _find_rel_href(&document, &base, &["prev", "previous"])?,
+    next: _find_rel_href(&document, &base, &["next"])?,
+  })
+}
+
+/// Extract `rel="prev"` and `rel="next"` pagination links, resolved against `base_url`.
+#[napi]
+pub async fn extract_pagination_links(
+  html: String,
+  base_url: String,
+) -> napi::Result<PaginationLinks> {
+  let res = task::spawn_blocking(move || _extract_pagination_links(&html, &base_url))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("extract_pagination_links join error: {e}"),
+      )
+    })?;
+
+  res.map_err(to_napi_err)
+}
+
+const LANGUAGE_DETECTION_MIN_CONFIDENCE: f64 = 0.85;
+const LANGUAGE_DETECTION_MAX_CHARS: usize = 10_000;
+
+/// Maps whatlang's ISO 639-3 codes to ISO 639-1.
+fn _iso_639_1(code: &str) -> Option<&'static str> {
+  Some(match code {
+    "afr" => "af",
+    "aka" => "ak",
+    "amh" => "am",
+    "ara" => "ar",
+    "aze" => "az",
+    "bel" => "be",
+    "ben" => "bn",
+    "bul" => "bg",
+    "cat" => "ca",
+    "ces" => "cs",
+    "cmn" => "zh",
+    "dan" => "da",
+    "deu" => "de",
+    "ell" => "el",
+    "eng" => "en",
+    "epo" => "eo",
+    "est" => "et",
+    "fin" => "fi",
+    "fra" => "fr",
+    "guj" => "gu",
+    "heb" => "he",
+    "hin" => "hi",
+    "hrv" => "hr",
+    "hun" => "hu",
+    "hye" => "hy",
+    "ind" => "id",
+    "ita" => "it",
+    "jav" => "jv",
+    "jpn" => "ja",
+    "kan" => "kn",
+    "kat" => "ka",
+    "khm" => "km",
+    "kor" => "ko",
+    "lat" => "la",
+    "lav" => "lv",
+    "lit" => "lt",
+    "mal" => "ml",
+    "mar" => "mr",
+    "mkd" => "mk",
+    "mya" => "my",
+    "nep" => "ne",
+    "nld" => "nl",
+    "nob" => "nb",
+    "ori" => "or",
+    "pan" => "pa",
+    "pes" => "fa",
+    "pol" => "pl",
+    "por" => "pt",
+    "ron" => "ro",
+    "rus" => "ru",
+    "sin" => "si",
+    "slk" => "sk",
+    "slv" => "sl",
+    "sna" => "sn",
+    "spa" => "es",
+    "srp" => "sr",
+    "swe" => "sv",
+    "tam" => "ta",
+    "tel" => "te",
+    "tgl" => "tl",
+    "tha" => "th",
+    "tuk" => "tk",
+    "tur" => "tr",
+    "ukr" => "uk",
+    "urd" => "ur",
+    "uzb" => "uz",
+    "vie" => "vi",
+    "yid" => "yi",
+    "zul" => "zu",
+    _ => return None,
+  })
+}
+
+fn _detect_page_language(
+  html: &str,
+) -> Result<Option<String>, Box<dyn std::error::Error + Send + Sync>> {
+  let document = parse_html().one(html);
+
+  if let Some(lang) = document
+    .select("html[lang]")
+    .map_err(|_| "Failed to select lang")?
+    .next()
+    .and_then(|x| {
+      x.attributes
+        .borrow()
+        .get("lang")
+        .map(|x| x.trim().to_string())
+    })
+    .filter(|lang| !lang.is_empty())
+  {
+    return Ok(Some(lang));
+  }
+
+  let non_text: Vec<_> = document
+    .select("script, style, noscript, template")
+    .map_err(|_| "Failed to select non-text elements")?
+    .collect();
+  for node in non_text {
+    node.as_node().detach();
+  }
+
+  let text = document
+    .text_contents()
+    .split_whitespace()
+    .collect::<Vec<_>>()
+    .join(" ");
+  let text: String = text.chars().take(LANGUAGE_DETECTION_MAX_CHARS).collect();
+
+  Ok(
+    whatlang::detect(&text)
+      .filter(|info| info.confidence() >= LANGUAGE_DETECTION_MIN_CONFIDENCE)
+      .and_then(|info| _iso_639_1(info.lang().code()))
+      .map(str::to_string),
+  )
+}
+
+/// Detect the page language as an ISO 639-1 code, preferring the `<html lang>`
+/// attribute and falling back to statistical detection on the text content.
+#[napi]
+pub async fn detect_page_language(html: String) -> napi::Result<Option<String>> {
+  let res = task::spawn_blocking(move || _detect_page_language(&html))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("detect_page_language join error: {e}"),
+      )
+    })?;
+
+  res.map_err(to_napi_err)
+}
+
+#[derive(Serialize)]
+#[napi(object)]
+pub struct FeedLink {
+  pub title: Option<String>,
+  pub href: String,
+  pub feed_type: String,
+}
+
+const FEED_TYPES: [&str; 2] = ["application/rss+xml", "application/atom+xml"];
+
+fn _extract_rss_feeds(
+  html: &str,
+  base_url: &str,
+) -> Result<Vec<FeedLink>, Box<dyn std::error::Error + Send + Sync>> {
+  let document = parse_html().one(html);
+  let base = Url::parse(&_extract_base_href_from_document(
+    &document,
+    &Url::parse(base_url)?,
+  )?)?;
+
+  let mut seen = HashSet::new();
+  let mut out = Vec::new();
+
+  for link in document
+    .select("link[rel][type][href]")
+    .map_err(|_| "Failed to select feed links")?
+  {
+    if !_has_rel(link.as_node(), &["alternate"]) {
+      continue;
+    }
+
+    let attributes = link.attributes.borrow();
+    let feed_type = attributes
+      .get("type")
+      .unwrap_or_default()
+      .trim()
+      .to_lowercase();
+    if !FEED_TYPES.contains(&feed_type.as_str()) {
+      continue;
+    }
+
+    let Some(href) = attributes
+      .get("href")
+      .and_then(|href| base.join(href.trim()).ok())
+      .map(|href| href.to_string())
+    else {
+      continue;
+    };
+    if !seen.insert(href.clone()) {
+      continue;
+    }
+
+    out.push(FeedLink {
+      title: attributes
+        .get("title")
+        .map(|x| x.trim().to_string())
+        .filter(|x| !x.is_empty()),
+      href,
+      feed_type,
+    });
+  }
+
+  Ok(out)
+}
+
+/// Extract RSS and Atom feed links advertised via `<link rel="alternate">`.
+#[napi]
+pub async fn extract_rss_feeds(html: String, base_url: String) -> napi::Result<Vec<FeedLink>> {
+  let res = task::spawn_blocking(move || _extract_rss_feeds(&html, &base_url))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("extract_rss_feeds join error: {e}"),
+      )
+    })?;
+
+  res.map_err(to_napi_err)
+}
+
+#[derive(Serialize)]
+#[napi(object)]
+pub struct Heading {
+  pub level: u32,
+  pub text: String,
+  pub id: Option<String>,
+}
+
+fn _extract_headings(html: &str) -> Result<Vec<Heading>, Box<dyn std::error::Error + Send + Sync>> {
+  let document = parse_html().one(html);
+
+  let mut out = Vec::new();
+  for heading in document
+    .select("h1, h2, h3, h4, h5, h6")
+    .map_err(|_| "Failed to select headings")?
+  {
+    let level = match heading.name.local.as_ref() {
+      "h1" => 1,
+      "h2" => 2,
+      "h3" => 3,
+      "h4" => 4,
+      "h5" => 5,
+      _ => 6,
+    };
+    let text = _collapse_whitespace(&heading.text_contents());
+    let id = heading
+      .attributes
+      .borrow()
+      .get("id")
+      .map(|x| x.trim().to_string())
+      .filter(|x| !x.is_empty());
+
+    out.push(Heading { level, text, id });
+  }
+
+  Ok(out)
+}
+
+/// Returns the page's headings in document order.
+#[napi]
+pub async fn extract_headings(html: String) -> napi::Result<Vec<Heading>> {
+  let res = task::spawn_blocking(move || _extract_headings(&html))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("extract_headings join error: {e}"),
+      )
+    })?;
+
+  res.map_err(to_napi_err)
+}
+
+#[derive(Serialize)]
+#[napi(object)]
+pub struct BreadcrumbItem {
+  pub position: u32,
+  pub name: String,
+  pub url: Option<String>,
+}
+
+fn _collapse_whitespace(text: &str) -> String {
+  text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn _breadcrumb_position(value: Option<&Value>) -> Option<u32> {
+  match value? {
+    Value::Number(n) => n.as_u64().and_then(|n| u32::try_from(n).ok()),
+    Value::String(s) => s.trim().parse().ok(),
+    _ => None,
+  }
+}
+
+/// Items of the first non-empty JSON-LD `BreadcrumbList`. `item` may be either
+/// a bare URL or a `Thing` carrying `@id` and `name`.
+fn _json_ld_breadcrumbs(document: &NodeRef) -> Vec<BreadcrumbItem> {
+  let mut entities = HashMap::new();
+  for blob in _extract_json_scripts(document, "application/ld+json") {
+    _collect_schema_org_entities(&blob, &mut entities);
+  }
+
+  for list in entities.remove("BreadcrumbList").unwrap_or_default() {
+    let Some(Value::Array(elements)) = list.get("itemListElement") else {
+      continue;
+    };
+
+    let mut out = Vec::new();
+    for (i, element) in elements.iter().enumerate() {
+      let item = element.get("item");
+      let name = element
+        .get("name")
+        .or_else(|| item.and_then(|item| item.get("name")))
+        .and_then(|name| name.as_str())
+        .map(_collapse_whitespace)
+        .unwrap_or_default();
+      if name.is_empty() {
+        continue;
+      }
+
+      let url = match item {
+        Some(Value::String(url)) => Some(url.as_str()),
+        Some(item) => item
+          .get("@id")
+          .or_else(|| item.get("url"))
+          .and_then(|url| url.as_str()),
+        None => None,
+      }
+      .map(|url| url.trim().to_string())
+      .filter(|url| !url.is_empty());
+
+      out.push(BreadcrumbItem {
+        position: _breadcrumb_position(element.get("position")).unwrap_or(i as u32 + 1),
+        name,
+        url,
+      });
+    }
+
+    if !out.is_empty() {
+      return out;
+    }
+  }
+
+  Vec::new()
+}
+
+fn _first_href(node: &NodeRef) -> Option<String> {
+  let anchor = if _is_element_named(node, "a") {
+    node.clone()
+  } else {
+    node.select_first("a[href]").ok()?.as_node().clone()
+  };
+  let href = anchor
+    .as_element()?
+    .attributes
+    .borrow()
+    .get("href")?
+    .trim()
+    .to_string();
+  (!href.is_empty()).then_some(href)
+}
+
+/// Breadcrumbs marked up with microdata, or a plain `<nav aria-label="breadcrumb">`
+/// whose list items (or links, when there is no list) are the crumbs.
+fn _html_breadcrumbs(document: &NodeRef) -> Vec<BreadcrumbItem> {
+  let Ok(container) = document.select_first(
+    "[itemtype$=\"schema.org/BreadcrumbList\"], nav[aria-label=\"breadcrumb\"], \
+     nav[aria-label=\"Breadcrumb\"], nav[aria-label=\"breadcrumbs\"], \
+     nav[aria-label=\"Breadcrumbs\"]",
+  ) else {
+    return Vec::new();
+  };
+  let container = container.as_node();
+
+  let mut out = Vec::new();
+  if let Ok(elements) = container.select("[itemprop=\"itemListElement\"]") {
+    for (i, element) in elements.enumerate() {
+      let element = element.as_node();
+      let name = element
+        .select_first("[itemprop=\"name\"]")
+        .map(|name| name.text_contents())
+        .unwrap_or_else(|_| element.text_contents());
+      let name = _collapse_whitespace(&name);
+      if name.is_empty() {
+        continue;
+      }
+
+      let url = element
+        .select_first("[itemprop=\"item\"]")
+        .ok()
+        .and_then(|item| {
+          let attributes = item.attributes.borrow();
+          attributes
+            .get("href")
+            .or_else(|| attributes.get("itemid"))
+            .or_else(|| attributes.get("content"))
+            .map(|url| url.trim().to_string())
+        })
+        .filter(|url| !url.is_empty());
+      let position = element
+        .select_first("[itemprop=\"position\"]")
+        .ok()
+        .and_then(|position| {
+          let content = position
+            .attributes
+            .borrow()
+            .get("content")
+            .map(str::to_string);
+          content
+            .unwrap_or_else(|| position.text_contents())
+            .trim()
+            .parse()
+            .ok()
+        })
+        .unwrap_or(i as u32 + 1);
+
+      out.push(BreadcrumbItem {
+        position,
+        name,
+        url,
+      });
+    }
+  }
+  if !out.is_empty() {
+    return out;
+  }
+
+  let crumbs: Vec<NodeRef> = match container.select("li") {
+    Ok(items) => items.map(|item| item.as_node().clone()).collect(),
+    Err(_) => Vec::new(),
+  };
+  let crumbs = if crumbs.is_empty() {
+    match container.select("a") {
+      Ok(links) => links.map(|link| link.as_node().clone()).collect(),
+      Err(_) => Vec::new(),
+    }
+  } else {
+    crumbs
+  };
+
+  for crumb in crumbs {
+    let name = _collapse_whitespace(&crumb.text_contents());
+    if name.is_empty() {
+      continue;
+    }
+    out.push(BreadcrumbItem {
+      position: out.len() as u32 + 1,
+      name,
+      url: _first_href(&crumb),
+    });
+  }
+
+  out
+}
+
+fn _extract_breadcrumbs(html: &str) -> Vec<BreadcrumbItem> {
+  let document = parse_html().one(html);
+  let mut out = _json_ld_breadcrumbs(&document);
+  if out.is_empty() {
+    out = _html_breadcrumbs(&document);
+  }
+  out.sort_by_key(|item| item.position);
+  out
+}
+
+/// Extract breadcrumb trail from JSON-LD `BreadcrumbList`, falling back to
+/// microdata or a breadcrumb `<nav>`.
+#[napi]
+pub async fn extract_breadcrumbs(html: String) -> napi::Result<Vec<BreadcrumbItem>> {
+  task::spawn_blocking(move || _extract_breadcrumbs(&html))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("extract_breadcrumbs join error: {e}"),
+      )
+    })
+}
+
+#[derive(Serialize)]
+#[napi(object)]
+pub struct VideoSource {
+  pub src: String,
+  pub source_type: String,
+  pub poster: Option<String>,
+}
+
+const YOUTUBE_HOSTS: [&str; 3] = ["youtube.com", "youtube-nocookie.com", "youtu.be"];
+const VIMEO_HOSTS: [&str; 1] = ["vimeo.com"];
+
+fn _host_matches(url: &Url, hosts: &[&str]) -> bool {
+  url.host_str().is_some_and(|host| {
+    hosts
+      .iter()
+      .any(|x| host == *x || host.strip_suffix(x).is_some_and(|h| h.ends_with('.')))
+  })
+}
+
+/// `youtube` and `vimeo` by host, `fallback` for everything else.
+fn _video_source_type(url: &Url, fallback: &str) -> String {
+  if _host_matches(url, &YOUTUBE_HOSTS) {
+    "youtube".to_string()
+  } else if _host_matches(url, &VIMEO_HOSTS) {
+    "vimeo".to_string()
+  } else {
+    fallback.to_string()
+  }
+}
+
+fn _extract_video_sources(
+  html: &str,
+  base_url: &str,
+) -> Result<Vec<VideoSource>, Box<dyn std::error::Error + Send + Sync>> {
+  let document = parse_html().one(html);
+  let base = Url::parse(&_extract_base_href_from_document(
+    &document,
+    &Url::parse(base_url)?,
+  )?)?;
+  let resolve = |raw: Option<&str>| {
+    raw
+      .map(str::trim)
+      .filter(|x| !x.is_empty())
+      .and_then(|x| base.join(x).ok())
+  };
+
+  let mut seen = HashSet::new();
+  let mut out = Vec::new();
+  let mut push = |src: Url, source_type: String, poster: Option<String>| {
+    if seen.insert(src.to_string()) {
+      out.push(VideoSource {
+        src: src.to_string(),
+        source_type,
+        poster,
+      });
+    }
+  };
+
+  for video in document
+    .select("video")
+    .map_err(|_| "Failed to select videos")?
+  {
+    let (src, poster) = {
+      let attributes = video.attributes.borrow();
+      (
+        resolve(attributes.get("src")),
+        resolve(attributes.get("poster")).map(|x| x.to_string()),
+      )
+    };
+
+    let mut srcs: Vec<Url> = src.into_iter().collect();
+    if let Ok(sources) = video.as_node().select("source[src]") {
+      for source in sources {
+        srcs.extend(resolve(source.attributes.borrow().get("src")));
+      }
+    }
+
+    for src in srcs {
+      push(src, "direct".to_string(), poster.clone());
+    }
+  }
+
+  for iframe in document
+    .select("iframe[src]")
+    .map_err(|_| "Failed to select iframes")?
+  {
+    let Some(src) = resolve(iframe.attributes.borrow().get("src")) else {
+      continue;
+    };
+    let source_type = _video_source_type(&src, "iframe");
+    push(src, source_type, None);
+  }
+
+  // OpenGraph video metadata, as emitted alongside oEmbed by most video hosts.
+  for meta in document
+    .select(
+      "meta[property=\"og:video\"], meta[property=\"og:video:url\"], \
+       meta[property=\"og:video:secure_url\"]",
+    )
+    .map_err(|_| "Failed to select video meta tags")?
+  {
+    let Some(src) = resolve(meta.attributes.borrow().get("content")) else {
+      continue;
+    };
+    let source_type = _video_source_type(&src, "direct");
+    push(src, source_type, None);
+  }
+
+  Ok(out)
+}
+
+/// Extract embedded videos: `<video>` sources, YouTube/Vimeo and other
+/// iframes, and OpenGraph video URLs, resolved against `base_url`.
+#[napi]
+pub async fn extract_video_sources(
+  html: String,
+  base_url: String,
+) -> napi::Result<Vec<VideoSource>> {
+  let res = task::spawn_blocking(move || _extract_video_sources(&html, &base_url))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("extract_video_sources join error: {e}"),
+      )
+    })?;
+
+  res.map_err(to_napi_err)
+}
+
+#[derive(Serialize)]
+#[napi(object)]
+pub struct ArticleMetadata {
+  pub author: Option<String>,
+  pub published_date: Option<String>,
+  pub modified_date: Option<String>,
+  pub section: Option<String>,
+  pub tags: Vec<String>,
+}
+
+/// schema.org types consulted for JSON-LD article fields, most specific first.
+const ARTICLE_SCHEMA_TYPES: [&str; 6] = [
+  "NewsArticle",
+  "BlogPosting",
+  "TechArticle",
+  "ScholarlyArticle",
+  "Report",
+  "Article",
+];
+
+fn _meta_contents(document: &NodeRef, selector: &str) -> Vec<String> {
+  let Ok(metas) = document.select(selector) else {
+    return Vec::new();
+  };
+  metas
+    .filter_map(|meta| {
+      let content = meta.attributes.borrow().get("content")?.trim().to_string();
+      (!content.is_empty()).then_some(content)
+    })
+    .collect()
+}
+
+fn _first_meta_content(document: &NodeRef, selector: &str) -> Option<String> {
+  _meta_contents(document, selector).into_iter().next()
+}
+
+/// Non-empty strings from a JSON-LD value that may be a string, an object
+/// carrying `name`, or an array of either.
+fn _json_ld_names(value: &Value) -> Vec<String> {
+  let names = match value {
+    Value::String(s) => vec![s.trim().to_string()],
+    Value::Object(map) => map.get("name").map(_json_ld_names).unwrap_or_default(),
+    Value::Array(items) => items.iter().flat_map(_json_ld_names).collect(),
+    _ => Vec::new(),
+  };
+  names.into_iter().filter(|s| !s.is_empty()).collect()
+}
+
+fn _extract_article_metadata(html: &str) -> ArticleMetadata {
+  let document = parse_html().one(html);
+
+  let mut entities = HashMap::new();
+  for blob in _extract_json_scripts(&document, "application/ld+json") {
+    _collect_schema_org_entities(&blob, &mut entities);
+  }
+  let article = ARTICLE_SCHEMA_TYPES
+    .iter()
+    .find_map(|t| entities.get(*t).and_then(|x| x.first()));
+  let article_names = |key: &str| {
+    article
+      .and_then(|a| a.get(key))
+      .map(_json_ld_names)
+      .unwrap_or_default()
+  };
+
+  let author = _first_meta_content(&document, "meta[name=\"author\"]")
+    .or_else(|| article_names("author").into_iter().next())
+    .or_else(|| {
+      let link = document.select_first("a[rel~=\"author\"]").ok()?;
+      let name = _collapse_whitespace(&link.text_contents());
+      (!name.is_empty()).then_some(name)
+    });
+
+  let published_date = _first_meta_content(&document, "meta[property=\"article:published_time\"]")
+    .or_else(|| article_names("datePublished").into_iter().next())
+    .or_else(|| {
+      let time = document.select_first("time[datetime]").ok()?;
+      let datetime = time.attributes.borrow().get("datetime")?.trim().to_string();
+      (!datetime.is_empty()).then_some(datetime)
+    });
+
+  let modified_date = _first_meta_content(&document, "meta[property=\"article:modified_time\"]")
+    .or_else(|| article_names("dateModified").into_iter().next())
+    .or_else(|| _first_meta_content(&document, "meta[property=\"og:updated_time\"]"));
+
+  let section = _first_meta_content(&document, "meta[property=\"article:section\"]")
+    .or_else(|| article_names("articleSection").into_iter().next());
+
+  let mut seen = HashSet::new();
+  let tags = _meta_contents(&document, "meta[property=\"article:tag\"]")
+    .into_iter()
+    .chain(
+      article_names("keywords")
+        .iter()
+        .flat_map(|k| k.split(',').map(|x| x.trim().to_string()))
+        .collect::<Vec<_>>(),
+    )
+    .filter(|tag| !tag.is_empty() && seen.insert(tag.to_lowercase()))
+    .collect();
+
+  ArticleMetadata {
+    author,
+    published_date,
+    modified_date,
+    section,
+    tags,
+  }
+}
+
+/// Extract article author, dates, section and tags. OpenGraph `article:*` and
+/// `<meta>` tags win over JSON-LD, which wins over in-page markup.
+#[napi]
+pub async fn extract_article_metadata(html: String) -> napi::Result<ArticleMetadata> {
+  task::spawn_blocking(move || _extract_article_metadata(&html))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("extract_article_metadata join error: {e}"),
+      )
+    })
+}
+
+#[derive(Serialize)]
+#[napi(object)]
+pub struct LdJsonArticle {
+  pub headline: Option<String>,
+  pub author: Option<String>,
+  pub date_published: Option<String>,
+  pub date_modified: Option<String>,
+  pub description: Option<String>,
+  pub image: Option<String>,
+  pub url: Option<String>,
+}
+
+const LD_JSON_ARTICLE_TYPES: [&str; 4] = ["Article", "NewsArticle", "BlogPosting", "TechArticle"];
+
+/// A URL from a JSON-LD value that may be a string, an object carrying `url`,
+/// or an array of either.
+fn _json_ld_url(value: &Value) -> Option<String> {
+  match value {
+    Value::String(s) => Some(s.trim().to_string()).filter(|s| !s.is_empty()),
+    Value::Object(map) => map.get("url").and_then(_json_ld_url),
+    Value::Array(items) => items.iter().find_map(_json_ld_url),
+    _ => None,
+  }
+}
+
+fn _collect_ld_json_articles(value: &Value, out: &mut Vec<LdJsonArticle>) {
+  match value {
+    Value::Array(items) => {
+      for item in items {
+        _collect_ld_json_articles(item, out);
+      }
+    }
+    Value::Object(map) => {
+      if let Some(graph) = map.get("@graph") {
+        _collect_ld_json_articles(graph, out);
+      }
+
+      let is_article = match map.get("@type") {
+        Some(Value::String(t)) => LD_JSON_ARTICLE_TYPES.contains(&_schema_org_type_name(t.trim())),
+        Some(Value::Array(ts)) => ts
+          .iter()
+          .filter_map(|t| t.as_str())
+          .any(|t| LD_JSON_ARTICLE_TYPES.contains(&_schema_org_type_name(t.trim()))),
+        _ => false,
+      };
+      if !is_article {
+        return;
+      }
+
+      let text = |key: &str| match map.get(key) {
+        Some(Value::String(s)) => Some(s.trim().to_string()).filter(|s| !s.is_empty()),
+        _ => None,
+      };
+      let authors = map.get("author").map(_json_ld_names).unwrap_or_default();
+      out.push(LdJsonArticle {
+        headline: text("headline"),
+        author: (!authors.is_empty()).then(|| authors.join(", ")),
+        date_published: text("datePublished"),
+        date_modified: text("dateModified"),
+        description: text("description"),
+        image: map.get("image").and_then(_json_ld_url),
+        url: map.get("url").and_then(_json_ld_url),
+      });
+    }
+    _ => {}
+  }
+}
+
+fn _extract_ld_json_articles(html: &str) -> Vec<LdJsonArticle> {
+  let document = parse_html().one(html);
+  let mut out = Vec::new();
+  for blob in _extract_json_scripts(&document, "application/ld+json") {
+    _collect_ld_json_articles(&blob, &mut out);
+  }
+  out
+}
+
+/// Extract `Article`, `NewsArticle`, `BlogPosting` and `TechArticle` entries
+/// from JSON-LD in document order.
+#[napi]
+pub async fn extract_ld_json_articles(html: String) -> napi::Result<Vec<LdJsonArticle>> {
+  task::spawn_blocking(move || _extract_ld_json_articles(&html))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("extract_ld_json_articles join error: {e}"),
+      )
+    })
+}
+
+fn _extract_open_graph_images(
+  html: &str,
+  base_url: &str,
+) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>> {
+  let document = parse_html().one(html);
+  let base = Url::parse(&_extract_base_href_from_document(
+    &document,
+    &Url::parse(base_url)?,
+  )?)?;
+
+  let mut out = Vec::new();
+  for content in _meta_contents(
+    &document,
+    "meta[property=\"og:image\"], meta[property=\"og:image:url\"], \
+     meta[property=\"og:image:secure_url\"], meta[name=\"twitter:image\"], \
+     meta[property=\"twitter:image\"]",
+  ) {
+    let Ok(mut url) = base.join(&content) else {
+      continue;
+    };
+    // Same-site images are assumed to be served over HTTPS too.
+    if url.scheme() == "http" && base.scheme() == "https" && url.host() == base.host() {
+      let _ = url.set_scheme("https");
+    }
+    if matches!(url.scheme(), "http" | "https") {
+      out.push(url.to_string());
+    }
+  }
+
+  out.sort();
+  out.dedup();
+  Ok(out)
+}
+
+/// Extract OpenGraph and Twitter Card images as absolute URLs.
+#[napi]
+pub async fn extract_open_graph_images(
+  html: String,
+  base_url: String,
+) -> napi::Result<Vec<String>> {
+  let res = task::spawn_blocking(move || _extract_open_graph_images(&html, &base_url))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("extract_open_graph_images join error: {e}"),
+      )
+    })?;
+
+  res.map_err(to_napi_err)
+}
+
+fn _microdata_value(node: &NodeRef) -> Value {
+  let Some(element) = node.as_element() else {
+    return Value::Null;
+  };
+  let attributes = element.attributes.borrow();
+  if attributes.contains("itemscope") {
+    drop(attributes);
+    return _microdata_item(node);
+  }
+
+  let attr = match element.name.local.as_ref() {
+    "meta" => Some("content"),
+    "a" | "area" | "link" => Some("href"),
+    "audio" | "embed" | "iframe" | "img" | "source" | "track" | "video" => Some("src"),
+    "object" => Some("data"),
+    "data" | "meter" => Some("value"),
+    "time" => attributes.contains("datetime").then_some("datetime"),
+    _ => None,
+  };
+  let value = match attr {
+    Some(attr) => attributes.get(attr).unwrap_or_default().trim().to_string(),
+    None => _collapse_whitespace(&node.text_contents()),
+  };
+  Value::String(value)
+}
+
+fn _collect_microdata_props(node: &NodeRef, props: &mut serde_json::Map<String, Value>) {
+  for child in node.children() {
+    let Some(element) = child.as_element() else {
+      continue;
+    };
+    let (names, nested) = {
+      let attributes = element.attributes.borrow();
+      (
+        attributes.get("itemprop").map(str::to_string),
+        attributes.contains("itemscope"),
+      )
+    };
+
+    if let Some(names) = names {
+      let value = _microdata_value(&child);
+      for name in names.split_whitespace() {
+        match props.get_mut(name) {
+          Some(Value::Array(values)) => values.push(value.clone()),
+          Some(existing) => {
+            let first = existing.take();
+            *existing = Value::Array(vec![first, value.clone()]);
+          }
+          None => {
+            props.insert(name.to_string(), value.clone());
+          }
+        }
+      }
+    }
+    // Properties below a nested scope belong to that item, not this one.
+    if !nested {
+      _collect_microdata_props(&child, props);
+    }
+  }
+}
+
+fn _microdata_item(node: &NodeRef) -> Value {
+  let mut props = serde_json::Map::new();
+  let item_type = node.as_element().and_then(|element| {
+    element
+      .attributes
+      .borrow()
+      .get("itemtype")
+      .map(|t| t.trim().to_string())
+      .filter(|t| !t.is_empty())
+  });
+  if let Some(item_type) = item_type {
+    props.insert("@type".to_string(), Value::String(item_type));
+  }
+  _collect_microdata_props(node, &mut props);
+  Value::Object(props)
+}
+
+fn _extract_microdata(html: &str) -> Vec<Value> {
+  let document = parse_html().one(html);
+  let Ok(scopes) = document.select("[itemscope]:not([itemprop])") else {
+    return Vec::new();
+  };
+  scopes
+    .map(|scope| _microdata_item(scope.as_node()))
+    .collect()
+}
+
+/// Extract schema.org microdata items (`itemscope`/`itemprop`) as JSON
+/// objects, with `@type` taken from `itemtype`.
+#[napi]
+pub async fn extract_microdata(html: String) -> napi::Result<Vec<Value>> {
+  task::spawn_blocking(move || _extract_microdata(&html))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("extract_microdata join error: {e}"),
+      )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|s| s.to_string()).collect()
+  }
+
+  #[test]
+  fn extract_tables_reads_thead_and_tbody() {
+    let html = r#"<table>
+      <thead><tr><th>Name</th><th>Price</th></tr></thead>
+      <tbody>
+        <tr><td>Apple</td><td> 1.00 </td></tr>
+        <tr><td>Pear</td><td>2.50</td></tr>
+      </tbody>
+    </table>"#;
+
+    let tables = _extract_tables(html).unwrap();
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].headers, strings(&["Name", "Price"]));
+    assert_eq!(
+      tables[0].rows,
+      vec![strings(&["Apple", "1.00"]), strings(&["Pear", "2.50"])]
+    );
+  }
+
+  #[test]
+  fn extract_tables_repeats_colspan_values() {
+    let html = r#"<table>
+      <tr><th colspan="2">Range</th><th>Unit</th></tr>
+      <tr><td>1</td><td>10</td><td>kg</td></tr>
+      <tr><td colspan="3">n/a</td></tr>
+    </table>"#;
+
+    let tables = _extract_tables(html).unwrap();
+    assert_eq!(tables[0].headers, strings(&["Range", "Range", "Unit"]));
+    assert_eq!(
+      tables[0].rows,
+      vec![strings(&["1", "10", "kg"]), strings(&["n/a", "n/a", "n/a"])]
+    );
+  }
+
+  #[test]
+  fn extract_tables_without_header_row_uses_indices() {
+    let html = r#"<table>
+      <tr><td>a</td><td>b</td></tr>
+      <tr><td>c</td><td>d</td><td>e</td></tr>
+    </table>"#;
+
+    let tables = _extract_tables(html).unwrap();
+    assert_eq!(tables[0].headers, strings(&["0", "1", "2"]));
+    assert_eq!(
+      tables[0].rows,
+      vec![strings(&["a", "b"]), strings(&["c", "d", "e"])]
+    );
+  }
+
+  #[test]
+  fn extract_tables_ignores_nested_tables() {
+    let html = r#"<table>
+      <tr><th>Outer</th></tr>
+      <tr><td><table><tr><td>inner</td></tr></table></td></tr>
+    </table>"#;
+
+    let tables = _extract_tables(html).unwrap();
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].headers, strings(&["Outer"]));
+    assert_eq!(tables[0].rows, vec![strings(&["inner"])]);
+  }
+
+  #[test]
+  fn extract_schema_org_entities_groups_by_type() {
+    let html = r#"<html><head>
+      <script type="application/ld+json">
+        {"@context": "https://schema.org", "@type": "Product", "name": "Lamp",
+         "offers": {"@type": "Offer", "price": "19.99"}}
+      </script>
+      <script type="application/ld+json">
+        {"@context": "https://schema.org", "@graph": [
+          {"@type": "Article", "headline": "News", "author": "Jo"},
+          {"@type": ["Product", "schema:Thing"], "name": "Desk"}
+        ]}
+      </script>
+    </head></html>"#;
+
+    let entities = _extract_schema_org_entities(html);
+
+    let products = &entities["Product"];
+    assert_eq!(products.len(), 2);
+    assert_eq!(products[0]["name"], "Lamp");
+    assert_eq!(products[0]["offers"]["price"], "19.99");
+    assert!(products[0].get("@context").is_none());
+    assert_eq!(products[1]["name"], "Desk");
+
+    assert_eq!(entities["Article"][0]["author"], "Jo");
+    assert_eq!(entities["Thing"].len(), 1);
+    assert!(!entities.contains_key("Offer"));
+  }
+
+  #[test]
+  fn html_to_markdown_resolves_relative_links() {
+    let html = r#"<html><body>
+      <h1>Title</h1>
+      <p>See <a href="/docs">the docs</a>.</p>
+      <script>var x = 1;</script>
+    </body></html>"#;
+
+    let markdown = _html_to_markdown(
+      html.to_string(),
+      Some("https://example.com/page".to_string()),
+    )
+    .unwrap();
+
+    assert!(markdown.contains("# Title"));
+    assert!(markdown.contains("[the docs](https://example.com/docs)"));
+    assert!(!markdown.contains("var x"));
+  }
+
+  #[test]
+  fn post_process_markdown_escapes_multiline_links() {
+    let markdown = "[Skip to Content](#main)[line one\nline two](https://example.com)";
+    assert_eq!(
+      _post_process_markdown(markdown),
+      "[line one\\\nline two](https://example.com)"
+    );
+  }
+
+  #[test]
+  fn post_process_markdown_escapes_brackets_in_image_alt_text() {
+    assert_eq!(
+      _post_process_markdown("See ![alt with [nested] brackets](https://example.com/a.png) here"),
+      "See ![alt with \\[nested\\] brackets](https://example.com/a.png) here"
+    );
+    assert_eq!(
+      _post_process_markdown("![unbalanced [alt](/b.png)\n[next\nline](/c)"),
+      "![unbalanced \\[alt](/b.png)\n[next\\\nline](/c)"
+    );
+    assert_eq!(
+      _post_process_markdown("![already \\[escaped\\]](/d.png)"),
+      "![already \\[escaped\\]](/d.png)"
+    );
+  }
+
+  #[test]
+  fn post_process_markdown_replaces_images_without_alt_text() {
+    assert_eq!(
+      _post_process_markdown(
+        "Logo: ![](https://example.com/logo.png \"Logo\") and ![ ](/x_(1).png)"
+      ),
+      "Logo: https://example.com/logo.png and /x_(1).png"
+    );
+    assert_eq!(
+      _post_process_markdown("Not an image: ![broken"),
+      "Not an image: ![broken"
+    );
+  }
+
+  #[test]
+  fn extract_code_blocks_reads_language_hints() {
+    let html = r#"<article>
+      <pre><code class="language-rust">fn main() {}
+</code></pre>
+      <div class="highlight highlight-source-python notranslate"><pre><span class="pl-k">print</span>("hi")</pre></div>
+      <p>Run <code>cargo test</code> first.</p>
+      <pre lang="shell"><code>ls -la</code></pre>
+      <pre><code></code></pre>
+    </article>"#;
+
+    let blocks = _extract_code_blocks(html).unwrap();
+    let found: Vec<_> = blocks
+      .iter()
+      .map(|b| (b.language.as_deref(), b.content.as_str()))
+      .collect();
+
+    assert_eq!(
+      found,
+      vec![
+        (Some("rust"), "fn main() {}"),
+        (Some("python"), "print(\"hi\")"),
+        (None, "cargo test"),
+        (Some("shell"), "ls -la"),
+      ]
+    );
+  }
+
+  #[test]
+  fn extract_pagination_links_prefers_link_elements() {
+    let html = r#"<html><head>
+      <link rel="next" href="/blog?page=3">
+    </head><body>
+      <a rel="prev nofollow" href="?page=1">Previous</a>
+      <a rel="next" href="/ignored">Next</a>
+    </body></html>"#;
+
+    let links = _extract_pagination_links(html, "https://example.com/blog?page=2").unwrap();
+
+    assert_eq!(
+      links.next.as_deref(),
+      Some("https://example.com/blog?page=3")
+    );
+    assert_eq!(
+      links.prev.as_deref(),
+      Some("https://example.com/blog?page=1")
+    );
+
+    let links = _extract_pagination_links("<p>No pages</p>", "https://example.com").unwrap();
+    assert!(links.prev.is_none() && links.next.is_none());
+  }
+
+  #[test]
+  fn detect_page_language_uses_attribute_then_text() {
+    let html = r#"<html lang="pt-BR"><body><p>Hello world</p></body></html>"#;
+    assert_eq!(
+      _detect_page_language(html).unwrap().as_deref(),
+      Some("pt-BR")
+    );
+
+    let html = r#"<html><body>
+      <script>var ignored = "this is not page text";</script>
+      <p>Der schnelle braune Fuchs springt über den faulen Hund. Die Katze schläft
+      den ganzen Tag auf dem Sofa, während draußen die Sonne scheint und die Vögel
+      in den Bäumen singen.</p>
+    </body></html>"#;
+    assert_eq!(_detect_page_language(html).unwrap().as_deref(), Some("de"));
+
+    assert_eq!(_detect_page_language("<p>42</p>").unwrap(), None);
+  }
+
+  #[test]
+  fn extract_rss_feeds_finds_rss_and_atom() {
+    let html = r#"<html><head>
+      <link rel="alternate" type="application/rss+xml" title="Blog" href="/feed.xml">
+      <link rel="alternate" type="application/atom+xml" href="https://example.com/atom">
+      <link rel="alternate" type="application/rss+xml" href="feed.xml">
+      <link rel="alternate" hreflang="de" type="text/html" href="/de">
+      <link rel="stylesheet" type="application/rss+xml" href="/not-a-feed">
+    </head></html>"#;
+
+    let feeds = _extract_rss_feeds(html, "https://example.com/").unwrap();
+
+    assert_eq!(feeds.len(), 2);
+    assert_eq!(feeds[0].title.as_deref(), Some("Blog"));
+    assert_eq!(feeds[0].href, "https://example.com/feed.xml");
+    assert_eq!(feeds[0].feed_type, "application/rss+xml");
+    assert_eq!(feeds[1].title, None);
+    assert_eq!(feeds[1].href, "https://example.com/atom");
+    assert_eq!(feeds[1].feed_type, "application/atom+xml");
+  }
+
+  #[test]
+  fn extract_metadata_maps_twitter_card_tags() {
+    let html = r#"<html><head>
+      <meta name="twitter:card" content="summary_large_image">
+      <meta property="twitter:title" content="Twitter Title">
+      <meta name="twitter:description" content="Twitter Description">
+      <meta name="twitter:image" content="https://example.com/card.png">
+      <meta name="twitter:site" content="@firecrawl">
+      <meta property="twitter:creator" content="@someone">
+    </head><body></body></html>"#;
+
+    let metadata = _extract_metadata(html, false).unwrap();
+    assert_eq!(metadata["twitterCard"], "summary_large_image");
+    assert_eq!(metadata["twitterTitle"], "Twitter Title");
+    assert_eq!(metadata["twitterDescription"], "Twitter Description");
+    assert_eq!(metadata["twitterImage"], "https://example.com/card.png");
+    assert_eq!(metadata["twitterSite"], "@firecrawl");
+    assert_eq!(metadata["twitterCreator"], "@someone");
+    // The raw tag names are still returned by the generic meta loop
+    assert_eq!(metadata["twitter:card"], "summary_large_image");
+  }
+
+  #[test]
+  fn extract_metadata_hreflang_survives_conflicting_meta() {
+    let html = r#"<html><head>
+      <link rel="canonical" href="https://example.com/en">
+      <link rel="alternate" hreflang="en" href="https://example.com/en">
+      <link rel="alternate" hreflang="de" href="https://example.com/de">
+      <meta name="hreflang" content="fr">
+      <meta name="canonicalUrl" content="https://example.com/other">
+    </head><body></body></html>"#;
+
+    let metadata = _extract_metadata(html, false).unwrap();
+    assert_eq!(metadata["hreflang"]["en"], "https://example.com/en");
+    assert_eq!(metadata["hreflang"]["de"], "https://example.com/de");
+    assert_eq!(metadata["canonicalUrl"], "https://example.com/en");
+  }
+
+  #[test]
+  fn extract_metadata_structured_data_is_opt_in() {
+    let html = r#"<html><head>
+      <script type="application/ld+json">{"@type": "Article", "headline": "Hi"}</script>
+    </head><body></body></html>"#;
+
+    assert!(!_extract_metadata(html, false)
+      .unwrap()
+      .contains_key("structuredData"));
+
+    let metadata = _extract_metadata(html, true).unwrap();
+    assert_eq!(metadata["structuredData"][0]["headline"], "Hi");
+  }
+
+  #[test]
+  fn extract_headings_returns_levels_text_and_ids() {
+    let html = r#"<html><body>
+      <h1 id="top">Guide</h1>
+      <section>
+        <h2>Getting <a href="/start">started</a></h2>
+        <div><h3 id=" install ">  Install
+          the CLI </h3></div>
+      </section>
+      <h6 id="">Fine print</h6>
+    </body></html>"#;
+
+    let headings = _extract_headings(html).unwrap();
+    let summary: Vec<(u32, &str, Option<&str>)> = headings
+      .iter()
+      .map(|h| (h.level, h.text.as_str(), h.id.as_deref()))
+      .collect();
+
+    assert_eq!(
+      summary,
+      vec![
+        (1, "Guide", Some("top")),
+        (2, "Getting started", None),
+        (3, "Install the CLI", Some("install")),
+        (6, "Fine print", None),
+      ]
+    );
+  }
+
+  fn breadcrumb_summary(items: &[BreadcrumbItem]) -> Vec<(u32, &str, Option<&str>)> {
+    items
+      .iter()
+      .map(|i| (i.position, i.name.as_str(), i.url.as_deref()))
+      .collect()
+  }
+
+  #[test]
+  fn extract_breadcrumbs_reads_json_ld_in_position_order() {
+    let html = r#"<html><head>
+      <script type="application/ld+json">
+      {
+        "@context": "https://schema.org",
+        "@type": "BreadcrumbList",
+        "itemListElement": [
+          { "@type": "ListItem", "position": 3, "name": "Widgets" },
+          { "@type": "ListItem", "position": 1,
+            "item": { "@id": "https://example.com/", "name": "Home" } },
+          { "@type": "ListItem", "position": "2", "name": "Shop",
+            "item": "https://example.com/shop" }
+        ]
+      }
+      </script>
+    </head><body>
+      <nav aria-label="breadcrumb"><a href="/ignored">Ignored</a></nav>
+    </body></html>"#;
+
+    let items = _extract_breadcrumbs(html);
+    assert_eq!(
+      breadcrumb_summary(&items),
+      vec![
+        (1, "Home", Some("https://example.com/")),
+        (2, "Shop", Some("https://example.com/shop")),
+        (3, "Widgets", None),
+      ]
+    );
+  }
+
+  #[test]
+  fn extract_breadcrumbs_falls_back_to_microdata() {
+    let html = r#"<ol itemscope itemtype="https://schema.org/BreadcrumbList">
+      <li itemprop="itemListElement" itemscope itemtype="https://schema.org/ListItem">
+        <a itemprop="item" href="/docs"><span itemprop="name">Docs</span></a>
+        <meta itemprop="position" content="1">
+      </li>
+      <li itemprop="itemListElement" itemscope itemtype="https://schema.org/ListItem">
+        <span itemprop="name">Install</span>
+        <meta itemprop="position" content="2">
+      </li>
+    </ol>"#;
+
+    let items = _extract_breadcrumbs(html);
+    assert_eq!(
+      breadcrumb_summary(&items),
+      vec![(1, "Docs", Some("/docs")), (2, "Install", None)]
+    );
+  }
+
+  #[test]
+  fn extract_breadcrumbs_falls_back_to_nav() {
+    let html = r#"<nav aria-label="breadcrumb"><ol>
+      <li><a href="/">Home</a></li>
+      <li><a href="/blog">Blog</a></li>
+      <li aria-current="page">Release notes</li>
+    </ol></nav>"#;
+
+    let items = _extract_breadcrumbs(html);
+    assert_eq!(
+      breadcrumb_summary(&items),
+      vec![
+        (1, "Home", Some("/")),
+        (2, "Blog", Some("/blog")),
+        (3, "Release notes", None),
+      ]
+    );
+
+    assert!(_extract_breadcrumbs("<html><body><p>No trail</p></body></html>").is_empty());
+  }
+
+  fn transform_opts(html: &str) -> TransformHtmlOptions {
+    TransformHtmlOptions {
+      html: html.to_string(),
+      url: "https://example.com/page".to_string(),
+      include_tags: vec![],
+      exclude_tags: vec![],
+      only_main_content: false,
+      omce_signatures: None,
+      additional_exclude_tags: None,
+      force_include_tags: None,
+      remove_boilerplate: None,
+      boilerplate_threshold: None,
+    }
+  }
+
+  #[test]
+  fn transform_html_flattens_picture_to_largest_source() {
+    let html = r#"<html><body><picture>
+      <source media="(min-width: 800px)" srcset="/large.webp 1600w, /medium.webp 800w">
+      <source srcset="/small.webp 400w">
+      <img src="/fallback.jpg" alt="Hero">
+    </picture></body></html>"#;
+
+    let out = _transform_html_inner(transform_opts(html)).unwrap();
+    assert!(!out.contains("<picture"));
+    assert!(!out.contains("<source"));
+    assert!(out.contains(r#"src="https://example.com/large.webp""#));
+    assert!(out.contains(r#"alt="Hero""#));
+    assert!(!out.contains("fallback.jpg"));
+  }
+
+  #[test]
+  fn transform_html_keeps_picture_fallback_without_sources() {
+    let html = r#"<html><body><picture><img src="/fallback.jpg"></picture></body></html>"#;
+
+    let out = _transform_html_inner(transform_opts(html)).unwrap();
+    assert!(!out.contains("<picture"));
+    assert!(out.contains(r#"src="https://example.com/fallback.jpg""#));
+  }
+
+  #[test]
+  fn transform_html_leaves_inline_urls_untouched() {
+    let data_uri = "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNk+M9QDwADhgGAWjR9awAAAABJRU5ErkJggg==";
+    let html = format!(
+      r#"<html><body>
+        <img src="{data_uri}">
+        <img src="blob:https://example.com/5d1c0a3e">
+        <a href="javascript:void(0)">js</a>
+        <a href="mailto:hi@example.com">mail</a>
+        <a href="tel:+15551234">call</a>
+        <a href="/about">about</a>
+      </body></html>"#
+    );
+
+    let out = _transform_html_inner(transform_opts(&html)).unwrap();
+    assert!(out.contains(&format!(r#"src="{data_uri}""#)));
+    assert!(out.contains(r#"src="blob:https://example.com/5d1c0a3e""#));
+    assert!(out.contains(r#"href="javascript:void(0)""#));
+    assert!(out.contains(r#"href="mailto:hi@example.com""#));
+    assert!(out.contains(r#"href="tel:+15551234""#));
+    assert!(out.contains(r#"href="https://example.com/about""#));
+  }
+
+  #[test]
+  fn transform_html_applies_user_main_content_selectors() {
+    let html = r#"<html><body>
+      <div id="promo-bar">Sale ends soon</div>
+      <div class="widget-sidebar">Popular posts</div>
+      <div class="widget-sidebar"><p class="keep-me">Author bio</p></div>
+      <main><p>Article body</p></main>
+    </body></html>"#;
+
+    let builtin = _transform_html_inner(TransformHtmlOptions {
+      only_main_content: true,
+      ..transform_opts(html)
+    })
+    .unwrap();
+    assert!(builtin.contains("Sale ends soon"));
+    assert!(builtin.contains("Popular posts"));
+
+    let out = _transform_html_inner(TransformHtmlOptions {
+      only_main_content: true,
+      additional_exclude_tags: Some(vec!["#promo-bar".into(), ".widget-sidebar".into()]),
+      force_include_tags: Some(vec![".keep-me".into()]),
+      ..transform_opts(html)
+    })
+    .unwrap();
+    assert!(!out.contains("Sale ends soon"));
+    assert!(!out.contains("Popular posts"));
+    assert!(out.contains("Author bio"));
+    assert!(out.contains("Article body"));
+  }
+
+  #[test]
+  fn extract_video_sources_classifies_and_resolves() {
+    let html = r#"<html><head>
+      <meta property="og:video" content="https://www.youtube.com/embed/abc123">
+    </head><body>
+      <video src="/media/intro.mp4" poster="/media/intro.jpg"></video>
+      <video poster="thumb.png">
+        <source src="clip.webm" type="video/webm">
+        <source src="clip.mp4" type="video/mp4">
+      </video>
+      <iframe src="https://www.youtube.com/embed/abc123"></iframe>
+      <iframe src="//player.vimeo.com/video/42"></iframe>
+      <iframe src="https://notvimeo.com/embed/1"></iframe>
+    </body></html>"#;
+
+    let videos = _extract_video_sources(html, "https://example.com/blog/post").unwrap();
+    let summary: Vec<(&str, &str, Option<&str>)> = videos
+      .iter()
+      .map(|v| (v.src.as_str(), v.source_type.as_str(), v.poster.as_deref()))
+      .collect();
+
+    assert_eq!(
+      summary,
+      vec![
+        (
+          "https://example.com/media/intro.mp4",
+          "direct",
+          Some("https://example.com/media/intro.jpg"),
+        ),
+        (
+          "https://example.com/blog/clip.webm",
+          "direct",
+          Some("https://example.com/blog/thumb.png"),
+        ),
+        (
+          "https://example.com/blog/clip.mp4",
+          "direct",
+          Some("https://example.com/blog/thumb.png"),
+        ),
+        ("https://www.youtube.com/embed/abc123", "youtube", None),
+        ("https://player.vimeo.com/video/42", "vimeo", None),
+        ("https://notvimeo.com/embed/1", "iframe", None),
+      ]
+    );
+  }
+
+  #[test]
+  fn extract_article_metadata_prefers_meta_over_json_ld() {
+    let html = r#"<html><head>
+      <meta property="article:published_time" content="2024-03-01T09:00:00Z">
+      <meta property="article:section" content="Engineering">
+      <meta property="article:tag" content="Rust">
+      <meta property="article:tag" content="Scraping">
+      <script type="application/ld+json">
+      {
+        "@context": "https://schema.org",
+        "@type": "NewsArticle",
+        "author": [{ "@type": "Person", "name": "Ada Lovelace" }],
+        "datePublished": "2024-02-01",
+        "dateModified": "2024-03-02T10:00:00Z",
+        "keywords": "rust, Crawling"
+      }
+      </script>
+    </head><body>
+      <a rel="author" href="/team/someone">Someone Else</a>
+      <time datetime="2023-01-01">Long ago</time>
+    </body></html>"#;
+
+    let meta = _extract_article_metadata(html);
+    assert_eq!(meta.author.as_deref(), Some("Ada Lovelace"));
+    assert_eq!(meta.published_date.as_deref(), Some("2024-03-01T09:00:00Z"));
+    assert_eq!(meta.modified_date.as_deref(), Some("2024-03-02T10:00:00Z"));
+    assert_eq!(meta.section.as_deref(), Some("Engineering"));
+    assert_eq!(meta.tags, vec!["Rust", "Scraping", "Crawling"]);
+  }
+
+  #[test]
+  fn extract_article_metadata_falls_back_to_page_markup() {
+    let html = r#"<html><body><article>
+      <p>By <a rel="author" href="/team/jane">Jane   Doe</a></p>
+      <time datetime="2024-05-06T07:08:09+02:00">May 6</time>
+    </article></body></html>"#;
+
+    let meta = _extract_article_metadata(html);
+    assert_eq!(meta.author.as_deref(), Some("Jane Doe"));
+    assert_eq!(
+      meta.published_date.as_deref(),
+      Some("2024-05-06T07:08:09+02:00")
+    );
+    assert_eq!(meta.modified_date, None);
+    assert_eq!(meta.section, None);
+    assert!(meta.tags.is_empty());
+  }
+
+  #[test]
+  fn transform_html_removes_boilerplate_blocks() {
+    let html = r#"<html><body>
+      <div class="x1"><a href="/a">Home</a> | <a href="/b">Pricing</a> | <a href="/c">Blog</a></div>
+      <div class="x2">Sponsored</div>
+      <div class="content">
+        <p>This paragraph carries the actual article text, long enough to score well
+        even though it links to <a href="/docs">the documentation</a> once.</p>
+      </div>
+      <div class="gallery"><img src="/photo.jpg"></div>
+    </body></html>"#;
+
+    let kept = _transform_html_inner(transform_opts(html)).unwrap();
+    assert!(kept.contains("Pricing"));
+    assert!(kept.contains("Sponsored"));
+
+    let out = _transform_html_inner(TransformHtmlOptions {
+      remove_boilerplate: Some(true),
+      ..transform_opts(html)
+    })
+    .unwrap();
+    assert!(!out.contains("Pricing"));
+    assert!(!out.contains("Sponsored"));
+    assert!(out.contains("actual article text"));
+    assert!(out.contains("photo.jpg"));
+
+    let strict = _transform_html_inner(TransformHtmlOptions {
+      remove_boilerplate: Some(true),
+      boilerplate_threshold: Some(1000.0),
+      ..transform_opts(html)
+    })
+    .unwrap();
+    assert!(!strict.contains("actual article text"));
+  }
+
+  #[test]
+  fn extract_open_graph_images_resolves_and_dedupes() {
+    let html = r#"<html><head>
+      <meta property="og:image" content="/img/cover.png">
+      <meta property="og:image:secure_url" content="https://example.com/img/cover.png">
+      <meta property="og:image:url" content="http://example.com/img/alt.png">
+      <meta name="twitter:image" content="http://cdn.other.com/card.jpg">
+      <meta property="og:image" content="data:image/png;base64,AAAA">
+    </head></html>"#;
+
+    assert_eq!(
+      _extract_open_graph_images(html, "https://example.com/post/1").unwrap(),
+      vec![
+        "http://cdn.other.com/card.jpg",
+        "https://example.com/img/alt.png",
+        "https://example.com/img/cover.png",
+      ]
+    );
+  }
+
+  #[test]
+  fn extract_microdata_nests_scopes() {
+    let html = r#"<div itemscope itemtype="https://schema.org/Product">
+        <h1 itemprop="name">Widget   Pro</h1>
+        <img itemprop="image" src="/widget.png">
+        <a itemprop="url" href="https://example.com/widget">link</a>
+        <span itemprop="color">red</span><span itemprop="color">blue</span>
+        <div itemprop="offers" itemscope itemtype="https://schema.org/Offer">
+          <meta itemprop="price" content="9.99">
+          <span itemprop="name">Launch offer</span>
+        </div>
+      </div>
+      <div itemscope><time itemprop="startDate" datetime="2024-05-01">May 1</time></div>"#;
+
+    let items = _extract_microdata(html);
+    assert_eq!(items.len(), 2);
+    assert_eq!(
+      items[0],
+      serde_json::json!({
+        "@type": "https://schema.org/Product",
+        "name": "Widget Pro",
+        "image": "/widget.png",
+        "url": "https://example.com/widget",
+        "color": ["red", "blue"],
+        "offers": {
+          "@type": "https://schema.org/Offer",
+          "price": "9.99",
+          "name": "Launch offer",
+        },
+      })
+    );
+    assert_eq!(items[1], serde_json::json!({ "startDate": "2024-05-01" }));
+  }
+
+  #[test]
+  fn extract_ld_json_articles_reads_article_types() {
+    let html = r#"<html><head>
+      <script type="application/ld+json">{
+        "@context": "https://schema.org",
+        "@graph": [
+          {"@type": "WebSite", "name": "Example"},
+          {
+            "@type": "NewsArticle",
+            "headline": " Big Story ",
+            "author": [{"@type": "Person", "name": "Ada"}, {"@type": "Person", "name": "Grace"}],
+            "datePublished": "2024-05-01",
+            "image": {"@type": "ImageObject", "url": "https://example.com/a.jpg"},
+            "url": "https://example.com/story"
+          }
+        ]
+      }</script>
+      <script type="application/ld+json">{"@type": "BlogPosting", "headline": "Post", "image": ["https://example.com/b.jpg"]}</script>
+    </head></html>"#;
+
+    let articles = _extract_ld_json_articles(html);
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0].headline.as_deref(), Some("Big Story"));
+    assert_eq!(articles[0].author.as_deref(), Some("Ada, Grace"));
+    assert_eq!(articles[0].date_published.as_deref(), Some("2024-05-01"));
+    assert_eq!(articles[0].date_modified, None);
+    assert_eq!(
+      articles[0].image.as_deref(),
+      Some("https://example.com/a.jpg")
+    );
+    assert_eq!(
+      articles[0].url.as_deref(),
+      Some("https://example.com/story")
+    );
+    assert_eq!(articles[1].headline.as_deref(), Some("Post"));
+    assert_eq!(
+      articles[1].image.as_deref(),
+      Some("https://example.com/b.jpg")
+    );
+
+    assert!(_extract_ld_json_articles("<p>no data</p>").is_empty());
+  }
+}
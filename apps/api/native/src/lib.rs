@@ -7,7 +7,10 @@ pub use crate::logging::*;
 pub use crate::pdf::*;
 pub use crate::utils::*;
 
-pub use crate::document::{DocumentConverter, DocumentType};
+pub use crate::document::{
+  detect_document_type, DocumentConverter, DocumentType, MarkdownConverter, PlainTextConverter,
+  TrackedChangeMode,
+};
 
 mod crawler;
 mod document;
@@ -1,5 +1,55 @@
 use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use sha2::{Digest, Sha256};
+use tokio::task;
 
 pub fn to_napi_err<E: std::fmt::Display>(error: E) -> Error {
   Error::new(Status::GenericFailure, error.to_string())
 }
+
+/// Hex-encoded SHA-256 of `text` with whitespace runs collapsed and trimmed,
+/// so formatting-only changes produce the same hash.
+pub fn compute_content_hash(text: &str) -> String {
+  let mut hasher = Sha256::new();
+  for (i, word) in text.split_whitespace().enumerate() {
+    if i > 0 {
+      hasher.update(b" ");
+    }
+    hasher.update(word.as_bytes());
+  }
+
+  hasher
+    .finalize()
+    .iter()
+    .map(|b| format!("{b:02x}"))
+    .collect()
+}
+
+/// Compute a whitespace-insensitive content hash for change detection.
+#[napi]
+pub async fn content_hash(text: String) -> napi::Result<String> {
+  task::spawn_blocking(move || compute_content_hash(&text))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("content_hash join error: {e}"),
+      )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn content_hash_ignores_whitespace_differences() {
+    let hash = compute_content_hash("Hello world");
+    assert_eq!(
+      hash,
+      "64ec88ca00b268e5ba1a35678a1b5316d212f4f366b2477232534a8aeca37f3c"
+    );
+    assert_eq!(compute_content_hash("  Hello \n\n\t world  "), hash);
+    assert_ne!(compute_content_hash("Hello, world"), hash);
+  }
+}
@@ -202,6 +202,13 @@ export class WebCrawler {
         robotsUserAgent: this.robotsUserAgent,
         allowExternalContentLinks: this.allowExternalContentLinks,
         allowSubdomains: this.allowSubdomains,
+        allowHashRoutes: false,
+        respectCrawlDelay: false,
+        deduplicateStripped: false,
+        deduplicateFingerprints: false,
+        additionalBlockedDomains: [],
+        stripQueryParamsForDedup: false,
+        allowDocumentLinks: true,
       });
 
       const fancyDenialReasons = new Map<string, string>();
@@ -675,6 +682,7 @@ export class WebCrawler {
       robotsUserAgent: this.robotsUserAgent,
       allowExternalContentLinks: this.allowExternalContentLinks,
       allowSubdomains: this.allowSubdomains,
+      additionalBlockedDomains: [],
     });
   }
 
@@ -12,10 +12,15 @@ use serde_json::json;
 #[tokio::main]
 async fn main() {
     // Initialize the Client with the API key
-    let client = Client::new("fc-YOUR-API-KEY").expect("Failed to initialize Client");
+    let client = Client::builder()
+        .api_key("fc-YOUR-API-KEY")
+        .build()
+        .expect("Failed to initialize Client");
 
     // Or, connect to a self-hosted instance:
-    // let client = Client::new_selfhosted("http://localhost:3002", None::<&str>)
+    // let client = Client::builder()
+    //     .base_url("http://localhost:3002")
+    //     .build()
     //     .expect("Failed to initialize Client");
 
     // Example 1: Simple Scrape
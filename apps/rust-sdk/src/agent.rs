@@ -3,6 +3,8 @@
 //! The Agent endpoint provides autonomous web browsing capabilities using AI
 //! to accomplish complex tasks that may require multiple page interactions.
 
+use std::sync::Arc;
+
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -48,6 +50,128 @@ pub struct AgentOptions {
     pub timeout: Option<u64>,
 }
 
+/// Builder for [`AgentOptions`] with chainable setters.
+///
+/// # Example
+///
+/// ```
+/// use firecrawl::AgentOptionsBuilder;
+///
+/// let options = AgentOptionsBuilder::new()
+///     .url("https://example.com")
+///     .prompt("Find the pricing for the pro plan")
+///     .max_credits(100)
+///     .build();
+///
+/// assert_eq!(options.urls, Some(vec!["https://example.com".to_string()]));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct AgentOptionsBuilder {
+    options: AgentOptions,
+}
+
+impl AgentOptions {
+    /// Returns a builder for agent options.
+    pub fn builder() -> AgentOptionsBuilder {
+        AgentOptionsBuilder::default()
+    }
+}
+
+impl AgentOptionsBuilder {
+    /// Returns an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a starting URL for the agent to explore.
+    pub fn url(&mut self, url: impl Into<String>) -> &mut Self {
+        self.options
+            .urls
+            .get_or_insert_with(Vec::new)
+            .push(url.into());
+        self
+    }
+
+    /// Starting URLs for the agent to explore, replacing any added so far.
+    pub fn urls(&mut self, urls: Vec<String>) -> &mut Self {
+        self.options.urls = Some(urls);
+        self
+    }
+
+    /// The prompt describing what the agent should accomplish.
+    pub fn prompt(&mut self, prompt: impl Into<String>) -> &mut Self {
+        self.options.prompt = prompt.into();
+        self
+    }
+
+    /// JSON schema for the expected output structure.
+    pub fn schema(&mut self, schema: Value) -> &mut Self {
+        self.options.schema = Some(schema);
+        self
+    }
+
+    /// Uses the JSON schema of `T` for the expected output structure.
+    ///
+    /// The schema is generated at runtime, when this method is called, from
+    /// `T`'s [`JsonSchema`](schemars::JsonSchema) implementation (usually
+    /// derived), so it stays in sync with the type the result is deserialized
+    /// into. Requires the `schemars` feature.
+    #[cfg(feature = "schemars")]
+    pub fn schema_from_type<T: schemars::JsonSchema>(&mut self) -> &mut Self {
+        let schema = serde_json::to_value(schemars::schema_for!(T))
+            .expect("generated JSON schemas always serialize");
+        self.options.schema = Some(schema);
+        self
+    }
+
+    /// Integration identifier for tracking.
+    pub fn integration(&mut self, integration: impl Into<String>) -> &mut Self {
+        self.options.integration = Some(integration.into());
+        self
+    }
+
+    /// Maximum credits the agent can use.
+    pub fn max_credits(&mut self, max_credits: u32) -> &mut Self {
+        self.options.max_credits = Some(max_credits);
+        self
+    }
+
+    /// Strictly constrain the agent to the provided URLs.
+    pub fn strict_constrain_to_urls(&mut self, strict_constrain_to_urls: bool) -> &mut Self {
+        self.options.strict_constrain_to_urls = Some(strict_constrain_to_urls);
+        self
+    }
+
+    /// Agent model to use.
+    pub fn model(&mut self, model: AgentModel) -> &mut Self {
+        self.options.model = Some(model);
+        self
+    }
+
+    /// Webhook configuration for agent notifications.
+    pub fn webhook(&mut self, webhook: impl Into<AgentWebhookConfig>) -> &mut Self {
+        self.options.webhook = Some(webhook.into());
+        self
+    }
+
+    /// Poll interval for synchronous agent execution (milliseconds).
+    pub fn poll_interval(&mut self, poll_interval: u64) -> &mut Self {
+        self.options.poll_interval = Some(poll_interval);
+        self
+    }
+
+    /// Timeout for synchronous agent execution (seconds).
+    pub fn timeout(&mut self, timeout: u64) -> &mut Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the agent options.
+    pub fn build(&self) -> AgentOptions {
+        self.options.clone()
+    }
+}
+
 /// Response from starting an agent task.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -95,6 +219,49 @@ pub struct AgentStatusResponse {
     pub credits_used: Option<u32>,
 }
 
+/// Cancels an agent task when dropped.
+///
+/// Returned by [`Client::agent_guarded`]. If the task holding the guard is
+/// aborted or finishes early, dropping the guard spawns a best-effort
+/// [`Client::cancel_agent`] call so the remote task stops consuming credits.
+/// Cancellation requires a running Tokio runtime and its outcome is ignored.
+/// Call [`AgentGuard::disarm`] to keep the task running.
+#[derive(Debug)]
+pub struct AgentGuard {
+    client: Arc<Client>,
+    id: String,
+    armed: bool,
+}
+
+impl AgentGuard {
+    /// The guarded agent task ID.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Releases the guard without cancelling the task, returning its ID.
+    pub fn disarm(mut self) -> String {
+        self.armed = false;
+        std::mem::take(&mut self.id)
+    }
+}
+
+impl Drop for AgentGuard {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            let client = self.client.clone();
+            let id = std::mem::take(&mut self.id);
+            handle.spawn(async move {
+                let _ = client.cancel_agent(id).await;
+            });
+        }
+    }
+}
+
 impl Client {
     /// Starts an agent task asynchronously.
     ///
@@ -115,7 +282,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     let options = AgentOptions {
     ///         urls: Some(vec!["https://example.com".to_string()]),
@@ -136,13 +303,14 @@ impl Client {
         let headers = self.prepare_headers(None);
 
         let response = self
-            .client
-            .post(self.url("/agent"))
-            .headers(headers)
-            .json(&options)
-            .send()
+            .send(
+                self.client
+                    .post(self.url("/agent"))
+                    .headers(headers)
+                    .json(&options),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError("Starting agent task".to_string(), e))?;
+            .map_err(|e| e.with_action("Starting agent task"))?;
 
         self.handle_response(response, "start agent").await
     }
@@ -164,7 +332,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     let status = client.get_agent_status("task-id").await?;
     ///     println!("Status: {:?}", status.status);
@@ -181,19 +349,77 @@ impl Client {
         id: impl AsRef<str>,
     ) -> Result<AgentStatusResponse, FirecrawlError> {
         let response = self
-            .client
-            .get(self.url(&format!("/agent/{}", id.as_ref())))
-            .headers(self.prepare_headers(None))
-            .send()
+            .send(
+                self.client
+                    .get(self.url(&format!("/agent/{}", id.as_ref())))
+                    .headers(self.prepare_headers(None)),
+            )
             .await
-            .map_err(|e| {
-                FirecrawlError::HttpError(format!("Getting agent status {}", id.as_ref()), e)
-            })?;
+            .map_err(|e| e.with_action(format!("Getting agent status {}", id.as_ref())))?;
 
         self.handle_response(response, format!("agent status {}", id.as_ref()))
             .await
     }
 
+    /// Starts an agent task that is cancelled when the returned guard is dropped.
+    ///
+    /// Returns an [`AgentGuard`] together with the task's initial status. Keep
+    /// the guard alive while waiting on the task; if the surrounding future is
+    /// aborted, the guard cancels the remote task.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the task can't be started. If it started but its
+    /// initial status couldn't be fetched, returns
+    /// [`FirecrawlError::AgentStatusUnavailable`], which carries the guard so
+    /// the task isn't lost.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::{Client, AgentOptions, AgentStatus};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
+    ///
+    ///     let options = AgentOptions {
+    ///         prompt: "Find the pricing information on example.com".to_string(),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let (guard, mut status) = client.agent_guarded(options).await?;
+    ///     while status.status == AgentStatus::Processing {
+    ///         tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+    ///         status = client.get_agent_status(guard.id()).await?;
+    ///     }
+    ///
+    ///     // The task has finished; no need to cancel it.
+    ///     guard.disarm();
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn agent_guarded(
+        &self,
+        options: AgentOptions,
+    ) -> Result<(AgentGuard, AgentStatusResponse), FirecrawlError> {
+        let response = self.start_agent(options).await?;
+        let guard = AgentGuard {
+            client: Arc::new(self.clone()),
+            id: response.id,
+            armed: true,
+        };
+
+        match self.get_agent_status(guard.id()).await {
+            Ok(status) => Ok((guard, status)),
+            Err(e) => Err(FirecrawlError::AgentStatusUnavailable {
+                guard,
+                source: Box::new(e),
+            }),
+        }
+    }
+
     /// Runs an agent task and waits for completion.
     ///
     /// This method starts an agent task and polls until it completes, fails, or times out.
@@ -214,7 +440,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     let options = AgentOptions {
     ///         urls: Some(vec!["https://example.com/pricing".to_string()]),
@@ -269,10 +495,54 @@ impl Client {
         poll_interval: u64,
         timeout: Option<u64>,
     ) -> Result<AgentStatusResponse, FirecrawlError> {
+        self.agent_poll_with_callback(id, poll_interval, timeout, |_, _| {})
+            .await
+    }
+
+    /// Polls an agent task until it finishes, calling `on_status` with the
+    /// current status and credits used after every poll.
+    ///
+    /// Returns the last status once the task completes, fails or is
+    /// cancelled, or once `timeout` seconds have passed while it is still
+    /// processing.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::{AgentOptions, Client};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
+    ///
+    ///     let task = client
+    ///         .start_agent(AgentOptions::builder().prompt("Find the pricing").build())
+    ///         .await?;
+    ///     let result = client
+    ///         .agent_poll_with_callback(&task.id, 2000, Some(300), |status, credits| {
+    ///             println!("{:?} ({} credits)", status, credits.unwrap_or(0));
+    ///         })
+    ///         .await?;
+    ///     println!("Data: {:?}", result.data);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn agent_poll_with_callback<F>(
+        &self,
+        id: &str,
+        poll_interval: u64,
+        timeout: Option<u64>,
+        on_status: F,
+    ) -> Result<AgentStatusResponse, FirecrawlError>
+    where
+        F: Fn(AgentStatus, Option<u32>) + Send,
+    {
         let start = std::time::Instant::now();
 
         loop {
             let status = self.get_agent_status(id).await?;
+            on_status(status.status, status.credits_used);
 
             match status.status {
                 AgentStatus::Completed | AgentStatus::Failed | AgentStatus::Cancelled => {
@@ -309,7 +579,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     let cancelled = client.cancel_agent("task-id").await?;
     ///     println!("Cancelled: {}", cancelled);
@@ -319,14 +589,13 @@ impl Client {
     /// ```
     pub async fn cancel_agent(&self, id: impl AsRef<str>) -> Result<bool, FirecrawlError> {
         let response = self
-            .client
-            .delete(self.url(&format!("/agent/{}", id.as_ref())))
-            .headers(self.prepare_headers(None))
-            .send()
+            .send(
+                self.client
+                    .delete(self.url(&format!("/agent/{}", id.as_ref())))
+                    .headers(self.prepare_headers(None)),
+            )
             .await
-            .map_err(|e| {
-                FirecrawlError::HttpError(format!("Cancelling agent {}", id.as_ref()), e)
-            })?;
+            .map_err(|e| e.with_action(format!("Cancelling agent {}", id.as_ref())))?;
 
         #[derive(Deserialize)]
         struct CancelResponse {
@@ -371,7 +640,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     let schema = json!({
     ///         "type": "object",
@@ -429,6 +698,7 @@ impl Client {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::RetryConfig;
     use serde_json::json;
 
     #[tokio::test]
@@ -448,7 +718,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let options = AgentOptions {
             urls: Some(vec!["https://example.com".to_string()]),
             prompt: "Find the contact information".to_string(),
@@ -485,7 +759,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let status = client.get_agent_status("agent-123").await.unwrap();
 
         assert!(status.success);
@@ -530,7 +808,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let options = AgentOptions {
             urls: Some(vec!["https://example.com".to_string()]),
             prompt: "Test task".to_string(),
@@ -545,6 +827,49 @@ mod tests {
         status_mock.assert();
     }
 
+    #[tokio::test]
+    async fn test_agent_poll_with_callback_reports_status() {
+        let mut server = mockito::Server::new_async().await;
+        for (status, credits) in [("processing", 3), ("completed", 7)] {
+            server
+                .mock("GET", "/v2/agent/agent-789")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    json!({
+                        "success": true,
+                        "status": status,
+                        "creditsUsed": credits
+                    })
+                    .to_string(),
+                )
+                .expect(1)
+                .create();
+        }
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
+        let updates = std::sync::Mutex::new(Vec::new());
+        let result = client
+            .agent_poll_with_callback("agent-789", 1, None, |status, credits| {
+                updates.lock().unwrap().push((status, credits));
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.status, AgentStatus::Completed);
+        assert_eq!(
+            updates.into_inner().unwrap(),
+            vec![
+                (AgentStatus::Processing, Some(3)),
+                (AgentStatus::Completed, Some(7))
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn test_cancel_agent_with_mock() {
         let mut server = mockito::Server::new_async().await;
@@ -561,7 +886,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let cancelled = client.cancel_agent("agent-789").await.unwrap();
 
         assert!(cancelled);
@@ -610,7 +939,11 @@ mod tests {
             price: f64,
         }
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
 
         let schema = json!({
             "type": "object",
@@ -657,7 +990,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let options = AgentOptions {
             urls: Some(vec!["https://example.com".to_string()]),
             prompt: "Task with specific model".to_string(),
@@ -671,4 +1008,178 @@ mod tests {
         assert!(response.success);
         mock.assert();
     }
+
+    #[tokio::test]
+    async fn test_agent_guard_cancels_on_drop() {
+        let mut server = mockito::Server::new_async().await;
+
+        let _start = server
+            .mock("POST", "/v2/agent")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "success": true, "id": "agent-guarded" }).to_string())
+            .create();
+        let _status = server
+            .mock("GET", "/v2/agent/agent-guarded")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "success": true, "status": "processing" }).to_string())
+            .create();
+        let cancel = server
+            .mock("DELETE", "/v2/agent/agent-guarded")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "success": true }).to_string())
+            .expect(1)
+            .create();
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
+        let options = AgentOptions {
+            prompt: "Find the contact information".to_string(),
+            ..Default::default()
+        };
+
+        let (guard, status) = client.agent_guarded(options).await.unwrap();
+        assert_eq!(status.status, AgentStatus::Processing);
+        assert!(!cancel.matched());
+
+        drop(guard);
+        for _ in 0..50 {
+            if cancel.matched() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        cancel.assert();
+    }
+
+    #[tokio::test]
+    async fn test_disarmed_agent_guard_does_not_cancel() {
+        let mut server = mockito::Server::new_async().await;
+
+        let _start = server
+            .mock("POST", "/v2/agent")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "success": true, "id": "agent-kept" }).to_string())
+            .create();
+        let _status = server
+            .mock("GET", "/v2/agent/agent-kept")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "success": true, "status": "completed" }).to_string())
+            .create();
+        let cancel = server
+            .mock("DELETE", "/v2/agent/agent-kept")
+            .expect(0)
+            .create();
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
+        let options = AgentOptions {
+            prompt: "Find the contact information".to_string(),
+            ..Default::default()
+        };
+
+        let (guard, _) = client.agent_guarded(options).await.unwrap();
+        assert_eq!(guard.disarm(), "agent-kept");
+
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        cancel.assert();
+    }
+
+    #[tokio::test]
+    async fn test_agent_guard_is_returned_when_status_fetch_fails() {
+        let mut server = mockito::Server::new_async().await;
+
+        let _start = server
+            .mock("POST", "/v2/agent")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "success": true, "id": "agent-unknown" }).to_string())
+            .create();
+        let _status = server
+            .mock("GET", "/v2/agent/agent-unknown")
+            .with_status(500)
+            .with_body("Internal Server Error")
+            .create();
+        let cancel = server
+            .mock("DELETE", "/v2/agent/agent-unknown")
+            .expect(0)
+            .create();
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .retry(RetryConfig::disabled())
+            .build()
+            .unwrap();
+        let options = AgentOptions {
+            prompt: "Find the contact information".to_string(),
+            ..Default::default()
+        };
+
+        let (guard, source) = match client.agent_guarded(options).await {
+            Err(FirecrawlError::AgentStatusUnavailable { guard, source }) => (guard, source),
+            other => panic!("expected AgentStatusUnavailable, got {:?}", other),
+        };
+        assert!(matches!(
+            *source,
+            FirecrawlError::ServerError { status: 500, .. }
+        ));
+        assert_eq!(guard.disarm(), "agent-unknown");
+
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        cancel.assert();
+    }
+
+    #[test]
+    fn test_agent_options_builder() {
+        let options = AgentOptionsBuilder::new()
+            .url("https://example.com")
+            .url("https://example.com/pricing")
+            .prompt("Find the pricing for the pro plan")
+            .max_credits(50)
+            .webhook("https://example.com/webhook")
+            .poll_interval(1000)
+            .build();
+
+        assert_eq!(options.prompt, "Find the pricing for the pro plan");
+        assert_eq!(options.poll_interval, Some(1000));
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            json!({
+                "urls": ["https://example.com", "https://example.com/pricing"],
+                "prompt": "Find the pricing for the pro plan",
+                "maxCredits": 50,
+                "webhook": { "url": "https://example.com/webhook" }
+            })
+        );
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn test_agent_options_schema_from_type() {
+        #[derive(schemars::JsonSchema)]
+        #[allow(dead_code)]
+        struct Pricing {
+            plan: String,
+            monthly_price: f64,
+        }
+
+        let options = AgentOptions::builder()
+            .prompt("Find the pricing")
+            .schema_from_type::<Pricing>()
+            .build();
+        let schema = options.schema.unwrap();
+        assert_eq!(schema["type"], "object");
+        assert!(schema["properties"]["monthly_price"].is_object());
+    }
 }
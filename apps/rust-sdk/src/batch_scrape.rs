@@ -1,6 +1,7 @@
 //! Batch scrape endpoint for Firecrawl API v2.
 
 use serde::{Deserialize, Serialize};
+use tokio_stream::Stream;
 
 use crate::client::Client;
 use crate::scrape::ScrapeOptions;
@@ -44,6 +45,14 @@ pub struct BatchScrapeOptions {
     pub poll_interval: Option<u64>,
 }
 
+impl BatchScrapeOptions {
+    /// Sets a freshly generated UUID v4 as the idempotency key.
+    pub fn with_idempotency(mut self) -> Self {
+        self.idempotency_key = Some(uuid::Uuid::new_v4().to_string());
+        self
+    }
+}
+
 /// Request body for batch scrape endpoint.
 #[derive(Deserialize, Serialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
@@ -110,7 +119,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     let urls = vec![
     ///         "https://example.com".to_string(),
@@ -141,13 +150,14 @@ impl Client {
         let headers = self.prepare_headers(options.idempotency_key.as_ref());
 
         let response = self
-            .client
-            .post(self.url("/batch/scrape"))
-            .headers(headers)
-            .json(&body)
-            .send()
+            .send(
+                self.client
+                    .post(self.url("/batch/scrape"))
+                    .headers(headers)
+                    .json(&body),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError("Starting batch scrape".to_string(), e))?;
+            .map_err(|e| e.with_action("Starting batch scrape"))?;
 
         self.handle_response(response, "start batch scrape").await
     }
@@ -171,7 +181,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     let status = client.get_batch_scrape_status("job-id").await?;
     ///     println!("Status: {:?}", status.status);
@@ -186,17 +196,13 @@ impl Client {
         id: impl AsRef<str>,
     ) -> Result<BatchScrapeJob, FirecrawlError> {
         let response = self
-            .client
-            .get(self.url(&format!("/batch/scrape/{}", id.as_ref())))
-            .headers(self.prepare_headers(None))
-            .send()
+            .send(
+                self.client
+                    .get(self.url(&format!("/batch/scrape/{}", id.as_ref())))
+                    .headers(self.prepare_headers(None)),
+            )
             .await
-            .map_err(|e| {
-                FirecrawlError::HttpError(
-                    format!("Checking batch scrape status {}", id.as_ref()),
-                    e,
-                )
-            })?;
+            .map_err(|e| e.with_action(format!("Checking batch scrape status {}", id.as_ref())))?;
 
         let mut status: BatchScrapeJob = self
             .handle_response(response, format!("batch scrape status {}", id.as_ref()))
@@ -220,14 +226,9 @@ impl Client {
         next: &str,
     ) -> Result<BatchScrapeJob, FirecrawlError> {
         let response = self
-            .client
-            .get(next)
-            .headers(self.prepare_headers(None))
-            .send()
+            .send(self.client.get(next).headers(self.prepare_headers(None)))
             .await
-            .map_err(|e| {
-                FirecrawlError::HttpError(format!("Paginating batch scrape at {}", next), e)
-            })?;
+            .map_err(|e| e.with_action(format!("Paginating batch scrape at {}", next)))?;
 
         self.handle_response(response, "batch scrape pagination")
             .await
@@ -253,7 +254,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     let urls = vec![
     ///         "https://example.com/page1".to_string(),
@@ -294,6 +295,58 @@ impl Client {
             .await
     }
 
+    /// Scrapes multiple URLs, yielding documents as they complete.
+    ///
+    /// Starts a batch scrape job and polls its status, emitting only the
+    /// documents that were not returned by a previous poll. The stream ends
+    /// when the job completes, or yields an error if it fails or is cancelled.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::Client;
+    /// use tokio_stream::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
+    ///
+    ///     let urls = vec![
+    ///         "https://example.com/page1".to_string(),
+    ///         "https://example.com/page2".to_string(),
+    ///     ];
+    ///
+    ///     let stream = client.batch_scrape_stream(urls, None);
+    ///     tokio::pin!(stream);
+    ///
+    ///     while let Some(document) = stream.next().await {
+    ///         let document = document?;
+    ///         println!("URL: {:?}", document.metadata.and_then(|m| m.source_url));
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn batch_scrape_stream(
+        &self,
+        urls: Vec<String>,
+        options: impl Into<Option<BatchScrapeOptions>>,
+    ) -> impl Stream<Item = Result<Document, FirecrawlError>> + '_ {
+        let options = options.into().unwrap_or_default();
+        let poll_interval = options.poll_interval.unwrap_or(2000);
+
+        self.job_document_stream(
+            async move {
+                self.start_batch_scrape(urls, options)
+                    .await
+                    .map(|job| job.id)
+            },
+            "/batch/scrape",
+            "Batch scrape",
+            poll_interval,
+        )
+    }
+
     /// Waits for a batch scrape job to complete.
     async fn wait_for_batch_scrape(
         &self,
@@ -341,7 +394,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     let errors = client.get_batch_scrape_errors("job-id").await?;
     ///     for error in errors.errors {
@@ -356,14 +409,13 @@ impl Client {
         id: impl AsRef<str>,
     ) -> Result<CrawlErrorsResponse, FirecrawlError> {
         let response = self
-            .client
-            .get(self.url(&format!("/batch/scrape/{}/errors", id.as_ref())))
-            .headers(self.prepare_headers(None))
-            .send()
+            .send(
+                self.client
+                    .get(self.url(&format!("/batch/scrape/{}/errors", id.as_ref())))
+                    .headers(self.prepare_headers(None)),
+            )
             .await
-            .map_err(|e| {
-                FirecrawlError::HttpError(format!("Getting batch scrape errors {}", id.as_ref()), e)
-            })?;
+            .map_err(|e| e.with_action(format!("Getting batch scrape errors {}", id.as_ref())))?;
 
         self.handle_response(response, "batch scrape errors").await
     }
@@ -392,7 +444,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let urls = vec![
             "https://example.com".to_string(),
             "https://example.org".to_string(),
@@ -434,7 +490,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let status = client.get_batch_scrape_status("batch-123").await.unwrap();
 
         assert_eq!(status.status, JobStatus::Completed);
@@ -463,7 +523,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let urls = vec!["https://example.com".to_string(), "not-a-url".to_string()];
 
         let options = BatchScrapeOptions {
@@ -518,7 +582,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let urls = vec!["https://example.com".to_string()];
 
         let result = client.batch_scrape(urls, None).await.unwrap();
@@ -552,11 +620,95 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let errors = client.get_batch_scrape_errors("batch-123").await.unwrap();
 
         assert_eq!(errors.errors.len(), 1);
         assert_eq!(errors.errors[0].error, "Connection timeout");
         mock.assert();
     }
+
+    #[tokio::test]
+    async fn test_batch_scrape_stream_yields_incremental_documents() {
+        use tokio_stream::StreamExt;
+
+        let mut server = mockito::Server::new_async().await;
+
+        let start_mock = server
+            .mock("POST", "/v2/batch/scrape")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "id": "batch-stream",
+                    "url": "https://api.firecrawl.dev/v2/batch/scrape/batch-stream"
+                })
+                .to_string(),
+            )
+            .create();
+        let first_poll = server
+            .mock("GET", "/v2/batch/scrape/batch-stream")
+            .match_query(mockito::Matcher::UrlEncoded("skip".into(), "0".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "status": "scraping",
+                    "total": 2,
+                    "completed": 1,
+                    "data": [{ "markdown": "# Page 1" }]
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create();
+        let second_poll = server
+            .mock("GET", "/v2/batch/scrape/batch-stream")
+            .match_query(mockito::Matcher::UrlEncoded("skip".into(), "1".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "status": "completed",
+                    "total": 2,
+                    "completed": 2,
+                    "data": [{ "markdown": "# Page 2" }]
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create();
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
+        let urls = vec![
+            "https://example.com/1".to_string(),
+            "https://example.com/2".to_string(),
+        ];
+        let options = BatchScrapeOptions {
+            poll_interval: Some(10),
+            ..Default::default()
+        };
+
+        let documents: Vec<Document> = client
+            .batch_scrape_stream(urls, options)
+            .collect::<Result<_, _>>()
+            .await
+            .unwrap();
+
+        assert_eq!(documents.len(), 2);
+        assert_eq!(documents[0].markdown.as_deref(), Some("# Page 1"));
+        assert_eq!(documents[1].markdown.as_deref(), Some("# Page 2"));
+        start_mock.assert();
+        first_poll.assert();
+        second_poll.assert();
+    }
 }
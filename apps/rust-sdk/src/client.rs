@@ -1,14 +1,364 @@
 //! Firecrawl API v2 client.
 
-use reqwest::Response;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use reqwest::{RequestBuilder, Response, StatusCode};
 use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use tracing::Instrument;
 
+use crate::crawl::IdempotentCrawls;
 use crate::error::FirecrawlError;
 
 pub(crate) const API_VERSION: &str = "/v2";
 const CLOUD_API_URL: &str = "https://api.firecrawl.dev";
 
+/// Retry behaviour for transient failures.
+///
+/// Requests are retried on connection errors, timeouts and on `429`, `500`,
+/// `502`, `503` and `504` responses. Delays grow exponentially from
+/// `initial_delay_ms`, capped at `max_delay_ms`. A `Retry-After` header on a
+/// `429` response takes precedence over the computed delay and is honored as
+/// is, even when it exceeds `max_delay_ms`, up to `max_retry_after_ms` (one
+/// minute by default). When the server asks for a longer wait, the request
+/// isn't retried and fails with [`FirecrawlError::RateLimited`] instead.
+///
+/// Only idempotent requests are retried by default: `GET`, `HEAD`, `PUT`,
+/// `DELETE` and `OPTIONS`, plus requests carrying an idempotency key. Set
+/// `retry_non_idempotent` to also retry other `POST` requests, which may
+/// start a second job or spend credits twice.
+///
+/// # Example
+///
+/// ```no_run
+/// use firecrawl::{Client, RetryConfig};
+///
+/// let client = Client::builder()
+///     .api_key("your-api-key")
+///     .retry(RetryConfig {
+///         max_attempts: 5,
+///         ..Default::default()
+///     })
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// Total number of attempts, including the first one. `1` disables retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry, in milliseconds.
+    pub initial_delay_ms: u64,
+    /// Upper bound for any single delay, in milliseconds.
+    pub max_delay_ms: u64,
+    /// Use full jitter (a random delay between zero and the backoff delay).
+    pub jitter: bool,
+    /// Also retry requests that aren't idempotent, such as a `POST` without
+    /// an idempotency key.
+    pub retry_non_idempotent: bool,
+    /// Longest `Retry-After` delay to wait for, in milliseconds.
+    pub max_retry_after_ms: u64,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_delay_ms: 500,
+            max_delay_ms: 30_000,
+            jitter: true,
+            retry_non_idempotent: false,
+            max_retry_after_ms: 60_000,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// A configuration that never retries.
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Delay to wait after the given (1-based) failed attempt.
+    fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after {
+            return retry_after;
+        }
+
+        let factor = 1u64
+            .checked_shl(attempt.saturating_sub(1))
+            .unwrap_or(u64::MAX);
+        let backoff = self
+            .initial_delay_ms
+            .saturating_mul(factor)
+            .min(self.max_delay_ms);
+        let backoff = if self.jitter && backoff > 0 {
+            random_u64() % (backoff + 1)
+        } else {
+            backoff
+        };
+        Duration::from_millis(backoff)
+    }
+}
+
+fn random_u64() -> u64 {
+    // RandomState is seeded per instance, which is plenty for jitter.
+    RandomState::new().build_hasher().finish()
+}
+
+/// Whether replaying `request` can't repeat a side effect: the method is
+/// idempotent or the request carries an idempotency key.
+fn is_idempotent(request: &reqwest::Request) -> bool {
+    use reqwest::Method;
+
+    matches!(
+        *request.method(),
+        Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS
+    ) || request.headers().contains_key("x-idempotency-key")
+}
+
+fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(status.as_u16(), 429 | 500 | 502 | 503 | 504)
+}
+
+fn retry_after(response: &Response) -> Option<Duration> {
+    if response.status() != StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Builder for [`Client`].
+///
+/// # Example
+///
+/// ```no_run
+/// use firecrawl::Client;
+///
+/// use std::time::Duration;
+///
+/// let client = Client::builder()
+///     .api_key("your-api-key")
+///     .base_url("http://localhost:3000")
+///     .timeout(Duration::from_secs(120))
+///     .user_agent("my-crawler/1.0")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct ClientBuilder {
+    api_key: Option<String>,
+    api_url: String,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    proxy: Option<String>,
+    retry_config: RetryConfig,
+    tracing: bool,
+    timeout_per_request: Option<Duration>,
+    health_check: bool,
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        Self {
+            api_key: None,
+            api_url: CLOUD_API_URL.to_string(),
+            timeout: None,
+            connect_timeout: None,
+            user_agent: None,
+            proxy: None,
+            retry_config: RetryConfig::default(),
+            tracing: false,
+            timeout_per_request: None,
+            health_check: false,
+        }
+    }
+}
+
+impl ClientBuilder {
+    /// Sets the API key.
+    pub fn api_key(mut self, api_key: impl AsRef<str>) -> Self {
+        self.api_key = Some(api_key.as_ref().to_string());
+        self
+    }
+
+    /// Sets the base URL of the Firecrawl instance. Defaults to the cloud service.
+    pub fn base_url(mut self, api_url: impl AsRef<str>) -> Self {
+        self.api_url = api_url.as_ref().to_string();
+        self
+    }
+
+    /// Sets the total timeout for each HTTP request.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets a client-side limit on how long each HTTP attempt may take.
+    /// Attempts exceeding it are retried like other transient failures, and
+    /// fail with [`FirecrawlError::Timeout`] once retries are exhausted.
+    ///
+    /// Unlike `ScrapeOptions::timeout`, which is a hint to the API, this
+    /// guards against hung connections.
+    pub fn timeout_per_request(mut self, timeout: Duration) -> Self {
+        self.timeout_per_request = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for establishing a connection.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with every request.
+    pub fn user_agent(mut self, user_agent: impl AsRef<str>) -> Self {
+        self.user_agent = Some(user_agent.as_ref().to_string());
+        self
+    }
+
+    /// Routes all requests through the given proxy URL.
+    pub fn proxy(mut self, proxy: impl AsRef<str>) -> Self {
+        self.proxy = Some(proxy.as_ref().to_string());
+        self
+    }
+
+    /// Sets the retry behaviour for transient failures.
+    pub fn retry(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self
+    }
+
+    /// Emits `tracing` events for each HTTP request: a `debug` span with the
+    /// method and URL, the response status and elapsed time, and a `warn`
+    /// event for unsuccessful responses. Request bodies are only logged (at
+    /// `trace` level) when the `FIRECRAWL_LOG_BODIES=1` environment variable
+    /// is set. Disabled by default.
+    pub fn tracing(mut self, enabled: bool) -> Self {
+        self.tracing = enabled;
+        self
+    }
+
+    /// Verifies the service is reachable and the API key is accepted before
+    /// handing out the client. The check needs an async context, so it runs
+    /// in [`ClientBuilder::connect`]; [`ClientBuilder::build`] rejects
+    /// builders with it enabled. Disabled by default.
+    pub fn with_health_check(mut self, enabled: bool) -> Self {
+        self.health_check = enabled;
+        self
+    }
+
+    /// Builds the client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the proxy URL is invalid, the HTTP client can't
+    /// be initialised, or a health check was requested (use
+    /// [`ClientBuilder::connect`] instead).
+    pub fn build(self) -> Result<Client, FirecrawlError> {
+        if self.health_check {
+            return Err(FirecrawlError::Misuse(
+                "health checks run asynchronously; use ClientBuilder::connect".to_string(),
+            ));
+        }
+        self.build_client()
+    }
+
+    /// Builds the client and, when [`ClientBuilder::with_health_check`] is
+    /// enabled, runs [`Client::health_check`] before returning it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`ClientBuilder::build`], plus any error
+    /// from the health check.
+    pub async fn connect(self) -> Result<Client, FirecrawlError> {
+        let health_check = self.health_check;
+        let client = self.build_client()?;
+        if health_check {
+            client.health_check().await?;
+        }
+        Ok(client)
+    }
+
+    fn build_client(self) -> Result<Client, FirecrawlError> {
+        // Normalize URL by trimming trailing slashes for consistent comparison
+        let api_url = self.api_url.trim_end_matches('/').to_string();
+
+        // An empty/missing API key is allowed: scrape, search, and interact fall
+        // back to the keyless free tier (rate-limited per IP). Other methods
+        // return 401 from the API until a key is provided.
+
+        let mut http = reqwest::Client::builder();
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        if let Some(user_agent) = self.user_agent {
+            http = http.user_agent(user_agent);
+        }
+        if let Some(proxy) = self.proxy {
+            let proxy = reqwest::Proxy::all(&proxy)
+                .map_err(|e| FirecrawlError::Misuse(format!("Invalid proxy URL: {}", e)))?;
+            http = http.proxy(proxy);
+        }
+        let client = http
+            .build()
+            .map_err(|e| FirecrawlError::HttpError("Building HTTP client".to_string(), e))?;
+
+        Ok(Client {
+            api_key: self.api_key,
+            api_url,
+            client,
+            retry_config: self.retry_config,
+            tracing: self.tracing,
+            timeout_per_request: self.timeout_per_request,
+            credits_used: Arc::new(AtomicU64::new(0)),
+            idempotent_crawls: Arc::new(Mutex::new(IdempotentCrawls::default())),
+        })
+    }
+}
+
+/// Failure to obtain a response from the API.
+#[derive(Debug)]
+pub(crate) enum SendError {
+    Http(reqwest::Error),
+    Timeout { url: String, elapsed: Duration },
+}
+
+impl SendError {
+    /// Converts into a [`FirecrawlError`] for the given action.
+    pub(crate) fn with_action(self, action: impl Into<String>) -> FirecrawlError {
+        match self {
+            SendError::Http(e) => FirecrawlError::HttpError(action.into(), e),
+            SendError::Timeout { url, elapsed } => FirecrawlError::Timeout { url, elapsed },
+        }
+    }
+}
+
+impl From<reqwest::Error> for SendError {
+    fn from(error: reqwest::Error) -> Self {
+        SendError::Http(error)
+    }
+}
+
 /// Firecrawl API v2 client.
 ///
 /// This client provides access to all v2 API endpoints including scrape, crawl,
@@ -22,10 +372,13 @@ const CLOUD_API_URL: &str = "https://api.firecrawl.dev";
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
 ///     // Create a client for the Firecrawl cloud service
-///     let client = Client::new("your-api-key")?;
+///     let client = Client::builder().api_key("your-api-key").build()?;
 ///
 ///     // Or create a client for a self-hosted instance
-///     let client = Client::new_selfhosted("http://localhost:3000", Some("api-key"))?;
+///     let client = Client::builder()
+///         .base_url("http://localhost:3000")
+///         .api_key("api-key")
+///         .build()?;
 ///
 ///     Ok(())
 /// }
@@ -35,9 +388,161 @@ pub struct Client {
     pub(crate) api_key: Option<String>,
     pub(crate) api_url: String,
     pub(crate) client: reqwest::Client,
+    pub(crate) retry_config: RetryConfig,
+    pub(crate) tracing: bool,
+    pub(crate) timeout_per_request: Option<Duration>,
+    pub(crate) credits_used: Arc<AtomicU64>,
+    /// Crawls started through `start_crawl_idempotent`, keyed by idempotency key.
+    pub(crate) idempotent_crawls: Arc<Mutex<IdempotentCrawls>>,
+}
+
+/// Usage statistics collected by a [`Client`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClientStats {
+    /// Credits reported by the API since the client was created or the
+    /// counter was last reset.
+    pub credits_used: u64,
+}
+
+/// Credit balance and plan details for the authenticated team, as reported
+/// by `GET /team/credit-usage`.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInfo {
+    /// Credits left in the current billing period. Can go negative when the
+    /// team has overage enabled.
+    pub remaining_credits: i64,
+    /// Credits included in the team's plan for each billing period.
+    pub plan_credits: u64,
+    /// Start of the current billing period (ISO 8601), if the team has one.
+    pub billing_period_start: Option<String>,
+    /// End of the current billing period (ISO 8601), if the team has one.
+    pub billing_period_end: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AccountInfoResponse {
+    data: AccountInfo,
+}
+
+/// Credits reported by a successful response: `data.metadata.creditsUsed`
+/// for single documents, or a top-level `creditsUsed`. Job status responses
+/// only count once the job has finished, as the total grows while it runs.
+fn response_credits(value: &Value, action: &str) -> Option<u64> {
+    if let Some(credits) = value["data"]["metadata"]["creditsUsed"].as_u64() {
+        return Some(credits);
+    }
+
+    // Follow-up pages repeat the job total.
+    if action.contains("pagination") {
+        return None;
+    }
+    match value["status"].as_str() {
+        None | Some("completed") | Some("failed") | Some("cancelled") => {
+            value["creditsUsed"].as_u64()
+        }
+        Some(_) => None,
+    }
 }
 
 impl Client {
+    /// Returns a [`ClientBuilder`] for configuring a client.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
+    /// Returns the number of credits reported by the API for requests made
+    /// through this client (and its clones).
+    ///
+    /// Polling a finished crawl or batch scrape more than once counts its
+    /// credits each time.
+    pub fn credits_used(&self) -> u64 {
+        self.credits_used.load(Ordering::Relaxed)
+    }
+
+    /// Resets the credits counter to zero.
+    pub fn reset_credits_counter(&self) {
+        self.credits_used.store(0, Ordering::Relaxed);
+    }
+
+    /// Returns usage statistics for this client.
+    pub fn stats(&self) -> ClientStats {
+        ClientStats {
+            credits_used: self.credits_used(),
+        }
+    }
+
+    /// Fetches the credit balance and plan of the account behind the API key.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
+    ///
+    ///     let account = client.get_account_info().await?;
+    ///     println!("{} of {} credits left", account.remaining_credits, account.plan_credits);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_account_info(&self) -> Result<AccountInfo, FirecrawlError> {
+        let response = self
+            .send(
+                self.client
+                    .get(self.url("/team/credit-usage"))
+                    .headers(self.prepare_headers(None)),
+            )
+            .await
+            .map_err(|e| e.with_action("Fetching account info"))?;
+
+        let result: AccountInfoResponse = self.handle_response(response, "account info").await?;
+        Ok(result.data)
+    }
+
+    /// Checks that the service is reachable and accepts the API key, using
+    /// the team credit usage endpoint as an authenticated probe.
+    ///
+    /// The request follows the client's retry policy and is bounded by
+    /// `timeout` and `timeout_per_request`; with neither set, an unresponsive
+    /// server keeps the check waiting indefinitely.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FirecrawlError::Unauthorized`] when the API key is rejected,
+    /// [`FirecrawlError::ServerError`] on 5xx responses, and
+    /// [`FirecrawlError::Timeout`] or [`FirecrawlError::HttpError`] when the
+    /// service can't be reached.
+    pub async fn health_check(&self) -> Result<bool, FirecrawlError> {
+        let response = self
+            .send(
+                self.client
+                    .get(self.url("/team/credit-usage"))
+                    .headers(self.prepare_headers(None)),
+            )
+            .await
+            .map_err(|e| e.with_action("Health check"))?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(true);
+        }
+        let retry_after = retry_after(&response);
+        let body = response.text().await.unwrap_or_default();
+        Err(
+            FirecrawlError::from_status(status, body, retry_after).unwrap_or_else(|| {
+                FirecrawlError::HttpRequestFailed(
+                    "Health check".to_string(),
+                    status.as_u16(),
+                    status.as_str().to_string(),
+                )
+            }),
+        )
+    }
+
     /// Creates a new client for the Firecrawl cloud service.
     ///
     /// # Arguments
@@ -55,8 +560,9 @@ impl Client {
     ///
     /// let client = Client::new("your-api-key").unwrap();
     /// ```
+    #[deprecated(note = "use `Client::builder().api_key(..).build()` instead")]
     pub fn new(api_key: impl AsRef<str>) -> Result<Self, FirecrawlError> {
-        Client::new_selfhosted(CLOUD_API_URL, Some(api_key))
+        Client::builder().api_key(api_key).build()
     }
 
     /// Creates a new client for a self-hosted Firecrawl instance.
@@ -81,23 +587,16 @@ impl Client {
     /// // Self-hosted with authentication
     /// let client = Client::new_selfhosted("http://localhost:3000", Some("api-key")).unwrap();
     /// ```
+    #[deprecated(note = "use `Client::builder().base_url(..).build()` instead")]
     pub fn new_selfhosted(
         api_url: impl AsRef<str>,
         api_key: Option<impl AsRef<str>>,
     ) -> Result<Self, FirecrawlError> {
-        // Normalize URL by trimming trailing slashes for consistent comparison
-        let url = api_url.as_ref().trim_end_matches('/').to_string();
-        let api_key = api_key.map(|k| k.as_ref().to_string());
-
-        // An empty/missing API key is allowed: scrape, search, and interact fall
-        // back to the keyless free tier (rate-limited per IP). Other methods
-        // return 401 from the API until a key is provided.
-
-        Ok(Client {
-            api_key,
-            api_url: url,
-            client: reqwest::Client::new(),
-        })
+        let builder = Client::builder().base_url(api_url);
+        match api_key {
+            Some(api_key) => builder.api_key(api_key).build(),
+            None => builder.build(),
+        }
     }
 
     /// Prepares headers for API requests.
@@ -138,6 +637,114 @@ impl Client {
         headers
     }
 
+    /// Sends a request, retrying transient failures according to the
+    /// client's [`RetryConfig`].
+    pub(crate) async fn send(&self, request: RequestBuilder) -> Result<Response, SendError> {
+        let may_retry = self.retry_config.retry_non_idempotent
+            || request
+                .try_clone()
+                .and_then(|clone| clone.build().ok())
+                .is_some_and(|request| is_idempotent(&request));
+        if !may_retry {
+            return self.execute(request).await;
+        }
+
+        let mut attempt = 1;
+        loop {
+            // Streaming bodies (e.g. multipart uploads) can't be replayed.
+            let retryable = match request.try_clone() {
+                Some(retryable) if attempt < self.retry_config.max_attempts => retryable,
+                _ => return self.execute(request).await,
+            };
+
+            let delay = match self.execute(retryable).await {
+                Ok(response) if !is_retryable_status(response.status()) => return Ok(response),
+                Ok(response) => match retry_after(&response) {
+                    Some(wait)
+                        if wait > Duration::from_millis(self.retry_config.max_retry_after_ms) =>
+                    {
+                        return Ok(response)
+                    }
+                    wait => self.retry_config.delay(attempt, wait),
+                },
+                Err(SendError::Http(e)) if e.is_connect() || e.is_timeout() => {
+                    self.retry_config.delay(attempt, None)
+                }
+                Err(SendError::Timeout { .. }) => self.retry_config.delay(attempt, None),
+                Err(e) => return Err(e),
+            };
+
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Sends a single request, emitting `tracing` events when enabled.
+    async fn execute(&self, request: RequestBuilder) -> Result<Response, SendError> {
+        let (client, request) = request.build_split();
+        let request = request?;
+
+        if !self.tracing {
+            return self.execute_with_timeout(&client, request).await;
+        }
+
+        let span = tracing::debug_span!(
+            "firecrawl_request",
+            method = %request.method(),
+            url = %request.url(),
+        );
+
+        async move {
+            if std::env::var("FIRECRAWL_LOG_BODIES").as_deref() == Ok("1") {
+                if let Some(body) = request.body().and_then(|body| body.as_bytes()) {
+                    tracing::trace!(body = %String::from_utf8_lossy(body), "request body");
+                }
+            }
+
+            let start = Instant::now();
+            let result = self.execute_with_timeout(&client, request).await;
+            let elapsed_ms = start.elapsed().as_millis() as u64;
+
+            match &result {
+                Ok(response) if response.status().is_success() => {
+                    tracing::debug!(status = response.status().as_u16(), elapsed_ms, "response");
+                }
+                Ok(response) => {
+                    tracing::warn!(
+                        status = response.status().as_u16(),
+                        elapsed_ms,
+                        "unsuccessful response"
+                    );
+                }
+                Err(e) => tracing::warn!(error = ?e, elapsed_ms, "request failed"),
+            }
+
+            result
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Executes a request, giving up after the client's per-request timeout.
+    async fn execute_with_timeout(
+        &self,
+        client: &reqwest::Client,
+        request: reqwest::Request,
+    ) -> Result<Response, SendError> {
+        let Some(limit) = self.timeout_per_request else {
+            return Ok(client.execute(request).await?);
+        };
+
+        let url = request.url().to_string();
+        match tokio::time::timeout(limit, client.execute(request)).await {
+            Ok(result) => Ok(result?),
+            Err(_) => Err(SendError::Timeout {
+                url,
+                elapsed: limit,
+            }),
+        }
+    }
+
     /// Handles API responses, parsing JSON and handling errors.
     pub(crate) async fn handle_response<T: DeserializeOwned>(
         &self,
@@ -146,9 +753,17 @@ impl Client {
     ) -> Result<T, FirecrawlError> {
         let (is_success, status) = (response.status().is_success(), response.status());
 
-        let response = response
-            .text()
-            .await
+        let retry_after = retry_after(&response);
+        let body = response.text().await;
+
+        if !is_success {
+            let text = body.as_deref().unwrap_or_default().to_string();
+            if let Some(error) = FirecrawlError::from_status(status, text, retry_after) {
+                return Err(error);
+            }
+        }
+
+        let response = body
             .map_err(FirecrawlError::ResponseParseErrorText)
             .and_then(|response_json| {
                 serde_json::from_str::<Value>(&response_json)
@@ -161,6 +776,9 @@ impl Client {
                     || response_value["success"].as_bool().unwrap_or(false)
                     || response_value.get("success").is_none()
                 {
+                    if let Some(credits) = response_credits(&response_value, action.as_ref()) {
+                        self.credits_used.fetch_add(credits, Ordering::Relaxed);
+                    }
                     serde_json::from_value::<T>(response_value)
                         .map_err(FirecrawlError::ResponseParseError)
                 } else {
@@ -201,6 +819,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(deprecated)]
     fn test_new_client() {
         let client = Client::new("test-api-key").unwrap();
         assert_eq!(client.api_key, Some("test-api-key".to_string()));
@@ -208,6 +827,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_new_client_allows_no_api_key_for_cloud_keyless() {
         // Keyless free tier: a cloud client with no API key is now allowed.
         let client = Client::new_selfhosted(CLOUD_API_URL, None::<&str>).unwrap();
@@ -215,6 +835,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_new_client_allows_empty_api_key_for_cloud_keyless() {
         // Empty/whitespace keys are no longer rejected (treated as keyless).
         assert!(Client::new_selfhosted(CLOUD_API_URL, Some("")).is_ok());
@@ -222,6 +843,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_new_selfhosted_client() {
         let client = Client::new_selfhosted("http://localhost:3000", Some("api-key")).unwrap();
         assert_eq!(client.api_key, Some("api-key".to_string()));
@@ -229,6 +851,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_selfhosted_without_api_key() {
         let client = Client::new_selfhosted("http://localhost:3000", None::<&str>).unwrap();
         assert_eq!(client.api_key, None);
@@ -236,12 +859,14 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_url_builder() {
         let client = Client::new("test-key").unwrap();
         assert_eq!(client.url("/scrape"), "https://api.firecrawl.dev/v2/scrape");
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_url_normalization_trailing_slash() {
         // Cloud URL with trailing slash is normalized; no API key required (keyless).
         let client = Client::new_selfhosted("https://api.firecrawl.dev/", None::<&str>).unwrap();
@@ -255,4 +880,534 @@ mod tests {
         let client = Client::new_selfhosted("http://localhost:3000/", None::<&str>).unwrap();
         assert_eq!(client.api_url, "http://localhost:3000");
     }
+
+    #[test]
+    fn test_builder_defaults() {
+        let client = Client::builder().build().unwrap();
+        assert_eq!(client.api_key, None);
+        assert_eq!(client.api_url, CLOUD_API_URL);
+        assert_eq!(client.retry_config, RetryConfig::default());
+    }
+
+    #[test]
+    fn test_builder_api_key_and_base_url() {
+        let client = Client::builder()
+            .api_key("key")
+            .base_url("http://localhost:3000/")
+            .build()
+            .unwrap();
+        assert_eq!(client.api_key, Some("key".to_string()));
+        assert_eq!(client.api_url, "http://localhost:3000");
+    }
+
+    #[test]
+    fn test_builder_timeouts() {
+        let builder = Client::builder()
+            .timeout(Duration::from_secs(30))
+            .connect_timeout(Duration::from_secs(5));
+        assert_eq!(builder.timeout, Some(Duration::from_secs(30)));
+        assert_eq!(builder.connect_timeout, Some(Duration::from_secs(5)));
+        assert!(builder.build().is_ok());
+    }
+
+    #[test]
+    fn test_builder_proxy() {
+        let builder = Client::builder().proxy("http://proxy.local:8080");
+        assert_eq!(builder.proxy.as_deref(), Some("http://proxy.local:8080"));
+        assert!(builder.build().is_ok());
+
+        let result = Client::builder().proxy("not a url").build();
+        assert!(matches!(result, Err(FirecrawlError::Misuse(_))));
+    }
+
+    #[test]
+    fn test_builder_retry() {
+        let client = Client::builder()
+            .retry(RetryConfig::disabled())
+            .build()
+            .unwrap();
+        assert_eq!(client.retry_config.max_attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_builder_user_agent() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/map")
+            .match_header("user-agent", "my-crawler/1.0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success": true, "links": []}"#)
+            .create_async()
+            .await;
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .user_agent("my-crawler/1.0")
+            .build()
+            .unwrap();
+
+        client.map("https://example.com", None).await.unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_credits_counter() {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("POST", "/v2/scrape")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"success": true, "data": {"markdown": "hi", "metadata": {"creditsUsed": 2}}}"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/v2/crawl/running")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status": "scraping", "total": 2, "completed": 1, "creditsUsed": 1, "data": []}"#)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/v2/crawl/done")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status": "completed", "total": 2, "completed": 2, "creditsUsed": 5, "data": []}"#)
+            .create_async()
+            .await;
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test-key")
+            .build()
+            .unwrap();
+        assert_eq!(client.credits_used(), 0);
+
+        client.scrape("https://example.com", None).await.unwrap();
+        assert_eq!(client.credits_used(), 2);
+
+        client.get_crawl_status("running").await.unwrap();
+        assert_eq!(client.credits_used(), 2);
+
+        client.get_crawl_status("done").await.unwrap();
+        assert_eq!(client.clone().stats(), ClientStats { credits_used: 7 });
+
+        client.reset_credits_counter();
+        assert_eq!(client.credits_used(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_get_account_info() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v2/team/credit-usage")
+            .match_header("authorization", "Bearer test-key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"success": true, "data": {"remainingCredits": 380, "planCredits": 500, "billingPeriodStart": "2026-10-01T00:00:00.000Z", "billingPeriodEnd": "2026-11-01T00:00:00.000Z"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test-key")
+            .build()
+            .unwrap();
+        let account = client.get_account_info().await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(
+            account,
+            AccountInfo {
+                remaining_credits: 380,
+                plan_credits: 500,
+                billing_period_start: Some("2026-10-01T00:00:00.000Z".to_string()),
+                billing_period_end: Some("2026-11-01T00:00:00.000Z".to_string()),
+            }
+        );
+        assert_eq!(client.credits_used(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_health_check() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test-key")
+            .retry(RetryConfig::disabled())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/v2/team/credit-usage")
+            .match_header("authorization", "Bearer test-key")
+            .with_status(200)
+            .with_body(r#"{"success": true, "data": {}}"#)
+            .create_async()
+            .await;
+        assert!(client.health_check().await.unwrap());
+        mock.assert_async().await;
+        mock.remove_async().await;
+
+        let mock = server
+            .mock("GET", "/v2/team/credit-usage")
+            .with_status(401)
+            .with_body(r#"{"success": false, "error": "Unauthorized"}"#)
+            .create_async()
+            .await;
+        assert!(matches!(
+            client.health_check().await,
+            Err(FirecrawlError::Unauthorized)
+        ));
+        let builder = Client::builder()
+            .base_url(server.url())
+            .api_key("bad-key")
+            .with_health_check(true);
+        assert!(matches!(
+            builder.connect().await,
+            Err(FirecrawlError::Unauthorized)
+        ));
+        mock.remove_async().await;
+
+        server
+            .mock("GET", "/v2/team/credit-usage")
+            .with_status(503)
+            .with_body("Service Unavailable")
+            .create_async()
+            .await;
+        assert!(matches!(
+            client.health_check().await,
+            Err(FirecrawlError::ServerError { status: 503, .. })
+        ));
+
+        let builder = Client::builder().with_health_check(true);
+        assert!(matches!(builder.build(), Err(FirecrawlError::Misuse(_))));
+    }
+
+    #[tokio::test]
+    async fn test_typed_status_errors() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::builder()
+            .base_url(server.url())
+            .retry(RetryConfig::disabled())
+            .build()
+            .unwrap();
+
+        let cases: [(usize, &str, Option<&str>); 6] = [
+            (401, r#"{"success": false, "error": "Unauthorized"}"#, None),
+            (
+                402,
+                r#"{"success": false, "error": "Insufficient credits"}"#,
+                None,
+            ),
+            (403, r#"{"success": false, "error": "Forbidden"}"#, None),
+            (404, r#"{"success": false, "error": "Job not found"}"#, None),
+            (
+                429,
+                r#"{"success": false, "error": "Slow down"}"#,
+                Some("7"),
+            ),
+            (502, "Bad Gateway", None),
+        ];
+
+        let mut errors = Vec::new();
+        for (status, body, retry_after) in cases {
+            let mut mock = server
+                .mock("GET", "/v2/crawl/job")
+                .with_status(status)
+                .with_body(body);
+            if let Some(retry_after) = retry_after {
+                mock = mock.with_header("Retry-After", retry_after);
+            }
+            let mock = mock.create_async().await;
+            errors.push(client.get_crawl_status("job").await.unwrap_err());
+            mock.remove_async().await;
+        }
+
+        assert!(matches!(errors[0], FirecrawlError::Unauthorized));
+        assert!(matches!(
+            &errors[1],
+            FirecrawlError::PaymentRequired { message } if message == "Insufficient credits"
+        ));
+        assert!(matches!(errors[2], FirecrawlError::Forbidden));
+        assert!(matches!(&errors[3], FirecrawlError::NotFound(m) if m == "Job not found"));
+        assert!(matches!(
+            errors[4],
+            FirecrawlError::RateLimited {
+                retry_after: Some(d)
+            } if d == Duration::from_secs(7)
+        ));
+        assert!(matches!(
+            &errors[5],
+            FirecrawlError::ServerError { status: 502, body } if body == "Bad Gateway"
+        ));
+    }
+
+    #[test]
+    fn test_retry_delay_backoff() {
+        let config = RetryConfig {
+            max_attempts: 5,
+            initial_delay_ms: 100,
+            max_delay_ms: 350,
+            jitter: false,
+            retry_non_idempotent: false,
+            max_retry_after_ms: 60_000,
+        };
+        assert_eq!(config.delay(1, None), Duration::from_millis(100));
+        assert_eq!(config.delay(2, None), Duration::from_millis(200));
+        assert_eq!(config.delay(3, None), Duration::from_millis(350));
+        assert_eq!(config.delay(64, None), Duration::from_millis(350));
+        assert_eq!(
+            config.delay(1, Some(Duration::from_secs(10))),
+            Duration::from_secs(10)
+        );
+
+        let jittered = RetryConfig {
+            jitter: true,
+            ..config
+        };
+        for attempt in 1..5 {
+            assert!(jittered.delay(attempt, None) <= Duration::from_millis(350));
+        }
+    }
+
+    fn fast_retry(max_attempts: u32) -> RetryConfig {
+        RetryConfig {
+            max_attempts,
+            initial_delay_ms: 1,
+            max_delay_ms: 10,
+            jitter: false,
+            retry_non_idempotent: true,
+            max_retry_after_ms: 1_000,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_does_not_wait_past_max_retry_after() {
+        let mut server = mockito::Server::new_async().await;
+
+        let rate_limited = server
+            .mock("POST", "/v2/map")
+            .with_status(429)
+            .with_header("Retry-After", "3600")
+            .with_body(r#"{"success": false, "error": "Rate limited"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test-key")
+            .retry(fast_retry(3))
+            .build()
+            .unwrap();
+
+        let start = Instant::now();
+        let result = client.map("https://example.com", None).await;
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert!(matches!(
+            result,
+            Err(FirecrawlError::RateLimited {
+                retry_after: Some(d)
+            }) if d == Duration::from_secs(3600)
+        ));
+        rate_limited.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_retries_only_idempotent_requests_by_default() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test-key")
+            .retry(RetryConfig {
+                retry_non_idempotent: false,
+                ..fast_retry(2)
+            })
+            .build()
+            .unwrap();
+
+        let map = server
+            .mock("POST", "/v2/map")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+        assert!(client.map("https://example.com", None).await.is_err());
+        map.assert_async().await;
+
+        let crawl = server
+            .mock("POST", "/v2/crawl")
+            .match_header("x-idempotency-key", "key-1")
+            .with_status(503)
+            .expect(2)
+            .create_async()
+            .await;
+        let options = crate::CrawlOptions {
+            idempotency_key: Some("key-1".to_string()),
+            ..Default::default()
+        };
+        assert!(client
+            .start_crawl("https://example.com", options)
+            .await
+            .is_err());
+        crawl.assert_async().await;
+
+        let status = server
+            .mock("GET", "/v2/team/credit-usage")
+            .with_status(503)
+            .expect(2)
+            .create_async()
+            .await;
+        assert!(client.get_account_info().await.is_err());
+        status.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_retries_after_429() {
+        let mut server = mockito::Server::new_async().await;
+
+        let rate_limited = server
+            .mock("POST", "/v2/map")
+            .with_status(429)
+            .with_header("Retry-After", "0")
+            .with_body(r#"{"success": false, "error": "Rate limited"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let ok = server
+            .mock("POST", "/v2/map")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success": true, "links": []}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test-key")
+            .retry(fast_retry(3))
+            .build()
+            .unwrap();
+
+        let response = client.map("https://example.com", None).await.unwrap();
+        assert!(response.success);
+        rate_limited.assert_async().await;
+        ok.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_attempts() {
+        let mut server = mockito::Server::new_async().await;
+
+        let unavailable = server
+            .mock("POST", "/v2/map")
+            .with_status(503)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .retry(fast_retry(2))
+            .build()
+            .unwrap();
+
+        let result = client.map("https://example.com", None).await;
+        assert!(matches!(
+            result,
+            Err(FirecrawlError::ServerError { status: 503, .. })
+        ));
+        unavailable.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_does_not_retry_client_errors() {
+        let mut server = mockito::Server::new_async().await;
+
+        let bad_request = server
+            .mock("POST", "/v2/map")
+            .with_status(400)
+            .with_body(r#"{"success": false, "error": "Bad request"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .retry(fast_retry(3))
+            .build()
+            .unwrap();
+
+        assert!(client.map("https://example.com", None).await.is_err());
+        bad_request.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_tracing_client_retries_and_succeeds() {
+        let mut server = mockito::Server::new_async().await;
+
+        let unavailable = server
+            .mock("POST", "/v2/map")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+        let ok = server
+            .mock("POST", "/v2/map")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success": true, "links": []}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .retry(fast_retry(2))
+            .tracing(true)
+            .build()
+            .unwrap();
+
+        assert!(client.tracing);
+        client.map("https://example.com", None).await.unwrap();
+        unavailable.assert_async().await;
+        ok.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_timeout_per_request() {
+        // A server that accepts connections but never responds.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut connections = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                connections.push(socket);
+            }
+        });
+
+        let client = Client::builder()
+            .base_url(format!("http://{}", addr))
+            .retry(RetryConfig::disabled())
+            .timeout_per_request(Duration::from_millis(50))
+            .build()
+            .unwrap();
+
+        let result = client.map("https://example.com", None).await;
+        match result {
+            Err(FirecrawlError::Timeout { url, elapsed }) => {
+                assert_eq!(url, format!("http://{}/v2/map", addr));
+                assert_eq!(elapsed, Duration::from_millis(50));
+            }
+            other => panic!("expected timeout, got {:?}", other),
+        }
+    }
 }
@@ -1,6 +1,10 @@
 //! Crawl endpoint for Firecrawl API v2.
 
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::future::Future;
+
 use serde::{Deserialize, Serialize};
+use tokio_stream::Stream;
 
 use crate::client::Client;
 use crate::scrape::ScrapeOptions;
@@ -8,6 +12,12 @@ use crate::types::{CrawlErrorsResponse, Document, JobStatus, SitemapMode, Webhoo
 use crate::FirecrawlError;
 
 /// Options for crawling a website.
+///
+/// The crawl API can't rewrite discovered URLs (for example to strip
+/// tracking parameters). Use `ignore_query_parameters` to stop the server
+/// from crawling the same path twice with different query strings, and
+/// `normalize_urls` to drop duplicate source URLs from [`Client::crawl`]
+/// results.
 #[serde_with::skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -67,6 +77,246 @@ pub struct CrawlOptions {
     /// Poll interval for synchronous crawl (milliseconds).
     #[serde(skip)]
     pub poll_interval: Option<u64>,
+
+    /// Drop documents from [`Client::crawl`] results whose source URL was
+    /// already returned, keeping the first occurrence. URLs are compared after
+    /// sorting query parameters and dropping the fragment and trailing slash.
+    #[serde(skip)]
+    pub normalize_urls: bool,
+}
+
+/// Builder for [`CrawlOptions`] with chainable setters.
+///
+/// # Example
+///
+/// ```
+/// use firecrawl::{CrawlOptions, Format, ScrapeOptions};
+///
+/// let options = CrawlOptions::builder()
+///     .limit(50)
+///     .webhook("https://example.com/webhook")
+///     .scrape_options(ScrapeOptions::builder().formats(vec![Format::Markdown]))
+///     .build();
+///
+/// assert_eq!(options.limit, Some(50));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct CrawlOptionsBuilder {
+    options: CrawlOptions,
+}
+
+impl CrawlOptions {
+    /// Returns a builder for crawl options.
+    pub fn builder() -> CrawlOptionsBuilder {
+        CrawlOptionsBuilder::default()
+    }
+
+    /// Sets a freshly generated UUID v4 as the idempotency key.
+    pub fn with_idempotency(mut self) -> Self {
+        self.idempotency_key = Some(uuid::Uuid::new_v4().to_string());
+        self
+    }
+}
+
+impl CrawlOptionsBuilder {
+    /// Natural language prompt to guide crawl behavior.
+    pub fn prompt(&mut self, prompt: impl Into<String>) -> &mut Self {
+        self.options.prompt = Some(prompt.into());
+        self
+    }
+
+    /// URL path patterns to exclude from crawling.
+    pub fn exclude_paths(&mut self, exclude_paths: Vec<String>) -> &mut Self {
+        self.options.exclude_paths = Some(exclude_paths);
+        self
+    }
+
+    /// URL path patterns to include in crawling.
+    pub fn include_paths(&mut self, include_paths: Vec<String>) -> &mut Self {
+        self.options.include_paths = Some(include_paths);
+        self
+    }
+
+    /// Maximum depth of links to follow from the initial URL.
+    pub fn max_discovery_depth(&mut self, max_discovery_depth: u32) -> &mut Self {
+        self.options.max_discovery_depth = Some(max_discovery_depth);
+        self
+    }
+
+    /// How to handle the sitemap.
+    pub fn sitemap(&mut self, sitemap: SitemapMode) -> &mut Self {
+        self.options.sitemap = Some(sitemap);
+        self
+    }
+
+    /// Ignore query parameters when deduplicating URLs.
+    pub fn ignore_query_parameters(&mut self, ignore_query_parameters: bool) -> &mut Self {
+        self.options.ignore_query_parameters = Some(ignore_query_parameters);
+        self
+    }
+
+    /// Maximum number of pages to crawl.
+    pub fn limit(&mut self, limit: u32) -> &mut Self {
+        self.options.limit = Some(limit);
+        self
+    }
+
+    /// Crawl the entire domain regardless of path structure.
+    pub fn crawl_entire_domain(&mut self, crawl_entire_domain: bool) -> &mut Self {
+        self.options.crawl_entire_domain = Some(crawl_entire_domain);
+        self
+    }
+
+    /// Allow following links to external domains.
+    pub fn allow_external_links(&mut self, allow_external_links: bool) -> &mut Self {
+        self.options.allow_external_links = Some(allow_external_links);
+        self
+    }
+
+    /// Allow following links to subdomains.
+    pub fn allow_subdomains(&mut self, allow_subdomains: bool) -> &mut Self {
+        self.options.allow_subdomains = Some(allow_subdomains);
+        self
+    }
+
+    /// Delay between requests in seconds.
+    pub fn delay(&mut self, delay: u32) -> &mut Self {
+        self.options.delay = Some(delay);
+        self
+    }
+
+    /// Maximum concurrent requests.
+    pub fn max_concurrency(&mut self, max_concurrency: u32) -> &mut Self {
+        self.options.max_concurrency = Some(max_concurrency);
+        self
+    }
+
+    /// Webhook configuration for job notifications.
+    pub fn webhook(&mut self, webhook: impl Into<WebhookConfig>) -> &mut Self {
+        self.options.webhook = Some(webhook.into());
+        self
+    }
+
+    /// Scrape options to apply to each page.
+    ///
+    /// Accepts either [`ScrapeOptions`] or a [`ScrapeOptionsBuilder`](crate::ScrapeOptionsBuilder).
+    pub fn scrape_options(&mut self, scrape_options: impl Into<ScrapeOptions>) -> &mut Self {
+        self.options.scrape_options = Some(scrape_options.into());
+        self
+    }
+
+    /// Enable zero data retention mode.
+    pub fn zero_data_retention(&mut self, zero_data_retention: bool) -> &mut Self {
+        self.options.zero_data_retention = Some(zero_data_retention);
+        self
+    }
+
+    /// Integration identifier for tracking.
+    pub fn integration(&mut self, integration: impl Into<String>) -> &mut Self {
+        self.options.integration = Some(integration.into());
+        self
+    }
+
+    /// Idempotency key for the request.
+    pub fn idempotency_key(&mut self, idempotency_key: impl Into<String>) -> &mut Self {
+        self.options.idempotency_key = Some(idempotency_key.into());
+        self
+    }
+
+    /// Poll interval for synchronous crawl (milliseconds).
+    pub fn poll_interval(&mut self, poll_interval: u64) -> &mut Self {
+        self.options.poll_interval = Some(poll_interval);
+        self
+    }
+
+    /// Drop documents with duplicate source URLs from [`Client::crawl`] results.
+    pub fn normalize_urls(&mut self, normalize_urls: bool) -> &mut Self {
+        self.options.normalize_urls = normalize_urls;
+        self
+    }
+
+    /// Builds the crawl options.
+    pub fn build(&self) -> CrawlOptions {
+        self.options.clone()
+    }
+}
+
+/// Normalizes a URL for comparison: sorts query parameters and drops the
+/// fragment and any trailing slash. Unparseable URLs are only trimmed.
+fn normalize_url(url: &str) -> String {
+    let Ok(mut parsed) = reqwest::Url::parse(url.trim()) else {
+        return url.trim().trim_end_matches('/').to_string();
+    };
+
+    if parsed.query().is_some() {
+        let mut pairs: Vec<(String, String)> = parsed.query_pairs().into_owned().collect();
+        pairs.sort();
+        if pairs.is_empty() {
+            parsed.set_query(None);
+        } else {
+            parsed.query_pairs_mut().clear().extend_pairs(pairs);
+        }
+    }
+    parsed.set_fragment(None);
+
+    let path = parsed.path();
+    if path.len() > 1 && path.ends_with('/') {
+        let trimmed = path.trim_end_matches('/');
+        let trimmed = if trimmed.is_empty() { "/" } else { trimmed }.to_string();
+        parsed.set_path(&trimmed);
+    }
+    parsed.to_string()
+}
+
+/// Removes documents with duplicate source URLs, keeping the first occurrence.
+fn deduplicate_documents(documents: &mut Vec<Document>) {
+    let mut seen = HashSet::new();
+    documents.retain(|document| {
+        match document
+            .metadata
+            .as_ref()
+            .and_then(|m| m.source_url.as_deref())
+        {
+            Some(url) => seen.insert(normalize_url(url)),
+            None => true,
+        }
+    });
+}
+
+/// Maximum number of crawls remembered by
+/// [`Client::start_crawl_idempotent`].
+const MAX_IDEMPOTENT_CRAWLS: usize = 1_000;
+
+/// Crawls started through [`Client::start_crawl_idempotent`], keyed by
+/// idempotency key. Crawls are forgotten once they reach a terminal state,
+/// and only the most recent [`MAX_IDEMPOTENT_CRAWLS`] are kept.
+#[derive(Debug, Default)]
+pub(crate) struct IdempotentCrawls {
+    crawls: HashMap<String, CrawlResponse>,
+    order: VecDeque<String>,
+}
+
+impl IdempotentCrawls {
+    fn get(&self, key: &str) -> Option<&CrawlResponse> {
+        self.crawls.get(key)
+    }
+
+    fn insert(&mut self, key: String, crawl: CrawlResponse) {
+        if self.crawls.insert(key.clone(), crawl).is_none() {
+            self.order.push_back(key);
+        }
+        while self.order.len() > MAX_IDEMPOTENT_CRAWLS {
+            if let Some(oldest) = self.order.pop_front() {
+                self.crawls.remove(&oldest);
+            }
+        }
+    }
+
+    /// Forgets the crawl with the given job ID.
+    fn remove_job(&mut self, id: &str) {
+        self.crawls.retain(|_, crawl| crawl.id != id);
+        self.order.retain(|key| self.crawls.contains_key(key));
+    }
 }
 
 /// Request body for crawl endpoint.
@@ -111,6 +361,15 @@ pub struct CrawlJob {
     pub data: Vec<Document>,
 }
 
+/// A page of a crawl or batch scrape job's status, as read by
+/// [`Client::job_document_stream`].
+#[derive(Deserialize)]
+struct JobPage {
+    status: JobStatus,
+    next: Option<String>,
+    data: Vec<Document>,
+}
+
 /// Response from canceling a crawl.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -140,7 +399,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     let response = client.start_crawl("https://example.com", None).await?;
     ///     println!("Crawl job started: {}", response.id);
@@ -157,26 +416,106 @@ impl Client {
         url: impl AsRef<str>,
         options: impl Into<Option<CrawlOptions>>,
     ) -> Result<CrawlResponse, FirecrawlError> {
-        let options = options.into().unwrap_or_default();
-        let body = CrawlRequest {
-            url: url.as_ref().to_string(),
-            options: options.clone(),
+        let response = self
+            .send_start_crawl(url.as_ref(), options.into().unwrap_or_default())
+            .await?;
+
+        self.handle_response(response, "start crawl").await
+    }
+
+    /// Starts a crawl job with the given idempotency key.
+    ///
+    /// Retrying with the same key is safe: if the API reports the key as
+    /// already used, the job previously started with it is returned instead
+    /// of an error.
+    ///
+    /// Recovery is per-process: jobs are remembered in memory by the client
+    /// (and its clones), so after a restart the existing job can only be
+    /// recovered if the API includes its ID in the conflict response. A job
+    /// is forgotten once a status check sees it completed, failed or
+    /// cancelled, and only the 1,000 most recently started jobs are kept.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
+    ///     let key = "2c0a7e8e-4f3b-4d57-9a8e-2f5b6c1d9e01";
+    ///
+    ///     let first = client.start_crawl_idempotent("https://example.com", None, key).await?;
+    ///     // Safe to call again after a failure; resolves to the same job.
+    ///     let again = client.start_crawl_idempotent("https://example.com", None, key).await?;
+    ///     assert_eq!(first.id, again.id);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn start_crawl_idempotent(
+        &self,
+        url: impl AsRef<str>,
+        options: impl Into<Option<CrawlOptions>>,
+        key: impl AsRef<str>,
+    ) -> Result<CrawlResponse, FirecrawlError> {
+        let key = key.as_ref().to_string();
+        let options = CrawlOptions {
+            idempotency_key: Some(key.clone()),
+            ..options.into().unwrap_or_default()
         };
 
-        let headers = self.prepare_headers(options.idempotency_key.as_ref());
+        let response = self.send_start_crawl(url.as_ref(), options).await?;
+
+        if response.status() == reqwest::StatusCode::CONFLICT {
+            let body: serde_json::Value = response.json().await.unwrap_or_default();
+            if let Some(id) = body["id"].as_str() {
+                return Ok(CrawlResponse {
+                    success: true,
+                    id: id.to_string(),
+                    url: body["url"]
+                        .as_str()
+                        .map(str::to_string)
+                        .unwrap_or_else(|| self.url(&format!("/crawl/{}", id))),
+                });
+            }
+            if let Some(existing) = self.idempotent_crawls.lock().unwrap().get(&key) {
+                return Ok(existing.clone());
+            }
+            return Err(FirecrawlError::APIError(
+                "start crawl".to_string(),
+                serde_json::from_value(body).map_err(FirecrawlError::ResponseParseError)?,
+            ));
+        }
 
-        let response = self
-            .client
-            .post(self.url("/crawl"))
-            .headers(headers)
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| {
-                FirecrawlError::HttpError(format!("Starting crawl of {:?}", url.as_ref()), e)
-            })?;
+        let crawl: CrawlResponse = self.handle_response(response, "start crawl").await?;
+        self.idempotent_crawls
+            .lock()
+            .unwrap()
+            .insert(key, crawl.clone());
+        Ok(crawl)
+    }
 
-        self.handle_response(response, "start crawl").await
+    /// Sends the request that starts a crawl job.
+    async fn send_start_crawl(
+        &self,
+        url: &str,
+        options: CrawlOptions,
+    ) -> Result<reqwest::Response, FirecrawlError> {
+        let headers = self.prepare_headers(options.idempotency_key.as_ref());
+        let body = CrawlRequest {
+            url: url.to_string(),
+            options,
+        };
+
+        self.send(
+            self.client
+                .post(self.url("/crawl"))
+                .headers(headers)
+                .json(&body),
+        )
+        .await
+        .map_err(|e| e.with_action(format!("Starting crawl of {:?}", url)))
     }
 
     /// Gets the status of a crawl job.
@@ -198,7 +537,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     let status = client.get_crawl_status("job-id").await?;
     ///     println!("Status: {:?}", status.status);
@@ -210,18 +549,20 @@ impl Client {
     /// ```
     pub async fn get_crawl_status(&self, id: impl AsRef<str>) -> Result<CrawlJob, FirecrawlError> {
         let response = self
-            .client
-            .get(self.url(&format!("/crawl/{}", id.as_ref())))
-            .headers(self.prepare_headers(None))
-            .send()
+            .send(
+                self.client
+                    .get(self.url(&format!("/crawl/{}", id.as_ref())))
+                    .headers(self.prepare_headers(None)),
+            )
             .await
-            .map_err(|e| {
-                FirecrawlError::HttpError(format!("Checking crawl status {}", id.as_ref()), e)
-            })?;
+            .map_err(|e| e.with_action(format!("Checking crawl status {}", id.as_ref())))?;
 
         let mut status: CrawlJob = self
             .handle_response(response, format!("crawl status {}", id.as_ref()))
             .await?;
+        if status.status != JobStatus::Scraping {
+            self.forget_idempotent_crawl(id.as_ref());
+        }
 
         // Auto-paginate if completed
         if status.status == JobStatus::Completed {
@@ -235,19 +576,72 @@ impl Client {
         Ok(status)
     }
 
+    /// Drops a finished crawl from the jobs remembered by
+    /// [`start_crawl_idempotent`](Self::start_crawl_idempotent).
+    pub(crate) fn forget_idempotent_crawl(&self, id: &str) {
+        self.idempotent_crawls.lock().unwrap().remove_job(id);
+    }
+
     /// Fetches the next page of crawl results.
     async fn get_crawl_status_next(&self, next: &str) -> Result<CrawlJob, FirecrawlError> {
         let response = self
-            .client
-            .get(next)
-            .headers(self.prepare_headers(None))
-            .send()
+            .send(self.client.get(next).headers(self.prepare_headers(None)))
             .await
-            .map_err(|e| FirecrawlError::HttpError(format!("Paginating crawl at {}", next), e))?;
+            .map_err(|e| e.with_action(format!("Paginating crawl at {}", next)))?;
 
         self.handle_response(response, "crawl pagination").await
     }
 
+    /// Iterates over the pages of a crawl job's results.
+    ///
+    /// Unlike [`get_crawl_status`](Self::get_crawl_status), pages are fetched
+    /// lazily: each item pulled from the stream makes exactly one HTTP request,
+    /// following the `next` link of the previous page. The stream ends once a
+    /// page without a `next` link has been yielded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::Client;
+    /// use tokio_stream::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
+    ///
+    ///     let pages = client.iter_crawl_pages("job-id");
+    ///     tokio::pin!(pages);
+    ///
+    ///     while let Some(page) = pages.next().await {
+    ///         println!("Fetched {} documents", page?.len());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn iter_crawl_pages(
+        &self,
+        id: impl AsRef<str>,
+    ) -> impl Stream<Item = Result<Vec<Document>, FirecrawlError>> + '_ {
+        let first = self.url(&format!("/crawl/{}", id.as_ref()));
+
+        async_stream::stream! {
+            let mut next = Some(first);
+            while let Some(page_url) = next.take() {
+                match self.get_crawl_status_next(&page_url).await {
+                    Ok(page) => {
+                        next = page.next;
+                        yield Ok(page.data);
+                    }
+                    Err(e) => {
+                        yield Err(e);
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
     /// Crawls a website and waits for completion.
     ///
     /// This method starts a crawl and polls until it completes or fails.
@@ -268,7 +662,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     let options = CrawlOptions {
     ///         sitemap: Some(SitemapMode::Include),
@@ -294,9 +688,164 @@ impl Client {
     ) -> Result<CrawlJob, FirecrawlError> {
         let options = options.into().unwrap_or_default();
         let poll_interval = options.poll_interval.unwrap_or(2000);
+        let normalize_urls = options.normalize_urls;
 
         let response = self.start_crawl(url, options).await?;
-        self.wait_for_crawl(&response.id, poll_interval).await
+        let mut job = self.wait_for_crawl(&response.id, poll_interval).await?;
+        if normalize_urls {
+            deduplicate_documents(&mut job.data);
+        }
+        Ok(job)
+    }
+
+    /// Crawls a website, yielding documents as they become available.
+    ///
+    /// Unlike [`Client::crawl`], documents are not collected in memory: the
+    /// crawl status is polled and only documents that haven't been yielded
+    /// yet are fetched. The stream ends once the crawl completes, and yields
+    /// an error if the job fails or is cancelled.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::{Client, CrawlOptions};
+    /// use tokio_stream::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
+    ///
+    ///     let stream = client.crawl_stream("https://example.com", None);
+    ///     tokio::pin!(stream);
+    ///
+    ///     while let Some(document) = stream.next().await {
+    ///         let document = document?;
+    ///         println!("URL: {:?}", document.metadata.and_then(|m| m.source_url));
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn crawl_stream(
+        &self,
+        url: impl AsRef<str>,
+        options: impl Into<Option<CrawlOptions>>,
+    ) -> impl Stream<Item = Result<Document, FirecrawlError>> + '_ {
+        let url = url.as_ref().to_string();
+        let options = options.into().unwrap_or_default();
+        let poll_interval = options.poll_interval.unwrap_or(2000);
+
+        self.job_document_stream(
+            async move { self.start_crawl(&url, options).await.map(|job| job.id) },
+            "/crawl",
+            "Crawl",
+            poll_interval,
+        )
+    }
+
+    /// Streams the documents of a crawl or batch scrape job as they become
+    /// available.
+    ///
+    /// `start` starts the job and resolves to its id. The job's status is then
+    /// polled at `{status_path}/{id}`, skipping documents that were already
+    /// yielded, every `poll_interval` milliseconds until it completes.
+    /// `job_label` names the job in errors, e.g. `"Crawl"`.
+    pub(crate) fn job_document_stream<'a>(
+        &'a self,
+        start: impl Future<Output = Result<String, FirecrawlError>> + 'a,
+        status_path: &'static str,
+        job_label: &'static str,
+        poll_interval: u64,
+    ) -> impl Stream<Item = Result<Document, FirecrawlError>> + 'a {
+        async_stream::stream! {
+            let id = match start.await {
+                Ok(id) => id,
+                Err(e) => {
+                    yield Err(e);
+                    return;
+                }
+            };
+
+            let mut yielded = 0usize;
+            loop {
+                let mut next = Some(self.url(&format!("{}/{}?skip={}", status_path, id, yielded)));
+                let mut status = JobStatus::Scraping;
+                let mut first_page = true;
+
+                while let Some(page_url) = next.take() {
+                    let page = match self.get_job_page(&page_url, job_label, first_page).await {
+                        Ok(page) => page,
+                        Err(e) => {
+                            yield Err(e);
+                            return;
+                        }
+                    };
+
+                    first_page = false;
+                    status = page.status;
+                    next = page.next;
+                    yielded += page.data.len();
+                    for document in page.data {
+                        yield Ok(document);
+                    }
+                }
+
+                if status != JobStatus::Scraping {
+                    self.forget_idempotent_crawl(&id);
+                }
+                match status {
+                    JobStatus::Completed => return,
+                    JobStatus::Scraping => {
+                        tokio::time::sleep(tokio::time::Duration::from_millis(poll_interval)).await;
+                    }
+                    JobStatus::Failed => {
+                        yield Err(FirecrawlError::JobFailed(
+                            format!("{} job failed", job_label),
+                            JobStatus::Failed,
+                        ));
+                        return;
+                    }
+                    JobStatus::Cancelled => {
+                        yield Err(FirecrawlError::JobFailed(
+                            format!("{} job was cancelled", job_label),
+                            JobStatus::Cancelled,
+                        ));
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Fetches one page of a crawl or batch scrape job's status.
+    ///
+    /// The first page of each poll is handled as a status response, so the
+    /// job's credits are counted once it reaches a terminal state; the pages
+    /// that follow only repeat that total.
+    async fn get_job_page(
+        &self,
+        url: &str,
+        job_label: &str,
+        first_page: bool,
+    ) -> Result<JobPage, FirecrawlError> {
+        let job_label = job_label.to_lowercase();
+        let response = self
+            .send(self.client.get(url).headers(self.prepare_headers(None)))
+            .await
+            .map_err(|e| {
+                if first_page {
+                    e.with_action(format!("Checking {} status {}", job_label, url))
+                } else {
+                    e.with_action(format!("Paginating {} at {}", job_label, url))
+                }
+            })?;
+
+        let action = if first_page {
+            format!("{} status", job_label)
+        } else {
+            format!("{} pagination", job_label)
+        };
+        self.handle_response(response, action).await
     }
 
     /// Waits for a crawl job to complete.
@@ -305,8 +854,49 @@ impl Client {
         id: &str,
         poll_interval: u64,
     ) -> Result<CrawlJob, FirecrawlError> {
+        self.crawl_poll_with_callback(id, poll_interval, |_, _| {})
+            .await
+    }
+
+    /// Polls a crawl job until it completes, calling `on_progress` with the
+    /// number of completed and total pages after every poll.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FirecrawlError::JobFailed`] if the crawl fails or is cancelled.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
+    ///
+    ///     let job = client.start_crawl("https://example.com", None).await?;
+    ///     let result = client
+    ///         .crawl_poll_with_callback(&job.id, 2000, |completed, total| {
+    ///             println!("{}/{} pages", completed, total);
+    ///         })
+    ///         .await?;
+    ///     println!("Documents: {}", result.data.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn crawl_poll_with_callback<F>(
+        &self,
+        id: &str,
+        poll_interval: u64,
+        on_progress: F,
+    ) -> Result<CrawlJob, FirecrawlError>
+    where
+        F: Fn(u32, u32) + Send,
+    {
         loop {
             let status = self.get_crawl_status(id).await?;
+            on_progress(status.completed, status.total);
 
             match status.status {
                 JobStatus::Completed => return Ok(status),
@@ -346,7 +936,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     let response = client.cancel_crawl("job-id").await?;
     ///     println!("Cancellation status: {}", response.status);
@@ -359,14 +949,13 @@ impl Client {
         id: impl AsRef<str>,
     ) -> Result<CancelCrawlResponse, FirecrawlError> {
         let response = self
-            .client
-            .delete(self.url(&format!("/crawl/{}", id.as_ref())))
-            .headers(self.prepare_headers(None))
-            .send()
+            .send(
+                self.client
+                    .delete(self.url(&format!("/crawl/{}", id.as_ref())))
+                    .headers(self.prepare_headers(None)),
+            )
             .await
-            .map_err(|e| {
-                FirecrawlError::HttpError(format!("Cancelling crawl {}", id.as_ref()), e)
-            })?;
+            .map_err(|e| e.with_action(format!("Cancelling crawl {}", id.as_ref())))?;
 
         self.handle_response(response, "cancel crawl").await
     }
@@ -388,7 +977,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     let errors = client.get_crawl_errors("job-id").await?;
     ///     for error in errors.errors {
@@ -403,14 +992,13 @@ impl Client {
         id: impl AsRef<str>,
     ) -> Result<CrawlErrorsResponse, FirecrawlError> {
         let response = self
-            .client
-            .get(self.url(&format!("/crawl/{}/errors", id.as_ref())))
-            .headers(self.prepare_headers(None))
-            .send()
+            .send(
+                self.client
+                    .get(self.url(&format!("/crawl/{}/errors", id.as_ref())))
+                    .headers(self.prepare_headers(None)),
+            )
             .await
-            .map_err(|e| {
-                FirecrawlError::HttpError(format!("Getting crawl errors {}", id.as_ref()), e)
-            })?;
+            .map_err(|e| e.with_action(format!("Getting crawl errors {}", id.as_ref())))?;
 
         self.handle_response(response, "crawl errors").await
     }
@@ -419,6 +1007,7 @@ impl Client {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::RetryConfig;
     use serde_json::json;
 
     #[tokio::test]
@@ -439,7 +1028,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let response = client
             .start_crawl("https://example.com", None)
             .await
@@ -486,7 +1079,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let status = client.get_crawl_status("crawl-123").await.unwrap();
 
         assert_eq!(status.status, JobStatus::Completed);
@@ -512,7 +1109,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let response = client.cancel_crawl("crawl-123").await.unwrap();
 
         assert_eq!(response.status, "cancelled");
@@ -545,7 +1146,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let errors = client.get_crawl_errors("crawl-123").await.unwrap();
 
         assert_eq!(errors.errors.len(), 1);
@@ -594,7 +1199,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let options = CrawlOptions {
             limit: Some(10),
             sitemap: Some(SitemapMode::Include),
@@ -608,4 +1217,532 @@ mod tests {
         start_mock.assert();
         status_mock.assert();
     }
+
+    #[tokio::test]
+    async fn test_crawl_normalize_urls_drops_duplicates() {
+        let mut server = mockito::Server::new_async().await;
+        let start_mock = server
+            .mock("POST", "/v2/crawl")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "id": "crawl-789",
+                    "url": "https://api.firecrawl.dev/v2/crawl/crawl-789"
+                })
+                .to_string(),
+            )
+            .create();
+        server
+            .mock("GET", "/v2/crawl/crawl-789")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "status": "completed",
+                    "total": 4,
+                    "completed": 4,
+                    "data": [
+                        { "metadata": { "sourceURL": "https://example.com/a?y=2&x=1" } },
+                        { "metadata": { "sourceURL": "https://Example.com/a/?x=1&y=2#top" } },
+                        { "metadata": { "sourceURL": "https://example.com/b" } },
+                        { "markdown": "no metadata" }
+                    ]
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
+        let options = CrawlOptions::builder().normalize_urls(true).build();
+
+        let result = client.crawl("https://example.com", options).await.unwrap();
+        start_mock.assert();
+        let urls: Vec<_> = result
+            .data
+            .iter()
+            .map(|d| d.metadata.as_ref().and_then(|m| m.source_url.as_deref()))
+            .collect();
+        assert_eq!(
+            urls,
+            vec![
+                Some("https://example.com/a?y=2&x=1"),
+                Some("https://example.com/b"),
+                None
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_crawl_poll_with_callback_reports_progress() {
+        let mut server = mockito::Server::new_async().await;
+        for (status, completed) in [("scraping", 1), ("completed", 2)] {
+            server
+                .mock("GET", "/v2/crawl/crawl-321")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    json!({
+                        "status": status,
+                        "total": 2,
+                        "completed": completed,
+                        "data": []
+                    })
+                    .to_string(),
+                )
+                .expect(1)
+                .create();
+        }
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
+        let progress = std::sync::Mutex::new(Vec::new());
+        let job = client
+            .crawl_poll_with_callback("crawl-321", 1, |completed, total| {
+                progress.lock().unwrap().push((completed, total));
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(job.status, JobStatus::Completed);
+        assert_eq!(progress.into_inner().unwrap(), vec![(1, 2), (2, 2)]);
+    }
+
+    fn crawl_start_mock(server: &mut mockito::Server, id: &str) -> mockito::Mock {
+        server
+            .mock("POST", "/v2/crawl")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "id": id,
+                    "url": format!("https://api.firecrawl.dev/v2/crawl/{}", id)
+                })
+                .to_string(),
+            )
+            .create()
+    }
+
+    #[tokio::test]
+    async fn test_crawl_stream_yields_incremental_documents() {
+        use tokio_stream::StreamExt;
+
+        let mut server = mockito::Server::new_async().await;
+        let start_mock = crawl_start_mock(&mut server, "crawl-789");
+
+        let first_poll = server
+            .mock("GET", "/v2/crawl/crawl-789")
+            .match_query(mockito::Matcher::UrlEncoded("skip".into(), "0".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "status": "scraping",
+                    "total": 2,
+                    "completed": 1,
+                    "data": [{ "markdown": "# Page 1" }]
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create();
+        let second_poll = server
+            .mock("GET", "/v2/crawl/crawl-789")
+            .match_query(mockito::Matcher::UrlEncoded("skip".into(), "1".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "status": "completed",
+                    "total": 2,
+                    "completed": 2,
+                    "data": [{ "markdown": "# Page 2" }]
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create();
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
+        let options = CrawlOptions {
+            poll_interval: Some(1),
+            ..Default::default()
+        };
+
+        let documents: Vec<_> = client
+            .crawl_stream("https://example.com", options)
+            .collect::<Result<_, _>>()
+            .await
+            .unwrap();
+
+        let markdown: Vec<_> = documents
+            .iter()
+            .map(|d| d.markdown.as_deref().unwrap())
+            .collect();
+        assert_eq!(markdown, vec!["# Page 1", "# Page 2"]);
+        start_mock.assert();
+        first_poll.assert();
+        second_poll.assert();
+    }
+
+    #[tokio::test]
+    async fn test_crawl_stream_counts_job_credits_once() {
+        use tokio_stream::StreamExt;
+
+        let mut server = mockito::Server::new_async().await;
+        let _start = crawl_start_mock(&mut server, "crawl-credits");
+        server
+            .mock("GET", "/v2/crawl/crawl-credits")
+            .match_query(mockito::Matcher::UrlEncoded("skip".into(), "0".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "status": "scraping",
+                    "total": 3,
+                    "completed": 1,
+                    "creditsUsed": 1,
+                    "data": [{ "markdown": "# Page 1" }]
+                })
+                .to_string(),
+            )
+            .create();
+        server
+            .mock("GET", "/v2/crawl/crawl-credits")
+            .match_query(mockito::Matcher::UrlEncoded("skip".into(), "1".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "status": "completed",
+                    "total": 3,
+                    "completed": 3,
+                    "creditsUsed": 3,
+                    "next": format!("{}/v2/crawl/crawl-credits?skip=2", server.url()),
+                    "data": [{ "markdown": "# Page 2" }]
+                })
+                .to_string(),
+            )
+            .create();
+        server
+            .mock("GET", "/v2/crawl/crawl-credits")
+            .match_query(mockito::Matcher::UrlEncoded("skip".into(), "2".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "status": "completed",
+                    "total": 3,
+                    "completed": 3,
+                    "creditsUsed": 3,
+                    "data": [{ "markdown": "# Page 3" }]
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
+        let options = CrawlOptions {
+            poll_interval: Some(1),
+            ..Default::default()
+        };
+
+        let documents: Vec<_> = client
+            .crawl_stream("https://example.com", options)
+            .collect::<Result<_, _>>()
+            .await
+            .unwrap();
+
+        assert_eq!(documents.len(), 3);
+        assert_eq!(client.credits_used(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_crawl_stream_errors_on_failed_job() {
+        use tokio_stream::StreamExt;
+
+        let mut server = mockito::Server::new_async().await;
+        let _start_mock = crawl_start_mock(&mut server, "crawl-bad");
+        let _status_mock = server
+            .mock("GET", "/v2/crawl/crawl-bad")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "status": "failed",
+                    "total": 0,
+                    "completed": 0,
+                    "data": []
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
+
+        let results: Vec<_> = client
+            .crawl_stream("https://example.com", None)
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            results[0],
+            Err(FirecrawlError::JobFailed(_, JobStatus::Failed))
+        ));
+    }
+
+    #[test]
+    fn test_with_idempotency_generates_uuid() {
+        let a = CrawlOptions::default().with_idempotency();
+        let b = CrawlOptions::default().with_idempotency();
+        let key = a.idempotency_key.unwrap();
+        assert!(uuid::Uuid::parse_str(&key).is_ok());
+        assert_ne!(Some(key), b.idempotency_key);
+    }
+
+    #[tokio::test]
+    async fn test_start_crawl_idempotent_returns_existing_job() {
+        let mut server = mockito::Server::new_async().await;
+        let key = "2c0a7e8e-4f3b-4d57-9a8e-2f5b6c1d9e01";
+
+        let first = server
+            .mock("POST", "/v2/crawl")
+            .match_header("x-idempotency-key", key)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "id": "crawl-1",
+                    "url": "https://api.firecrawl.dev/v2/crawl/crawl-1"
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create();
+        let duplicate = server
+            .mock("POST", "/v2/crawl")
+            .match_header("x-idempotency-key", key)
+            .with_status(409)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "success": false, "error": "Idempotency key already used" }).to_string(),
+            )
+            .create();
+        let unknown = server
+            .mock("POST", "/v2/crawl")
+            .match_header("x-idempotency-key", "unknown")
+            .with_status(409)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "success": false, "error": "Idempotency key already used" }).to_string(),
+            )
+            .expect(1)
+            .create();
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .retry(RetryConfig::disabled())
+            .build()
+            .unwrap();
+
+        let started = client
+            .start_crawl_idempotent("https://example.com", None, key)
+            .await
+            .unwrap();
+        let retried = client
+            .start_crawl_idempotent("https://example.com", None, key)
+            .await
+            .unwrap();
+
+        assert_eq!(started.id, "crawl-1");
+        assert_eq!(retried.id, "crawl-1");
+        first.assert();
+        duplicate.assert();
+
+        // Unknown keys still surface the conflict.
+        let result = client
+            .start_crawl_idempotent("https://example.com", None, "unknown")
+            .await;
+        assert!(matches!(result, Err(FirecrawlError::APIError(_, _))));
+        unknown.assert();
+    }
+
+    #[tokio::test]
+    async fn test_idempotent_crawl_is_forgotten_once_finished() {
+        let mut server = mockito::Server::new_async().await;
+        let key = "5b0f0c52-2f0e-4d4b-8d8a-0f7b0e3c9a11";
+
+        server
+            .mock("POST", "/v2/crawl")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "id": "crawl-done",
+                    "url": "https://api.firecrawl.dev/v2/crawl/crawl-done"
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create();
+        server
+            .mock("GET", "/v2/crawl/crawl-done")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "status": "completed", "total": 0, "completed": 0, "data": [] })
+                    .to_string(),
+            )
+            .create();
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .retry(RetryConfig::disabled())
+            .build()
+            .unwrap();
+
+        client
+            .start_crawl_idempotent("https://example.com", None, key)
+            .await
+            .unwrap();
+        assert!(client.idempotent_crawls.lock().unwrap().get(key).is_some());
+
+        client.get_crawl_status("crawl-done").await.unwrap();
+        assert!(client.idempotent_crawls.lock().unwrap().get(key).is_none());
+    }
+
+    #[test]
+    fn test_idempotent_crawls_keeps_most_recent() {
+        let mut crawls = IdempotentCrawls::default();
+        for i in 0..=MAX_IDEMPOTENT_CRAWLS {
+            crawls.insert(
+                format!("key-{}", i),
+                CrawlResponse {
+                    success: true,
+                    id: format!("crawl-{}", i),
+                    url: String::new(),
+                },
+            );
+        }
+
+        assert_eq!(crawls.crawls.len(), MAX_IDEMPOTENT_CRAWLS);
+        assert!(crawls.get("key-0").is_none());
+        assert!(crawls.get("key-1").is_some());
+
+        crawls.remove_job("crawl-1");
+        assert!(crawls.get("key-1").is_none());
+        assert_eq!(crawls.crawls.len(), MAX_IDEMPOTENT_CRAWLS - 1);
+    }
+
+    #[tokio::test]
+    async fn test_iter_crawl_pages_fetches_lazily() {
+        use tokio_stream::StreamExt;
+
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let first_page = server
+            .mock("GET", "/v2/crawl/crawl-pages")
+            .match_query(mockito::Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "status": "completed",
+                    "total": 3,
+                    "completed": 3,
+                    "next": format!("{}/v2/crawl/crawl-pages?skip=2", url),
+                    "data": [{ "markdown": "# Page 1" }, { "markdown": "# Page 2" }]
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create();
+        let second_page = server
+            .mock("GET", "/v2/crawl/crawl-pages")
+            .match_query(mockito::Matcher::UrlEncoded("skip".into(), "2".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "status": "completed",
+                    "total": 3,
+                    "completed": 3,
+                    "data": [{ "markdown": "# Page 3" }]
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create();
+
+        let client = Client::builder()
+            .base_url(url)
+            .api_key("test_key")
+            .build()
+            .unwrap();
+
+        let pages = client.iter_crawl_pages("crawl-pages");
+        tokio::pin!(pages);
+
+        let page = pages.next().await.unwrap().unwrap();
+        assert_eq!(page.len(), 2);
+        first_page.assert();
+        assert!(!second_page.matched());
+
+        let page = pages.next().await.unwrap().unwrap();
+        assert_eq!(page[0].markdown.as_deref(), Some("# Page 3"));
+        assert!(pages.next().await.is_none());
+        second_page.assert();
+    }
+
+    #[test]
+    fn test_crawl_options_builder_composes_scrape_options() {
+        use crate::Format;
+
+        let options = CrawlOptions::builder()
+            .limit(10)
+            .include_paths(vec!["/blog/*".to_string()])
+            .webhook("https://example.com/webhook")
+            .scrape_options(ScrapeOptions::builder().formats(vec![Format::Markdown]))
+            .poll_interval(500)
+            .build();
+
+        assert_eq!(options.poll_interval, Some(500));
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            json!({
+                "includePaths": ["/blog/*"],
+                "limit": 10,
+                "webhook": { "url": "https://example.com/webhook" },
+                "scrapeOptions": { "formats": ["markdown"] }
+            })
+        );
+    }
 }
@@ -1,4 +1,5 @@
 use std::fmt::Display;
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -26,20 +27,109 @@ impl Display for FirecrawlAPIError {
     }
 }
 
+impl std::error::Error for FirecrawlAPIError {}
+
 #[derive(Error, Debug)]
 pub enum FirecrawlError {
     #[error("{0} failed: HTTP error {1}: {2}")]
     HttpRequestFailed(String, u16, String),
     #[error("{0} failed: HTTP error: {1}")]
-    HttpError(String, reqwest::Error),
+    HttpError(String, #[source] reqwest::Error),
     #[error("Failed to parse response as text: {0}")]
-    ResponseParseErrorText(reqwest::Error),
+    ResponseParseErrorText(#[source] reqwest::Error),
     #[error("Failed to parse response: {0}")]
-    ResponseParseError(serde_json::Error),
+    ResponseParseError(#[from] serde_json::Error),
     #[error("{0} failed: {1}")]
-    APIError(String, FirecrawlAPIError),
+    APIError(String, #[source] FirecrawlAPIError),
     #[error("Job failed: {0} (status: {1:?})")]
     JobFailed(String, crate::types::JobStatus),
     #[error("Misuse: {0}")]
     Misuse(String),
+    #[error("Unauthorized: invalid or missing API key")]
+    Unauthorized,
+    #[error("Payment required: {message}")]
+    PaymentRequired { message: String },
+    #[error("Forbidden")]
+    Forbidden,
+    #[error("Not found: {0}")]
+    NotFound(String),
+    #[error("Rate limited (retry after: {retry_after:?})")]
+    RateLimited { retry_after: Option<Duration> },
+    #[error("Server error: HTTP {status}: {body}")]
+    ServerError { status: u16, body: String },
+    #[error("Request to {url} timed out after {elapsed:?}")]
+    Timeout { url: String, elapsed: Duration },
+    /// An agent task was started by [`Client::agent_guarded`], but its
+    /// initial status couldn't be fetched. `guard` still owns the task:
+    /// keep it to poll the task, or drop it to cancel the task.
+    ///
+    /// [`Client::agent_guarded`]: crate::Client::agent_guarded
+    #[error("Agent task {} started, but fetching its status failed: {source}", .guard.id())]
+    AgentStatusUnavailable {
+        guard: crate::AgentGuard,
+        source: Box<FirecrawlError>,
+    },
+}
+
+impl FirecrawlError {
+    /// Maps an unsuccessful HTTP status to a typed error. Returns `None` for
+    /// statuses without a dedicated variant.
+    pub(crate) fn from_status(
+        status: reqwest::StatusCode,
+        body: String,
+        retry_after: Option<Duration>,
+    ) -> Option<Self> {
+        let message = || {
+            serde_json::from_str::<Value>(&body)
+                .ok()
+                .and_then(|v| v["error"].as_str().map(str::to_string))
+                .unwrap_or_else(|| body.clone())
+        };
+
+        match status.as_u16() {
+            401 => Some(FirecrawlError::Unauthorized),
+            402 => Some(FirecrawlError::PaymentRequired { message: message() }),
+            403 => Some(FirecrawlError::Forbidden),
+            404 => Some(FirecrawlError::NotFound(message())),
+            429 => Some(FirecrawlError::RateLimited { retry_after }),
+            500..=599 => Some(FirecrawlError::ServerError {
+                status: status.as_u16(),
+                body,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for FirecrawlError {
+    fn from(error: reqwest::Error) -> Self {
+        FirecrawlError::HttpError("Request".to_string(), error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as _;
+
+    #[test]
+    fn test_errors_expose_source() {
+        let json_error = serde_json::from_str::<Value>("{").unwrap_err();
+        let error: FirecrawlError = json_error.into();
+        assert!(matches!(error, FirecrawlError::ResponseParseError(_)));
+        assert!(error.source().is_some());
+
+        let api_error = FirecrawlError::APIError(
+            "scrape".to_string(),
+            FirecrawlAPIError {
+                success: false,
+                error: "Invalid URL".to_string(),
+                details: None,
+            },
+        );
+        assert_eq!(api_error.to_string(), "scrape failed: Invalid URL");
+        assert_eq!(api_error.source().unwrap().to_string(), "Invalid URL");
+
+        assert!(FirecrawlError::Unauthorized.source().is_none());
+    }
 }
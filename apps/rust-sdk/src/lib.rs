@@ -10,7 +10,7 @@
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
-//!     let client = Client::new("your-api-key")?;
+//!     let client = Client::builder().api_key("your-api-key").build()?;
 //!     let document = client.scrape("https://example.com", None).await?;
 //!     println!("{:?}", document.markdown);
 //!     Ok(())
@@ -31,10 +31,11 @@ mod research;
 mod scrape;
 mod search;
 mod types;
+mod webhook;
 
 pub use agent::*;
 pub use batch_scrape::*;
-pub use client::Client;
+pub use client::{AccountInfo, Client, ClientBuilder, ClientStats, RetryConfig};
 pub use crawl::*;
 pub use error::FirecrawlError;
 pub use map::*;
@@ -44,3 +45,4 @@ pub use research::*;
 pub use scrape::*;
 pub use search::*;
 pub use types::*;
+pub use webhook::*;
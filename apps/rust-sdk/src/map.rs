@@ -79,7 +79,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     // Simple map
     ///     let response = client.map("https://example.com", None).await?;
@@ -114,13 +114,14 @@ impl Client {
         let headers = self.prepare_headers(None);
 
         let response = self
-            .client
-            .post(self.url("/map"))
-            .headers(headers)
-            .json(&body)
-            .send()
+            .send(
+                self.client
+                    .post(self.url("/map"))
+                    .headers(headers)
+                    .json(&body),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError(format!("Mapping {:?}", url.as_ref()), e))?;
+            .map_err(|e| e.with_action(format!("Mapping {:?}", url.as_ref())))?;
 
         self.handle_response(response, "map").await
     }
@@ -145,7 +146,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     let urls = client.map_urls("https://example.com", None).await?;
     ///     for url in urls {
@@ -200,7 +201,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let response = client.map("https://example.com", None).await.unwrap();
 
         assert!(response.success);
@@ -230,7 +235,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let options = MapOptions {
             sitemap: Some(SitemapMode::Include),
             include_subdomains: Some(true),
@@ -266,7 +275,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let urls = client.map_urls("https://example.com", None).await.unwrap();
 
         assert_eq!(urls.len(), 3);
@@ -294,7 +307,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let options = MapOptions {
             search: Some("blog".to_string()),
             ..Default::default()
@@ -324,7 +341,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let result = client.map("invalid-url", None).await;
 
         assert!(result.is_err());
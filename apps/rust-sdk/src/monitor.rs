@@ -286,13 +286,14 @@ impl Client {
         request: CreateMonitorRequest,
     ) -> Result<Monitor, FirecrawlError> {
         let response = self
-            .client
-            .post(self.url("/monitor"))
-            .headers(self.prepare_headers(None))
-            .json(&request)
-            .send()
+            .send(
+                self.client
+                    .post(self.url("/monitor"))
+                    .headers(self.prepare_headers(None))
+                    .json(&request),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError("Creating monitor".to_string(), e))?;
+            .map_err(|e| e.with_action("Creating monitor"))?;
 
         let response: DataResponse<Monitor> =
             self.handle_response(response, "create monitor").await?;
@@ -305,12 +306,13 @@ impl Client {
         offset: Option<u32>,
     ) -> Result<Vec<Monitor>, FirecrawlError> {
         let response = self
-            .client
-            .get(self.url(&format!("/monitor{}", query(limit, offset, None))))
-            .headers(self.prepare_headers(None))
-            .send()
+            .send(
+                self.client
+                    .get(self.url(&format!("/monitor{}", query(limit, offset, None))))
+                    .headers(self.prepare_headers(None)),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError("Listing monitors".to_string(), e))?;
+            .map_err(|e| e.with_action("Listing monitors"))?;
 
         let response: DataResponse<Vec<Monitor>> =
             self.handle_response(response, "list monitors").await?;
@@ -322,12 +324,13 @@ impl Client {
         monitor_id: impl AsRef<str>,
     ) -> Result<Monitor, FirecrawlError> {
         let response = self
-            .client
-            .get(self.url(&format!("/monitor/{}", monitor_id.as_ref())))
-            .headers(self.prepare_headers(None))
-            .send()
+            .send(
+                self.client
+                    .get(self.url(&format!("/monitor/{}", monitor_id.as_ref())))
+                    .headers(self.prepare_headers(None)),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError("Getting monitor".to_string(), e))?;
+            .map_err(|e| e.with_action("Getting monitor"))?;
 
         let response: DataResponse<Monitor> = self.handle_response(response, "get monitor").await?;
         Ok(response.data)
@@ -339,13 +342,14 @@ impl Client {
         request: UpdateMonitorRequest,
     ) -> Result<Monitor, FirecrawlError> {
         let response = self
-            .client
-            .patch(self.url(&format!("/monitor/{}", monitor_id.as_ref())))
-            .headers(self.prepare_headers(None))
-            .json(&request)
-            .send()
+            .send(
+                self.client
+                    .patch(self.url(&format!("/monitor/{}", monitor_id.as_ref())))
+                    .headers(self.prepare_headers(None))
+                    .json(&request),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError("Updating monitor".to_string(), e))?;
+            .map_err(|e| e.with_action("Updating monitor"))?;
 
         let response: DataResponse<Monitor> =
             self.handle_response(response, "update monitor").await?;
@@ -357,12 +361,13 @@ impl Client {
         monitor_id: impl AsRef<str>,
     ) -> Result<bool, FirecrawlError> {
         let response = self
-            .client
-            .delete(self.url(&format!("/monitor/{}", monitor_id.as_ref())))
-            .headers(self.prepare_headers(None))
-            .send()
+            .send(
+                self.client
+                    .delete(self.url(&format!("/monitor/{}", monitor_id.as_ref())))
+                    .headers(self.prepare_headers(None)),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError("Deleting monitor".to_string(), e))?;
+            .map_err(|e| e.with_action("Deleting monitor"))?;
 
         let response: SuccessResponse = self.handle_response(response, "delete monitor").await?;
         Ok(response.success)
@@ -373,13 +378,14 @@ impl Client {
         monitor_id: impl AsRef<str>,
     ) -> Result<MonitorCheck, FirecrawlError> {
         let response = self
-            .client
-            .post(self.url(&format!("/monitor/{}/run", monitor_id.as_ref())))
-            .headers(self.prepare_headers(None))
-            .json(&serde_json::json!({}))
-            .send()
+            .send(
+                self.client
+                    .post(self.url(&format!("/monitor/{}/run", monitor_id.as_ref())))
+                    .headers(self.prepare_headers(None))
+                    .json(&serde_json::json!({})),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError("Running monitor".to_string(), e))?;
+            .map_err(|e| e.with_action("Running monitor"))?;
 
         let response: DataResponse<MonitorCheck> =
             self.handle_response(response, "run monitor").await?;
@@ -398,12 +404,13 @@ impl Client {
             query(limit, offset, None)
         );
         let response = self
-            .client
-            .get(self.url(&path))
-            .headers(self.prepare_headers(None))
-            .send()
+            .send(
+                self.client
+                    .get(self.url(&path))
+                    .headers(self.prepare_headers(None)),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError("Listing monitor checks".to_string(), e))?;
+            .map_err(|e| e.with_action("Listing monitor checks"))?;
 
         let response: DataResponse<Vec<MonitorCheck>> = self
             .handle_response(response, "list monitor checks")
@@ -426,12 +433,13 @@ impl Client {
             check_page_query(limit, skip, status)
         );
         let response = self
-            .client
-            .get(self.url(&path))
-            .headers(self.prepare_headers(None))
-            .send()
+            .send(
+                self.client
+                    .get(self.url(&path))
+                    .headers(self.prepare_headers(None)),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError("Getting monitor check".to_string(), e))?;
+            .map_err(|e| e.with_action("Getting monitor check"))?;
 
         let response: DataResponse<MonitorCheckDetail> =
             self.handle_response(response, "get monitor check").await?;
@@ -439,14 +447,9 @@ impl Client {
 
         while let Some(next) = check.next.clone() {
             let response = self
-                .client
-                .get(next)
-                .headers(self.prepare_headers(None))
-                .send()
+                .send(self.client.get(next).headers(self.prepare_headers(None)))
                 .await
-                .map_err(|e| {
-                    FirecrawlError::HttpError("Getting monitor check page".to_string(), e)
-                })?;
+                .map_err(|e| e.with_action("Getting monitor check page"))?;
             let response: DataResponse<MonitorCheckDetail> = self
                 .handle_response(response, "get monitor check page")
                 .await?;
@@ -472,12 +475,13 @@ impl Client {
             check_page_query(limit, skip, status)
         );
         let response = self
-            .client
-            .get(self.url(&path))
-            .headers(self.prepare_headers(None))
-            .send()
+            .send(
+                self.client
+                    .get(self.url(&path))
+                    .headers(self.prepare_headers(None)),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError("Getting monitor check".to_string(), e))?;
+            .map_err(|e| e.with_action("Getting monitor check"))?;
 
         let response: DataResponse<MonitorCheckDetail> =
             self.handle_response(response, "get monitor check").await?;
@@ -177,13 +177,14 @@ impl Client {
         let headers = self.prepare_multipart_headers(None);
 
         let response = self
-            .client
-            .post(self.url("/parse"))
-            .headers(headers)
-            .multipart(form)
-            .send()
+            .send(
+                self.client
+                    .post(self.url("/parse"))
+                    .headers(headers)
+                    .multipart(form),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError("Parsing uploaded file".to_string(), e))?;
+            .map_err(|e| e.with_action("Parsing uploaded file"))?;
 
         let response: ParseResponse = self.handle_response(response, "parse").await?;
         Ok(response.data)
@@ -222,7 +223,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let file = ParseFile::from_bytes("upload.html", b"<html><body>ok</body></html>".to_vec())
             .with_content_type("text/html");
         let doc = client.parse(file, None).await.unwrap();
@@ -259,7 +264,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let file = ParseFile::from_bytes("upload.xyz", b"not a real file".to_vec());
         let result = client.parse(file, None).await;
 
@@ -272,7 +281,11 @@ mod tests {
         let file = ParseFile::from_bytes("empty.html", vec![]);
         let rt = tokio::runtime::Runtime::new().unwrap();
         let result = rt.block_on(async {
-            let client = Client::new_selfhosted("http://localhost:9999", Some("k")).unwrap();
+            let client = Client::builder()
+                .base_url("http://localhost:9999")
+                .api_key("k")
+                .build()
+                .unwrap();
             client.parse(file, None).await
         });
 
@@ -183,13 +183,14 @@ impl Client {
         }
 
         let response = self
-            .client
-            .get(self.url("/search/research/papers"))
-            .headers(self.prepare_headers(None))
-            .query(&query)
-            .send()
+            .send(
+                self.client
+                    .get(self.url("/search/research/papers"))
+                    .headers(self.prepare_headers(None))
+                    .query(&query),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError("search papers".to_string(), e))?;
+            .map_err(|e| e.with_action("search papers"))?;
 
         self.handle_response(response, "search papers").await
     }
@@ -199,15 +200,16 @@ impl Client {
         paper_id: impl AsRef<str>,
     ) -> Result<PaperMetadataResponse, FirecrawlError> {
         let response = self
-            .client
-            .get(self.url(&format!(
-                "/search/research/papers/{}",
-                path_escape(paper_id.as_ref())
-            )))
-            .headers(self.prepare_headers(None))
-            .send()
+            .send(
+                self.client
+                    .get(self.url(&format!(
+                        "/search/research/papers/{}",
+                        path_escape(paper_id.as_ref())
+                    )))
+                    .headers(self.prepare_headers(None)),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError("inspect paper".to_string(), e))?;
+            .map_err(|e| e.with_action("inspect paper"))?;
 
         self.handle_response(response, "inspect paper").await
     }
@@ -232,16 +234,17 @@ impl Client {
         }
 
         let response = self
-            .client
-            .get(self.url(&format!(
-                "/search/research/papers/{}",
-                path_escape(paper_id.as_ref())
-            )))
-            .headers(self.prepare_headers(None))
-            .query(&query)
-            .send()
+            .send(
+                self.client
+                    .get(self.url(&format!(
+                        "/search/research/papers/{}",
+                        path_escape(paper_id.as_ref())
+                    )))
+                    .headers(self.prepare_headers(None))
+                    .query(&query),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError("read paper".to_string(), e))?;
+            .map_err(|e| e.with_action("read paper"))?;
 
         self.handle_response(response, "read paper").await
     }
@@ -275,16 +278,17 @@ impl Client {
         }
 
         let response = self
-            .client
-            .get(self.url(&format!(
-                "/search/research/papers/{}/similar",
-                path_escape(paper_id.as_ref())
-            )))
-            .headers(self.prepare_headers(None))
-            .query(&query)
-            .send()
+            .send(
+                self.client
+                    .get(self.url(&format!(
+                        "/search/research/papers/{}/similar",
+                        path_escape(paper_id.as_ref())
+                    )))
+                    .headers(self.prepare_headers(None))
+                    .query(&query),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError("related papers".to_string(), e))?;
+            .map_err(|e| e.with_action("related papers"))?;
 
         self.handle_response(response, "related papers").await
     }
@@ -308,13 +312,14 @@ impl Client {
         }
 
         let response = self
-            .client
-            .get(self.url("/search/research/github"))
-            .headers(self.prepare_headers(None))
-            .query(&query)
-            .send()
+            .send(
+                self.client
+                    .get(self.url("/search/research/github"))
+                    .headers(self.prepare_headers(None))
+                    .query(&query),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError("search github".to_string(), e))?;
+            .map_err(|e| e.with_action("search github"))?;
 
         self.handle_response(response, "search github").await
     }
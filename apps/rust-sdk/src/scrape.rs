@@ -1,5 +1,6 @@
 //! Scrape endpoint for Firecrawl API v2.
 
+use futures_util::stream::{self, StreamExt};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -102,6 +103,226 @@ pub struct ScrapeOptions {
     pub attribute_selectors: Option<Vec<AttributeSelector>>,
 }
 
+/// Builder for [`ScrapeOptions`] with chainable setters.
+///
+/// # Example
+///
+/// ```
+/// use firecrawl::{Format, ScrapeOptions};
+///
+/// let options = ScrapeOptions::builder()
+///     .formats(vec![Format::Markdown, Format::Links])
+///     .only_main_content(true)
+///     .timeout(30_000)
+///     .build();
+///
+/// assert_eq!(options.timeout, Some(30_000));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct ScrapeOptionsBuilder {
+    options: ScrapeOptions,
+}
+
+impl ScrapeOptions {
+    /// Returns a builder for scrape options.
+    pub fn builder() -> ScrapeOptionsBuilder {
+        ScrapeOptionsBuilder::default()
+    }
+}
+
+impl ScrapeOptionsBuilder {
+    /// Origin label for request attribution (e.g., "rust-sdk@2.8.0").
+    pub fn origin(&mut self, origin: impl Into<String>) -> &mut Self {
+        self.options.origin = Some(origin.into());
+        self
+    }
+
+    /// Output formats to include in the response.
+    pub fn formats(&mut self, formats: Vec<Format>) -> &mut Self {
+        self.options.formats = Some(formats);
+        self
+    }
+
+    /// Additional HTTP headers to send with the request.
+    pub fn headers(&mut self, headers: HashMap<String, String>) -> &mut Self {
+        self.options.headers = Some(headers);
+        self
+    }
+
+    /// HTML tags to exclusively include in the output.
+    pub fn include_tags(&mut self, include_tags: Vec<String>) -> &mut Self {
+        self.options.include_tags = Some(include_tags);
+        self
+    }
+
+    /// HTML tags to exclude from the output.
+    pub fn exclude_tags(&mut self, exclude_tags: Vec<String>) -> &mut Self {
+        self.options.exclude_tags = Some(exclude_tags);
+        self
+    }
+
+    /// Only extract the main content of the page.
+    pub fn only_main_content(&mut self, only_main_content: bool) -> &mut Self {
+        self.options.only_main_content = Some(only_main_content);
+        self
+    }
+
+    /// Timeout in milliseconds before returning an error.
+    pub fn timeout(&mut self, timeout: u32) -> &mut Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Time to wait after page load before scraping (milliseconds).
+    pub fn wait_for(&mut self, wait_for: u32) -> &mut Self {
+        self.options.wait_for = Some(wait_for);
+        self
+    }
+
+    /// Emulate a mobile device.
+    pub fn mobile(&mut self, mobile: bool) -> &mut Self {
+        self.options.mobile = Some(mobile);
+        self
+    }
+
+    /// Parser configurations (e.g., for PDFs).
+    pub fn parsers(&mut self, parsers: Vec<ParserConfig>) -> &mut Self {
+        self.options.parsers = Some(parsers);
+        self
+    }
+
+    /// Browser automation actions to perform before scraping.
+    pub fn actions(&mut self, actions: Vec<Action>) -> &mut Self {
+        self.options.actions = Some(actions);
+        self
+    }
+
+    /// Location configuration for proxy routing.
+    pub fn location(&mut self, location: LocationConfig) -> &mut Self {
+        self.options.location = Some(location);
+        self
+    }
+
+    /// Skip TLS certificate verification.
+    pub fn skip_tls_verification(&mut self, skip_tls_verification: bool) -> &mut Self {
+        self.options.skip_tls_verification = Some(skip_tls_verification);
+        self
+    }
+
+    /// Remove base64-encoded images from the output.
+    pub fn remove_base64_images(&mut self, remove_base64_images: bool) -> &mut Self {
+        self.options.remove_base64_images = Some(remove_base64_images);
+        self
+    }
+
+    /// Enable fast mode for quicker scrapes with reduced accuracy.
+    pub fn fast_mode(&mut self, fast_mode: bool) -> &mut Self {
+        self.options.fast_mode = Some(fast_mode);
+        self
+    }
+
+    /// Block advertisements on the page.
+    pub fn block_ads(&mut self, block_ads: bool) -> &mut Self {
+        self.options.block_ads = Some(block_ads);
+        self
+    }
+
+    /// Proxy type to use.
+    pub fn proxy(&mut self, proxy: ProxyType) -> &mut Self {
+        self.options.proxy = Some(proxy);
+        self
+    }
+
+    /// Maximum age of cached content to accept (seconds).
+    pub fn max_age(&mut self, max_age: u32) -> &mut Self {
+        self.options.max_age = Some(max_age);
+        self
+    }
+
+    /// Minimum age of cached content to accept (seconds).
+    pub fn min_age(&mut self, min_age: u32) -> &mut Self {
+        self.options.min_age = Some(min_age);
+        self
+    }
+
+    /// Store the result in cache for future requests.
+    pub fn store_in_cache(&mut self, store_in_cache: bool) -> &mut Self {
+        self.options.store_in_cache = Some(store_in_cache);
+        self
+    }
+
+    /// Lockdown mode: serve only previously cached results, never make outbound requests.
+    pub fn lockdown(&mut self, lockdown: bool) -> &mut Self {
+        self.options.lockdown = Some(lockdown);
+        self
+    }
+
+    /// Redact personally identifiable information from returned content.
+    pub fn redact_pii(&mut self, redact_pii: bool) -> &mut Self {
+        self.options.redact_pii = Some(redact_pii);
+        self
+    }
+
+    /// Persistent browser profile for maintaining state across scrapes.
+    pub fn profile(&mut self, profile: ProfileConfig) -> &mut Self {
+        self.options.profile = Some(profile);
+        self
+    }
+
+    /// Integration identifier for tracking.
+    pub fn integration(&mut self, integration: impl Into<String>) -> &mut Self {
+        self.options.integration = Some(integration.into());
+        self
+    }
+
+    /// JSON extraction options.
+    pub fn json_options(&mut self, json_options: JsonOptions) -> &mut Self {
+        self.options.json_options = Some(json_options);
+        self
+    }
+
+    /// Screenshot options.
+    pub fn screenshot_options(&mut self, screenshot_options: ScreenshotOptions) -> &mut Self {
+        self.options.screenshot_options = Some(screenshot_options);
+        self
+    }
+
+    /// Change tracking options.
+    pub fn change_tracking_options(
+        &mut self,
+        change_tracking_options: ChangeTrackingOptions,
+    ) -> &mut Self {
+        self.options.change_tracking_options = Some(change_tracking_options);
+        self
+    }
+
+    /// Attribute selectors for extraction.
+    pub fn attribute_selectors(
+        &mut self,
+        attribute_selectors: Vec<AttributeSelector>,
+    ) -> &mut Self {
+        self.options.attribute_selectors = Some(attribute_selectors);
+        self
+    }
+
+    /// Builds the scrape options.
+    pub fn build(&self) -> ScrapeOptions {
+        self.options.clone()
+    }
+}
+
+impl From<ScrapeOptionsBuilder> for ScrapeOptions {
+    fn from(builder: ScrapeOptionsBuilder) -> Self {
+        builder.options
+    }
+}
+
+impl From<&mut ScrapeOptionsBuilder> for ScrapeOptions {
+    fn from(builder: &mut ScrapeOptionsBuilder) -> Self {
+        builder.build()
+    }
+}
+
 /// Parser configuration for document parsing.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(untagged)]
@@ -227,7 +448,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     // Simple scrape
     ///     let document = client.scrape("https://example.com", None).await?;
@@ -262,19 +483,68 @@ impl Client {
         let headers = self.prepare_headers(None);
 
         let response = self
-            .client
-            .post(self.url("/scrape"))
-            .headers(headers)
-            .json(&body)
-            .send()
+            .send(
+                self.client
+                    .post(self.url("/scrape"))
+                    .headers(headers)
+                    .json(&body),
+            )
             .await
-            .map_err(|e| FirecrawlError::HttpError(format!("Scraping {:?}", url.as_ref()), e))?;
+            .map_err(|e| e.with_action(format!("Scraping {:?}", url.as_ref())))?;
 
         let response: ScrapeResponse = self.handle_response(response, "scrape").await?;
 
         Ok(response.data)
     }
 
+    /// Scrapes several URLs concurrently without starting a batch scrape job.
+    ///
+    /// At most `concurrency` requests are in flight at once (`0` means 5).
+    /// Results are returned in the same order as `urls`, and a failure for
+    /// one URL doesn't affect the others.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
+    ///
+    ///     let urls = vec![
+    ///         "https://example.com".to_string(),
+    ///         "https://example.org".to_string(),
+    ///     ];
+    ///     for (url, result) in urls.iter().zip(client.scrape_many(urls.clone(), None, 5).await) {
+    ///         match result {
+    ///             Ok(document) => println!("{}: {:?}", url, document.markdown),
+    ///             Err(e) => eprintln!("{}: {}", url, e),
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn scrape_many(
+        &self,
+        urls: Vec<String>,
+        options: impl Into<Option<ScrapeOptions>>,
+        concurrency: usize,
+    ) -> Vec<Result<Document, FirecrawlError>> {
+        let options = options.into();
+        let concurrency = if concurrency == 0 { 5 } else { concurrency };
+
+        stream::iter(urls)
+            .map(|url| {
+                let options = options.clone();
+                async move { self.scrape(url, options).await }
+            })
+            .buffered(concurrency)
+            .collect()
+            .await
+    }
+
     /// Scrapes a URL with a JSON schema for structured extraction.
     ///
     /// This is a convenience method that combines scraping with JSON extraction.
@@ -297,7 +567,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     let schema = json!({
     ///         "type": "object",
@@ -373,17 +643,18 @@ impl Client {
         }
 
         let response = self
-            .client
-            .post(self.url(&format!("/scrape/{}/interact", job_id.as_ref())))
-            .headers(self.prepare_headers(None))
-            .json(&body)
-            .send()
+            .send(
+                self.client
+                    .post(self.url(&format!("/scrape/{}/interact", job_id.as_ref())))
+                    .headers(self.prepare_headers(None))
+                    .json(&body),
+            )
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(
-                    format!("Interacting with scrape browser for {}", job_id.as_ref()),
-                    e,
-                )
+                e.with_action(format!(
+                    "Interacting with scrape browser for {}",
+                    job_id.as_ref()
+                ))
             })?;
 
         self.handle_response(response, "scrape interact").await
@@ -403,17 +674,13 @@ impl Client {
         job_id: impl AsRef<str>,
     ) -> Result<ScrapeBrowserDeleteResponse, FirecrawlError> {
         let response = self
-            .client
-            .delete(self.url(&format!("/scrape/{}/interact", job_id.as_ref())))
-            .headers(self.prepare_headers(None))
-            .send()
+            .send(
+                self.client
+                    .delete(self.url(&format!("/scrape/{}/interact", job_id.as_ref())))
+                    .headers(self.prepare_headers(None)),
+            )
             .await
-            .map_err(|e| {
-                FirecrawlError::HttpError(
-                    format!("Stopping interaction for {}", job_id.as_ref()),
-                    e,
-                )
-            })?;
+            .map_err(|e| e.with_action(format!("Stopping interaction for {}", job_id.as_ref())))?;
 
         self.handle_response(response, "stop interaction").await
     }
@@ -541,7 +808,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let document = client.scrape("https://example.com", None).await.unwrap();
 
         assert!(document.markdown.is_some());
@@ -574,7 +845,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let options = ScrapeOptions {
             formats: Some(vec![Format::Markdown, Format::Html, Format::Links]),
             only_main_content: Some(true),
@@ -616,7 +891,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
 
         let schema = json!({
             "type": "object",
@@ -657,13 +936,84 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let result = client.scrape("invalid-url", None).await;
 
         assert!(result.is_err());
         mock.assert();
     }
 
+    #[tokio::test]
+    async fn test_scrape_many_preserves_order_and_isolates_errors() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mut mocks = Vec::new();
+        for page in ["a", "b", "c"] {
+            mocks.push(
+                server
+                    .mock("POST", "/v2/scrape")
+                    .match_body(mockito::Matcher::PartialJson(
+                        json!({ "url": format!("https://example.com/{}", page) }),
+                    ))
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body(
+                        json!({
+                            "success": true,
+                            "data": { "markdown": format!("# {}", page) }
+                        })
+                        .to_string(),
+                    )
+                    .create(),
+            );
+        }
+        mocks.push(
+            server
+                .mock("POST", "/v2/scrape")
+                .match_body(mockito::Matcher::PartialJson(
+                    json!({ "url": "https://example.com/broken" }),
+                ))
+                .with_status(400)
+                .with_header("content-type", "application/json")
+                .with_body(json!({ "success": false, "error": "Invalid URL" }).to_string())
+                .create(),
+        );
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
+        let urls = ["a", "broken", "b", "c"]
+            .iter()
+            .map(|page| format!("https://example.com/{}", page))
+            .collect();
+
+        let results = client.scrape_many(urls, None, 0).await;
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(
+            results[0].as_ref().unwrap().markdown.as_deref(),
+            Some("# a")
+        );
+        assert!(results[1].is_err());
+        assert_eq!(
+            results[2].as_ref().unwrap().markdown.as_deref(),
+            Some("# b")
+        );
+        assert_eq!(
+            results[3].as_ref().unwrap().markdown.as_deref(),
+            Some("# c")
+        );
+        for mock in mocks {
+            mock.assert();
+        }
+    }
+
     #[tokio::test]
     async fn test_interact_with_mock() {
         let mut server = mockito::Server::new_async().await;
@@ -685,7 +1035,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let response = client
             .interact(
                 "job-123",
@@ -726,7 +1080,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let response = client
             .interact(
                 "job-789",
@@ -772,7 +1130,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let response = client.stop_interaction("job-123").await.unwrap();
 
         assert!(response.success);
@@ -798,7 +1160,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let result = client
             .interact(
                 "job-404",
@@ -812,4 +1178,53 @@ mod tests {
         assert!(result.is_err());
         mock.assert();
     }
+
+    #[tokio::test]
+    async fn test_scrape_with_builder_options() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "url": "https://example.com",
+                "formats": ["markdown", "links"],
+                "headers": { "X-Test": "1" },
+                "onlyMainContent": true,
+                "waitFor": 500,
+                "mobile": true,
+                "redactPII": true,
+                "integration": "builder-test"
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "data": { "markdown": "# Hello" }
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
+
+        let options = ScrapeOptions::builder()
+            .formats(vec![Format::Markdown, Format::Links])
+            .headers(HashMap::from([("X-Test".to_string(), "1".to_string())]))
+            .only_main_content(true)
+            .wait_for(500)
+            .mobile(true)
+            .redact_pii(true)
+            .integration("builder-test")
+            .build();
+
+        let document = client.scrape("https://example.com", options).await.unwrap();
+
+        assert_eq!(document.markdown.as_deref(), Some("# Hello"));
+        mock.assert();
+    }
 }
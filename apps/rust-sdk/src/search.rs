@@ -1,5 +1,8 @@
 //! Search endpoint for Firecrawl API v2.
 
+use std::collections::HashSet;
+
+use chrono::{Datelike, NaiveDate};
 use serde::{Deserialize, Serialize};
 
 use crate::client::Client;
@@ -32,6 +35,10 @@ pub struct SearchOptions {
     /// Time-based search filter (e.g., "qdr:d" for past day).
     pub tbs: Option<String>,
 
+    /// Custom date range for results. Takes precedence over `tbs` when set.
+    #[serde(skip)]
+    pub date_range: Option<DateRange>,
+
     /// Geographic location string for local search results.
     pub location: Option<String>,
 
@@ -52,6 +59,42 @@ pub struct SearchOptions {
 
     /// Origin label for request attribution (e.g., "rust-sdk@2.8.0").
     pub origin: Option<String>,
+
+    /// Drop web results whose URL was already returned, keeping the first
+    /// occurrence. URLs are compared case-insensitively, ignoring a trailing slash.
+    #[serde(skip)]
+    pub deduplicate: bool,
+}
+
+/// Inclusive date range for search results.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DateRange {
+    /// Earliest publication date.
+    pub start: Option<NaiveDate>,
+    /// Latest publication date.
+    pub end: Option<NaiveDate>,
+}
+
+impl DateRange {
+    /// Converts the range to a Google `tbs` filter string, or `None` if
+    /// neither bound is set.
+    pub fn to_tbs(&self) -> Option<String> {
+        if self.start.is_none() && self.end.is_none() {
+            return None;
+        }
+
+        let format =
+            |date: NaiveDate| format!("{:02}/{:02}/{:04}", date.month(), date.day(), date.year());
+
+        let mut tbs = "cdr:1".to_string();
+        if let Some(start) = self.start {
+            tbs.push_str(&format!(",cd_min:{}", format(start)));
+        }
+        if let Some(end) = self.end {
+            tbs.push_str(&format!(",cd_max:{}", format(end)));
+        }
+        Some(tbs)
+    }
 }
 
 /// Request body for search endpoint.
@@ -127,6 +170,79 @@ pub struct SearchResponse {
     pub data: SearchData,
     /// Warning message if any.
     pub warning: Option<String>,
+    /// Total number of results, if reported by the API.
+    pub total_results: Option<u64>,
+}
+
+impl SearchResultOrDocument {
+    /// URL of the result, if known.
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            SearchResultOrDocument::WebResult(result) => Some(&result.url),
+            SearchResultOrDocument::Document(document) => document
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.source_url.as_deref()),
+        }
+    }
+
+    /// Title of the result, if known.
+    pub fn title(&self) -> Option<&str> {
+        match self {
+            SearchResultOrDocument::WebResult(result) => result.title.as_deref(),
+            SearchResultOrDocument::Document(document) => document
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.title.as_deref()),
+        }
+    }
+
+    /// Returns the scraped document, if this result is one.
+    pub fn as_document(&self) -> Option<&Document> {
+        match self {
+            SearchResultOrDocument::Document(document) => Some(document),
+            SearchResultOrDocument::WebResult(_) => None,
+        }
+    }
+
+    /// Returns the web result, if this result is one.
+    pub fn as_web_result(&self) -> Option<&SearchResultWeb> {
+        match self {
+            SearchResultOrDocument::WebResult(result) => Some(result),
+            SearchResultOrDocument::Document(_) => None,
+        }
+    }
+
+    /// Converts into the scraped document, if this result is one.
+    pub fn into_document(self) -> Option<Document> {
+        match self {
+            SearchResultOrDocument::Document(document) => Some(document),
+            SearchResultOrDocument::WebResult(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for SearchResultOrDocument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.url().unwrap_or_default())
+    }
+}
+
+/// Collects the URLs of search results, skipping results without one.
+pub fn search_results_to_urls(results: Vec<SearchResultOrDocument>) -> Vec<String> {
+    results
+        .iter()
+        .filter_map(|result| result.url().map(str::to_string))
+        .collect()
+}
+
+/// Removes web results with duplicate URLs, keeping the first occurrence.
+fn deduplicate_web_results(results: &mut Vec<SearchResultOrDocument>) {
+    let mut seen = HashSet::new();
+    results.retain(|result| match result.url() {
+        Some(url) => seen.insert(url.trim_end_matches('/').to_lowercase()),
+        None => true,
+    });
 }
 
 impl Client {
@@ -148,7 +264,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     // Simple search
     ///     let results = client.search("rust programming", None).await?;
@@ -183,6 +299,10 @@ impl Client {
         if options.origin.is_none() {
             options.origin = Some(format!("rust-sdk@{}", env!("CARGO_PKG_VERSION")));
         }
+        if let Some(tbs) = options.date_range.and_then(|range| range.to_tbs()) {
+            options.tbs = Some(tbs);
+        }
+        let deduplicate = options.deduplicate;
         let body = SearchRequest {
             query: query.as_ref().to_string(),
             options,
@@ -191,17 +311,23 @@ impl Client {
         let headers = self.prepare_headers(None);
 
         let response = self
-            .client
-            .post(self.url("/search"))
-            .headers(headers)
-            .json(&body)
-            .send()
+            .send(
+                self.client
+                    .post(self.url("/search"))
+                    .headers(headers)
+                    .json(&body),
+            )
             .await
-            .map_err(|e| {
-                FirecrawlError::HttpError(format!("Searching for {:?}", query.as_ref()), e)
-            })?;
+            .map_err(|e| e.with_action(format!("Searching for {:?}", query.as_ref())))?;
+
+        let mut response: SearchResponse = self.handle_response(response, "search").await?;
+        if deduplicate {
+            if let Some(web) = response.data.web.as_mut() {
+                deduplicate_web_results(web);
+            }
+        }
 
-        self.handle_response(response, "search").await
+        Ok(response)
     }
 
     /// Searches the web and scrapes the results.
@@ -224,7 +350,7 @@ impl Client {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new("your-api-key")?;
+    ///     let client = Client::builder().api_key("your-api-key").build()?;
     ///
     ///     let documents = client.search_and_scrape("rust programming", 5).await?;
     ///     for doc in documents {
@@ -253,10 +379,7 @@ impl Client {
             .web
             .unwrap_or_default()
             .into_iter()
-            .filter_map(|result| match result {
-                SearchResultOrDocument::Document(doc) => Some(doc),
-                SearchResultOrDocument::WebResult(_) => None,
-            })
+            .filter_map(SearchResultOrDocument::into_document)
             .collect();
 
         Ok(documents)
@@ -311,7 +434,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let response = client.search("test query", None).await.unwrap();
 
         assert!(response.success);
@@ -353,7 +480,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let options = SearchOptions {
             limit: Some(10),
             sources: Some(vec![SearchSource::Web, SearchSource::News]),
@@ -396,7 +527,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let documents = client.search_and_scrape("test", 5).await.unwrap();
 
         assert_eq!(documents.len(), 1);
@@ -421,7 +556,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let result = client.search("", None).await;
 
         assert!(result.is_err());
@@ -466,7 +605,11 @@ mod tests {
             )
             .create();
 
-        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
         let response = client.search("test", None).await.unwrap();
 
         let web_results = response.data.web.unwrap();
@@ -492,4 +635,148 @@ mod tests {
 
         mock.assert();
     }
+
+    #[test]
+    fn test_date_range_to_tbs() {
+        let range = DateRange {
+            start: NaiveDate::from_ymd_opt(2024, 1, 5),
+            end: NaiveDate::from_ymd_opt(2024, 12, 31),
+        };
+        assert_eq!(
+            range.to_tbs().as_deref(),
+            Some("cdr:1,cd_min:01/05/2024,cd_max:12/31/2024")
+        );
+
+        let open_ended = DateRange {
+            start: NaiveDate::from_ymd_opt(2023, 7, 1),
+            end: None,
+        };
+        assert_eq!(
+            open_ended.to_tbs().as_deref(),
+            Some("cdr:1,cd_min:07/01/2023")
+        );
+        assert_eq!(DateRange::default().to_tbs(), None);
+    }
+
+    #[tokio::test]
+    async fn test_search_date_range_overrides_tbs() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/search")
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "query": "firecrawl",
+                "tbs": "cdr:1,cd_min:01/01/2024,cd_max:02/01/2024"
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "success": true, "data": { "web": [] } }).to_string())
+            .create();
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
+        let options = SearchOptions {
+            tbs: Some("qdr:d".to_string()),
+            date_range: Some(DateRange {
+                start: NaiveDate::from_ymd_opt(2024, 1, 1),
+                end: NaiveDate::from_ymd_opt(2024, 2, 1),
+            }),
+            ..Default::default()
+        };
+
+        client.search("firecrawl", options).await.unwrap();
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_search_deduplicates_web_results() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/search")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "totalResults": 42,
+                    "data": {
+                        "web": [
+                            { "url": "https://example.com/page", "title": "First" },
+                            { "url": "https://other.com", "title": "Other" },
+                            { "url": "https://Example.com/page/", "title": "Duplicate" },
+                            {
+                                "markdown": "# Page",
+                                "metadata": { "sourceURL": "https://example.com/PAGE" }
+                            }
+                        ]
+                    }
+                })
+                .to_string(),
+            )
+            .expect(2)
+            .create();
+
+        let client = Client::builder()
+            .base_url(server.url())
+            .api_key("test_key")
+            .build()
+            .unwrap();
+
+        let options = SearchOptions {
+            deduplicate: true,
+            ..Default::default()
+        };
+        let response = client.search("example", options).await.unwrap();
+        let web = response.data.web.unwrap();
+
+        assert_eq!(response.total_results, Some(42));
+        assert_eq!(web.len(), 2);
+        match &web[0] {
+            SearchResultOrDocument::WebResult(result) => {
+                assert_eq!(result.title.as_deref(), Some("First"))
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+        assert_eq!(web[1].url(), Some("https://other.com"));
+
+        let response = client.search("example", None).await.unwrap();
+        assert_eq!(response.data.web.unwrap().len(), 4);
+        mock.assert();
+    }
+
+    #[test]
+    fn test_search_result_accessors() {
+        let web: SearchResultOrDocument = serde_json::from_value(json!({
+            "url": "https://example.com",
+            "title": "Example"
+        }))
+        .unwrap();
+        let document: SearchResultOrDocument = serde_json::from_value(json!({
+            "markdown": "# Docs",
+            "metadata": { "sourceURL": "https://example.com/docs", "title": "Docs" }
+        }))
+        .unwrap();
+
+        assert_eq!(web.url(), Some("https://example.com"));
+        assert_eq!(web.title(), Some("Example"));
+        assert!(web.as_web_result().is_some());
+        assert!(web.as_document().is_none());
+        assert_eq!(web.to_string(), "https://example.com");
+
+        assert_eq!(document.title(), Some("Docs"));
+        assert!(document.as_web_result().is_none());
+        assert_eq!(
+            search_results_to_urls(vec![web.clone(), document.clone()]),
+            vec!["https://example.com", "https://example.com/docs"]
+        );
+        assert_eq!(
+            document.into_document().unwrap().markdown.as_deref(),
+            Some("# Docs")
+        );
+        assert!(web.into_document().is_none());
+    }
 }
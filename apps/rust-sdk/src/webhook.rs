@@ -0,0 +1,196 @@
+//! Webhook verification and parsing.
+
+use hmac::{Hmac, Mac};
+use serde::de::Error as _;
+use serde::Deserialize;
+use serde_json::Value;
+use sha2::Sha256;
+
+use crate::error::FirecrawlError;
+use crate::types::Document;
+
+/// Name of the header carrying the webhook signature.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Firecrawl-Signature";
+
+/// A webhook event sent by Firecrawl for a crawl or batch scrape job.
+#[derive(Debug, Clone)]
+pub enum WebhookPayload {
+    /// The job has started.
+    Started,
+    /// A page has been scraped.
+    Page(Box<Document>),
+    /// The job has completed.
+    Completed,
+    /// The job has failed.
+    Failed {
+        /// Error message reported by the API, if any.
+        error: Option<String>,
+    },
+}
+
+#[derive(Deserialize)]
+struct RawWebhookPayload {
+    #[serde(rename = "type")]
+    event_type: String,
+    #[serde(default)]
+    data: Value,
+    error: Option<String>,
+}
+
+/// Verifies the signature of a webhook request.
+///
+/// `signature` is the value of the [`WEBHOOK_SIGNATURE_HEADER`] header, with
+/// or without its `sha256=` prefix, and `payload` is the raw request body.
+/// The comparison is performed in constant time.
+///
+/// # Example
+///
+/// ```no_run
+/// use firecrawl::verify_webhook_signature;
+///
+/// let body = br#"{"success":true,"type":"crawl.started","id":"job-id","data":[]}"#;
+/// let signature = "sha256=...";
+///
+/// if !verify_webhook_signature(body, signature, "your-webhook-secret") {
+///     eprintln!("Rejecting spoofed webhook");
+/// }
+/// ```
+pub fn verify_webhook_signature(payload: &[u8], signature: &str, secret: &str) -> bool {
+    let signature = signature.trim();
+    let signature = signature.strip_prefix("sha256=").unwrap_or(signature);
+
+    let Some(expected) = decode_hex(signature) else {
+        return false;
+    };
+
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(payload);
+    mac.verify_slice(&expected).is_ok()
+}
+
+/// Parses the body of a webhook request.
+///
+/// Event types are matched on their suffix, so both `crawl.page` and
+/// `batch_scrape.page` events parse to [`WebhookPayload::Page`].
+pub fn parse_webhook_event(payload: &[u8]) -> Result<WebhookPayload, FirecrawlError> {
+    let raw: RawWebhookPayload =
+        serde_json::from_slice(payload).map_err(FirecrawlError::ResponseParseError)?;
+
+    let kind = raw.event_type.rsplit('.').next().unwrap_or(&raw.event_type);
+
+    match kind {
+        "started" => Ok(WebhookPayload::Started),
+        "completed" => Ok(WebhookPayload::Completed),
+        "failed" => Ok(WebhookPayload::Failed { error: raw.error }),
+        "page" => {
+            let document = match raw.data {
+                Value::Array(mut documents) if !documents.is_empty() => documents.swap_remove(0),
+                _ => {
+                    return Err(FirecrawlError::ResponseParseError(
+                        serde_json::Error::custom("page event without a document"),
+                    ))
+                }
+            };
+            serde_json::from_value(document)
+                .map(WebhookPayload::Page)
+                .map_err(FirecrawlError::ResponseParseError)
+        }
+        _ => Err(FirecrawlError::ResponseParseError(
+            serde_json::Error::custom(format!("unknown webhook event type {}", raw.event_type)),
+        )),
+    }
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sign(payload: &[u8], secret: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(payload);
+        mac.finalize()
+            .into_bytes()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    #[test]
+    fn test_verify_webhook_signature() {
+        let payload = br#"{"success":true,"type":"crawl.started","id":"job","data":[]}"#;
+        let signature = sign(payload, "secret");
+
+        assert!(verify_webhook_signature(payload, &signature, "secret"));
+        assert!(verify_webhook_signature(
+            payload,
+            &format!("sha256={}", signature),
+            "secret"
+        ));
+    }
+
+    #[test]
+    fn test_verify_webhook_signature_rejects_tampering() {
+        let payload = br#"{"success":true,"type":"crawl.started","id":"job","data":[]}"#;
+        let signature = sign(payload, "secret");
+        let tampered = br#"{"success":true,"type":"crawl.failed","id":"job","data":[]}"#;
+
+        assert!(!verify_webhook_signature(tampered, &signature, "secret"));
+        assert!(!verify_webhook_signature(
+            payload,
+            &signature,
+            "other-secret"
+        ));
+        assert!(!verify_webhook_signature(
+            payload,
+            "sha256=not-hex",
+            "secret"
+        ));
+        assert!(!verify_webhook_signature(payload, "", "secret"));
+    }
+
+    #[test]
+    fn test_parse_webhook_event() {
+        let page = json!({
+            "success": true,
+            "type": "batch_scrape.page",
+            "id": "job",
+            "data": [{ "markdown": "# Hello", "metadata": { "sourceURL": "https://example.com" } }]
+        });
+        match parse_webhook_event(page.to_string().as_bytes()).unwrap() {
+            WebhookPayload::Page(document) => {
+                assert_eq!(document.markdown.as_deref(), Some("# Hello"))
+            }
+            other => panic!("unexpected payload {:?}", other),
+        }
+
+        let failed =
+            json!({ "success": false, "type": "crawl.failed", "id": "job", "error": "boom" });
+        assert!(matches!(
+            parse_webhook_event(failed.to_string().as_bytes()).unwrap(),
+            WebhookPayload::Failed { error: Some(e) } if e == "boom"
+        ));
+
+        let completed =
+            json!({ "success": true, "type": "crawl.completed", "id": "job", "data": [] });
+        assert!(matches!(
+            parse_webhook_event(completed.to_string().as_bytes()).unwrap(),
+            WebhookPayload::Completed
+        ));
+
+        let unknown = json!({ "success": true, "type": "monitor.check.completed.later" });
+        assert!(parse_webhook_event(unknown.to_string().as_bytes()).is_err());
+    }
+}
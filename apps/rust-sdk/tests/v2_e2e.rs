@@ -16,7 +16,12 @@ use std::env;
 fn get_client() -> Client {
     let api_url = env::var("API_URL").expect("API_URL environment variable is required");
     let api_key = env::var("TEST_API_KEY").ok();
-    Client::new_selfhosted(api_url, api_key).expect("Failed to create client")
+    let builder = Client::builder().base_url(api_url);
+    let builder = match api_key {
+        Some(api_key) => builder.api_key(api_key),
+        None => builder,
+    };
+    builder.build().expect("Failed to create client")
 }
 
 #[tokio::test]
@@ -300,6 +305,7 @@ async fn test_agent_with_schema() {
 // Test that the client can be created with different configurations
 // This test doesn't require API access
 #[test]
+#[allow(deprecated)]
 fn test_client_creation() {
     // Cloud client requires API key
     let cloud_result = Client::new("test-key");